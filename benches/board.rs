@@ -3,13 +3,13 @@ use {
         chips::board::BoardConfig,
         circuits::board::BoardCircuit,
         utils::{
-            binary::BinaryValue, board::Board, deck::Deck, pedersen::pedersen_commit,
+            binary::BinaryValue, fixtures::pattern_1, pedersen::pedersen_commit,
             ship::DEFAULT_WITNESS_OPTIONS, shot::serialize,
         },
     },
     criterion::{criterion_group, criterion_main, Criterion},
     halo2_proofs::{
-        arithmetic::{CurveAffine, Field, FieldExt},
+        arithmetic::{CurveAffine, Field},
         pasta::{group::Curve, pallas, vesta},
         plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, SingleVerifier},
         poly::commitment::Params,
@@ -23,17 +23,11 @@ const K: u32 = 12;
 
 fn benchmark(c: &mut Criterion) {
     // construct battleship board pattern #1
-    let board = Board::from(&Deck::from([
-        Some((3, 3, true)),
-        Some((5, 4, false)),
-        Some((0, 1, false)),
-        Some((0, 5, true)),
-        Some((6, 1, false)),
-    ]));
+    let board = pattern_1();
     // sample a random trapdoor value for commitment
     let trapdoor = pallas::Scalar::random(&mut OsRng);
     // marshall the board state into a pallas base field element
-    let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+    let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
     // commit to the board state
     let commitment = {
         let commitment = pedersen_commit(&message, &trapdoor).to_affine();
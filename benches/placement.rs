@@ -0,0 +1,35 @@
+use {
+    battlezips_halo2::{
+        chips::placement::{compute_placement_trace, compute_placement_trace_fast},
+        utils::ship::{Ship, ShipType},
+    },
+    criterion::{black_box, criterion_group, criterion_main, Criterion},
+    halo2_proofs::pasta::pallas,
+};
+
+// carrier placement chip trace
+const S: usize = 5;
+
+fn benchmark(c: &mut Criterion) {
+    // vertical carrier at (3, 3), transposed as witnessed by the board circuit
+    let ship = Ship::new(ShipType::Carrier, 3, 3, true).bits(true);
+
+    // both variants must witness the same trace
+    assert_eq!(
+        compute_placement_trace::<pallas::Base, S>(&ship),
+        compute_placement_trace_fast::<pallas::Base, S>(&ship)
+    );
+
+    // benchmark trace computation
+    let mut group = c.benchmark_group("placement_trace");
+    group.bench_function("field", |b| {
+        b.iter(|| compute_placement_trace::<pallas::Base, S>(black_box(&ship)))
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| compute_placement_trace_fast::<pallas::Base, S>(black_box(&ship)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -3,13 +3,15 @@ use {
         chips::shot::ShotConfig,
         circuits::shot::ShotCircuit,
         utils::{
-            binary::BinaryValue, board::Board, deck::Deck, pedersen::pedersen_commit,
-            ship::DEFAULT_WITNESS_OPTIONS, shot::serialize,
+            fixtures::pattern_1,
+            pedersen::pedersen_commit,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::{serialize, ShotResult},
         },
     },
     criterion::{criterion_group, criterion_main, Criterion},
     halo2_proofs::{
-        arithmetic::{CurveAffine, Field, FieldExt},
+        arithmetic::{CurveAffine, Field},
         pasta::{group::Curve, pallas, vesta},
         plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, SingleVerifier},
         poly::commitment::Params,
@@ -23,21 +25,15 @@ const K: u32 = 11;
 
 fn benchmark(c: &mut Criterion) {
     // construct battleship board pattern #1
-    let board = Board::from(&Deck::from([
-        Some((3, 3, true)),
-        Some((5, 4, false)),
-        Some((0, 1, false)),
-        Some((0, 5, true)),
-        Some((6, 1, false)),
-    ]));
+    let board = pattern_1();
     // serialize a shot at (3, 3) into `33u256`
     let shot = serialize::<1>([3], [3]);
-    // assert a miss and wrap in u256
-    let hit = BinaryValue::from_u8(0);
+    // assert a miss
+    let hit = ShotResult::Miss;
     // sample a random trapdoor value for commitment
     let trapdoor = pallas::Scalar::random(&mut OsRng);
     // marshall the board state into a pallas base field element
-    let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+    let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
     // commit to the board state
     let commitment = {
         let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -49,8 +45,8 @@ fn benchmark(c: &mut Criterion) {
     let public_inputs = vec![
         commitment.0,
         commitment.1,
-        pallas::Base::from_u128(shot.lower_u128()),
-        pallas::Base::from_u128(hit.lower_u128()),
+        shot.to_fp(),
+        hit.to_fp(),
     ];
     // construct Shot circuit
     let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
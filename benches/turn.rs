@@ -0,0 +1,90 @@
+use {
+    battlezips_halo2::{
+        circuits::{
+            board::{BoardCircuit, BOARD_CIRCUIT_K},
+            shot::{ShotCircuit, SHOT_CIRCUIT_K},
+            turn::{TurnCircuit, TURN_CIRCUIT_K},
+        },
+        utils::{
+            commitment::ShotCommitment,
+            fixtures::pattern_1,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::{serialize, ShotResult},
+        },
+    },
+    criterion::{criterion_group, criterion_main, Criterion},
+    halo2_proofs::{
+        arithmetic::Field,
+        pasta::{pallas, vesta},
+        plonk::{create_proof, keygen_pk, keygen_vk},
+        poly::commitment::Params,
+        transcript::{Blake2bWrite, Challenge255},
+    },
+    rand::rngs::OsRng,
+};
+
+fn benchmark(c: &mut Criterion) {
+    // construct battleship board pattern #1
+    let board = pattern_1();
+    let ship_commitments = board.witness(DEFAULT_WITNESS_OPTIONS);
+    let state = board.state(DEFAULT_WITNESS_OPTIONS);
+    // serialize a shot at (3, 5) that hits the carrier
+    let shot = serialize::<1>([3], [5]);
+    let hit = ShotResult::Hit;
+    // sample a random trapdoor value for commitment
+    let trapdoor = pallas::Scalar::random(&mut OsRng);
+
+    // separate board + shot proofs
+    let board_circuit = BoardCircuit::new(ship_commitments, state, trapdoor);
+    let board_params: Params<vesta::Affine> = Params::new(BOARD_CIRCUIT_K);
+    let board_pk = BoardCircuit::keygen(&board_params).expect("keygen should not fail");
+    let board_proof = board_circuit
+        .prove(&board_params, &board_pk)
+        .expect("proof generation should not fail");
+    let shot_circuit = ShotCircuit::new(state, trapdoor, shot, hit);
+    let shot_params: Params<vesta::Affine> = Params::new(SHOT_CIRCUIT_K);
+    let vk = keygen_vk(&shot_params, &shot_circuit).expect("keygen_vk should not fail");
+    let shot_pk = keygen_pk(&shot_params, vk, &shot_circuit).expect("keygen_pk should not fail");
+    let shot_inputs =
+        ShotCircuit::instance(&board_circuit.commitment(), &ShotCommitment(shot), hit);
+    let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &shot_params,
+        &shot_pk,
+        &[shot_circuit],
+        &[&[&shot_inputs]],
+        &mut OsRng,
+        &mut transcript,
+    )
+    .expect("proof generation should not fail");
+    let shot_proof = transcript.finalize();
+
+    // combined turn proof
+    let turn_circuit = TurnCircuit::new(ship_commitments, state, trapdoor, shot, hit.into());
+    let turn_params: Params<vesta::Affine> = Params::new(TURN_CIRCUIT_K);
+    let turn_pk = TurnCircuit::keygen(&turn_params).expect("keygen should not fail");
+    let turn_proof = turn_circuit
+        .prove(&turn_params, &turn_pk)
+        .expect("proof generation should not fail");
+
+    // report proof sizes
+    println!(
+        "proof size: board {} + shot {} = {} bytes, turn {} bytes",
+        board_proof.len(),
+        shot_proof.len(),
+        board_proof.len() + shot_proof.len(),
+        turn_proof.len()
+    );
+
+    // benchmark proof creation
+    c.bench_function("turn_prover", |b| {
+        b.iter(|| {
+            turn_circuit
+                .prove(&turn_params, &turn_pk)
+                .expect("proof generation should not fail")
+        })
+    });
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,181 @@
+use {
+    battlezips_halo2::{
+        circuits::game::{BoardRecord, GameTranscript, ProverContext, ShotRecord},
+        utils::{
+            analysis::heatmap,
+            binary::BinaryValue,
+            board::Board,
+            commitment::ShotCommitment,
+            deck::Deck,
+            opponent::OpponentView,
+            ship::{ShipType, DEFAULT_WITNESS_OPTIONS},
+            shot::{serialize, ShotResult},
+        },
+        BoardCircuit, ShotCircuit,
+    },
+    halo2_proofs::{arithmetic::Field, pasta::pallas},
+    rand::rngs::OsRng,
+    std::time::Instant,
+};
+
+// every ship in the standard fleet in deck order
+const ROSTER: [ShipType; 5] = [
+    ShipType::Carrier,
+    ShipType::Battleship,
+    ShipType::Cruiser,
+    ShipType::Submarine,
+    ShipType::Destroyer,
+];
+
+/**
+ * Choose the next cell for a heatmap bot to fire at
+ * @dev exact enumeration of the whole fleet is intractable early on, so until two ships remain the
+ *      bot sums each afloat ship's placements over the misses and prefers cells next to a hit
+ *
+ * @param view - verified hits, misses and sunk ships of the opponent's board
+ * @return - (x, y) of an unresolved cell
+ */
+fn next_shot(view: &OpponentView) -> (u8, u8) {
+    let afloat: Vec<ShipType> = ROSTER
+        .into_iter()
+        .filter(|ship| !view.sunk.contains(ship))
+        .collect();
+    let probabilities = if afloat.len() <= 2 {
+        heatmap(view, &ROSTER)
+    } else {
+        let mut misses = OpponentView::new();
+        misses.misses = view.misses;
+        let mut density = [[0.0; 10]; 10];
+        for ship in afloat {
+            let ship_heatmap = heatmap(&misses, &[ship]);
+            for i in 0..100 {
+                density[i / 10][i % 10] += ship_heatmap[i / 10][i % 10];
+            }
+        }
+        density
+    };
+    // unresolved cells next to a hit, falling back to every unresolved cell
+    let unresolved = |(x, y): &(u8, u8)| !view.is_cell_resolved(*x, *y);
+    let targets: Vec<(u8, u8)> = (0..100u8)
+        .map(|i| (i % 10, i / 10))
+        .filter(unresolved)
+        .filter(|(x, y)| {
+            [(-1, 0), (1, 0), (0, -1), (0, 1)].iter().any(|(dx, dy)| {
+                let (nx, ny) = (*x as i8 + dx, *y as i8 + dy);
+                (0..10).contains(&nx)
+                    && (0..10).contains(&ny)
+                    && view.hits.value[ny as usize * 10 + nx as usize]
+            })
+        })
+        .collect();
+    let candidates: Vec<(u8, u8)> = if targets.is_empty() {
+        (0..100u8)
+            .map(|i| (i % 10, i / 10))
+            .filter(unresolved)
+            .collect()
+    } else {
+        targets
+    };
+    // first candidate with the highest probability, scanning from (0, 0)
+    let mut best = candidates[0];
+    for (x, y) in candidates {
+        if probabilities[y as usize][x as usize] > probabilities[best.1 as usize][best.0 as usize] {
+            best = (x, y);
+        }
+    }
+    best
+}
+
+/**
+ * Play two random decks against each other with heatmap bots, proving every board and shot
+ * @dev prints the time taken by keygen and by each proof
+ *
+ * @return - the shared prover context and the transcript of the finished game
+ */
+pub fn play() -> (ProverContext, GameTranscript) {
+    let timer = Instant::now();
+    let context = ProverContext::new().unwrap();
+    println!("keygen: {:?}", timer.elapsed());
+    // each player places a random deck and proves their board
+    let mut boards = Vec::new();
+    let mut trapdoors = Vec::new();
+    let mut records = Vec::new();
+    for player in 0..2 {
+        let board = Board::from(&Deck::random(&mut OsRng));
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            trapdoor,
+        );
+        let timer = Instant::now();
+        let proof = context.prove_board(&circuit).unwrap();
+        println!("player {} board proof: {:?}", player, timer.elapsed());
+        board.print();
+        boards.push(board);
+        trapdoors.push(trapdoor);
+        records.push(BoardRecord {
+            commitment: circuit.commitment(),
+            proof,
+        });
+    }
+    let mut transcript = GameTranscript::new(records.try_into().unwrap());
+    // players alternate shots until one fleet is sunk
+    let mut views = [OpponentView::new(), OpponentView::new()];
+    let mut shots_received: [Vec<BinaryValue>; 2] = [Vec::new(), Vec::new()];
+    let mut attacker = 0;
+    while transcript.winner().is_none() {
+        let defender = 1 - attacker;
+        let (x, y) = next_shot(&views[attacker]);
+        let shot = serialize::<1>([x], [y]);
+        let hit = boards[defender].evaluate_shot(&shot).unwrap();
+        // the defender proves the result of the shot against their committed board
+        let turn = transcript.shots.len() as u64;
+        let circuit = ShotCircuit::new_with_turn(
+            boards[defender].state(DEFAULT_WITNESS_OPTIONS),
+            trapdoors[defender],
+            shot,
+            ShotResult::from(hit),
+            turn,
+        );
+        let timer = Instant::now();
+        let proof = context.prove_shot(&circuit).unwrap();
+        println!(
+            "player {} fires at ({}, {}): {} [{:?}]",
+            attacker,
+            x,
+            y,
+            if hit { "hit" } else { "miss" },
+            timer.elapsed()
+        );
+        transcript.record_shot(ShotRecord {
+            defender,
+            shot: ShotCommitment(shot),
+            hit,
+            turn,
+            proof,
+        });
+        // the attacker records the result and the defender announces any ship it sank
+        views[attacker].apply_shot(x, y, hit).unwrap();
+        shots_received[defender].push(shot);
+        for (i, ship) in ROSTER.into_iter().enumerate() {
+            if hit && boards[defender].is_sunk_by(i, &shots_received[defender]) {
+                views[attacker].mark_sunk(ship);
+            }
+        }
+        attacker = defender;
+    }
+    (context, transcript)
+}
+
+fn main() {
+    let (context, transcript) = play();
+    println!(
+        "player {} wins after {} shots",
+        transcript.winner().unwrap(),
+        transcript.shots.len()
+    );
+    let timer = Instant::now();
+    transcript.verify(&context).unwrap();
+    println!("transcript verified: {:?}", timer.elapsed());
+}
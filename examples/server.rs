@@ -0,0 +1,270 @@
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    battlezips_halo2::{
+        circuits::{
+            bundle::{ProofBundle, ProofKind},
+            game::ProverContext,
+        },
+        utils::{
+            board::Board,
+            commitment::{BoardCommitment, ShotCommitment},
+            deck::Deck,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::ShotResult,
+        },
+        BoardCircuit, ShotCircuit,
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        pasta::{group::ff::PrimeField, pallas},
+    },
+    rand::rngs::OsRng,
+    serde::{Deserialize, Serialize},
+    std::io::Read,
+    tiny_http::{Header, Response, Server},
+};
+
+/**
+ * Body of POST /prove/board
+ *
+ * @param ships - (x, y, vertical) placement of each ship in deck order
+ * @param trapdoor - hex encoded commitment trapdoor; sampled by the server when omitted
+ */
+#[derive(Deserialize, Serialize)]
+pub struct ProveBoardRequest {
+    pub ships: [(u8, u8, bool); 5],
+    pub trapdoor: Option<String>,
+}
+
+/**
+ * Body of POST /prove/shot
+ *
+ * @param ships - (x, y, vertical) placement of each ship in deck order
+ * @param trapdoor - hex encoded trapdoor the board was committed with
+ * @param x - horizontal coordinate of the shot
+ * @param y - vertical coordinate of the shot
+ */
+#[derive(Deserialize, Serialize)]
+pub struct ProveShotRequest {
+    pub ships: [(u8, u8, bool); 5],
+    pub trapdoor: String,
+    pub x: u8,
+    pub y: u8,
+}
+
+/**
+ * Body of POST /verify
+ *
+ * @param bundle - base64 encoded ProofBundle::to_bytes()
+ */
+#[derive(Deserialize, Serialize)]
+pub struct VerifyRequest {
+    pub bundle: String,
+}
+
+/**
+ * Response to POST /prove/board
+ *
+ * @param bundle - base64 encoded ProofBundle::to_bytes()
+ * @param trapdoor - hex encoded trapdoor needed to prove shots against the commitment
+ */
+#[derive(Deserialize, Serialize)]
+pub struct ProveBoardResponse {
+    pub bundle: String,
+    pub trapdoor: String,
+}
+
+/**
+ * Response to POST /prove/shot
+ *
+ * @param bundle - base64 encoded ProofBundle::to_bytes()
+ * @param hit - whether the shot hit the board
+ */
+#[derive(Deserialize, Serialize)]
+pub struct ProveShotResponse {
+    pub bundle: String,
+    pub hit: bool,
+}
+
+/**
+ * Response to POST /verify
+ *
+ * @param kind - "board" or "shot"
+ * @param valid - whether the bundled proof verifies against its public inputs
+ */
+#[derive(Deserialize, Serialize)]
+pub struct VerifyResponse {
+    pub kind: String,
+    pub valid: bool,
+}
+
+// body of every 4xx response
+#[derive(Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+/**
+ * Place a deck and check that it is a valid board
+ *
+ * @param ships - (x, y, vertical) placement of each ship in deck order
+ * @return - the board, or why it was rejected
+ */
+fn board(ships: [(u8, u8, bool); 5]) -> Result<Board, String> {
+    let deck = Deck::try_from(ships.map(Some))?;
+    let board = Board::from(&deck);
+    board
+        .is_valid()
+        .map_err(|violations| format!("Invalid board: {:?}", violations))?;
+    Ok(board)
+}
+
+/**
+ * Decode a hex encoded trapdoor
+ *
+ * @param trapdoor - 32 bytes of little endian hex
+ * @return - the trapdoor, or why it was rejected
+ */
+fn decode_trapdoor(trapdoor: &str) -> Result<pallas::Scalar, String> {
+    let bytes: [u8; 32] = hex::decode(trapdoor)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("Trapdoor must be 32 bytes of hex")?;
+    Option::from(pallas::Scalar::from_repr(bytes))
+        .ok_or_else(|| String::from("Trapdoor is not a canonical scalar"))
+}
+
+// prove a board and return its bundle with the trapdoor the shots must be proven with
+fn prove_board(context: &ProverContext, request: ProveBoardRequest) -> Result<String, String> {
+    let board = board(request.ships)?;
+    let trapdoor = match request.trapdoor {
+        Some(trapdoor) => decode_trapdoor(&trapdoor)?,
+        None => pallas::Scalar::random(&mut OsRng),
+    };
+    let circuit = BoardCircuit::new(
+        board.witness(DEFAULT_WITNESS_OPTIONS),
+        board.state(DEFAULT_WITNESS_OPTIONS),
+        trapdoor,
+    );
+    let commitment = circuit
+        .try_commitment()
+        .map_err(|error| error.to_string())?;
+    let proof = context
+        .prove_board(&circuit)
+        .map_err(|error| error.to_string())?;
+    let bundle = ProofBundle::board(&commitment, proof, context.board_vk_fingerprint());
+    serde_json::to_string(&ProveBoardResponse {
+        bundle: STANDARD.encode(bundle.to_bytes()),
+        trapdoor: hex::encode(trapdoor.to_repr()),
+    })
+    .map_err(|error| error.to_string())
+}
+
+// prove the result of a shot against the board committed to with the given trapdoor
+fn prove_shot(context: &ProverContext, request: ProveShotRequest) -> Result<String, String> {
+    let board = board(request.ships)?;
+    let trapdoor = decode_trapdoor(&request.trapdoor)?;
+    let shot = ShotCommitment::new(request.x, request.y).map_err(|error| error.to_string())?;
+    let hit = board
+        .hit(request.x, request.y)
+        .map_err(|error| error.to_string())?;
+    let hit_value = ShotResult::from(hit);
+    let state = board.state(DEFAULT_WITNESS_OPTIONS);
+    let commitment =
+        BoardCommitment::try_commit(&state, &trapdoor).map_err(|error| error.to_string())?;
+    let circuit = ShotCircuit::new(state, trapdoor, shot.0, hit_value);
+    let proof = context
+        .prove_shot(&circuit)
+        .map_err(|error| error.to_string())?;
+    let bundle = ProofBundle::shot(
+        ShotCircuit::instance(&commitment, &shot, hit_value),
+        proof,
+        context.shot_vk_fingerprint(),
+    );
+    serde_json::to_string(&ProveShotResponse {
+        bundle: STANDARD.encode(bundle.to_bytes()),
+        hit,
+    })
+    .map_err(|error| error.to_string())
+}
+
+// verify a bundle against the shared verifying keys
+fn verify(context: &ProverContext, request: VerifyRequest) -> Result<String, String> {
+    let bytes = STANDARD
+        .decode(request.bundle)
+        .map_err(|error| error.to_string())?;
+    let bundle = ProofBundle::from_bytes(&bytes)?;
+    serde_json::to_string(&VerifyResponse {
+        kind: String::from(match bundle.kind {
+            ProofKind::Board => "board",
+            ProofKind::Shot => "shot",
+            ProofKind::TurnShot => "turn_shot",
+        }),
+        valid: bundle.verify(context).is_ok(),
+    })
+    .map_err(|error| error.to_string())
+}
+
+/**
+ * Route a request to its endpoint
+ *
+ * @param context - shared prover context
+ * @param method - http method of the request
+ * @param url - path of the request
+ * @param body - json body of the request
+ * @return - http status code and json body of the response
+ */
+pub fn handle(context: &ProverContext, method: &str, url: &str, body: &str) -> (u16, String) {
+    let result = match (method, url) {
+        ("POST", "/prove/board") => serde_json::from_str(body)
+            .map_err(|error| error.to_string())
+            .and_then(|request| prove_board(context, request)),
+        ("POST", "/prove/shot") => serde_json::from_str(body)
+            .map_err(|error| error.to_string())
+            .and_then(|request| prove_shot(context, request)),
+        ("POST", "/verify") => serde_json::from_str(body)
+            .map_err(|error| error.to_string())
+            .and_then(|request| verify(context, request)),
+        _ => return (404, String::from("{\"error\":\"Not found\"}")),
+    };
+    match result {
+        Ok(response) => (200, response),
+        Err(error) => (
+            400,
+            serde_json::to_string(&ErrorResponse { error }).unwrap(),
+        ),
+    }
+}
+
+/**
+ * Serve requests one at a time until the server is closed
+ * @dev proofs are cpu bound, so requests are handled in arrival order on the calling thread
+ *
+ * @param server - bound http server
+ * @param context - shared prover context
+ */
+pub fn serve(server: Server, context: &ProverContext) {
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, response) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => handle(context, request.method().as_str(), request.url(), &body),
+            Err(_) => (400, String::from("{\"error\":\"Body must be utf-8\"}")),
+        };
+        let response = Response::from_string(response)
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        // the client hanging up is not a server error
+        let _ = request.respond(response);
+    }
+}
+
+fn main() {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("127.0.0.1:8080"));
+    let context = ProverContext::new().unwrap();
+    let server = Server::http(&address).unwrap();
+    println!("listening on http://{}", address);
+    serve(server, &context);
+}
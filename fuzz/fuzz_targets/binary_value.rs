@@ -0,0 +1,16 @@
+#![no_main]
+
+use {battlezips_halo2::BinaryValue, libfuzzer_sys::fuzz_target};
+
+// hex parsing never panics and any parsed value round trips through hex and bytes
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(value) = BinaryValue::from_hex(input) {
+            assert_eq!(BinaryValue::from_hex(&value.to_hex()), Ok(value));
+            assert_eq!(BinaryValue::from_repr(value.to_repr()), value);
+            if let Ok(fp) = value.try_to_fp() {
+                assert_eq!(BinaryValue::from_fp(fp), value);
+            }
+        }
+    }
+});
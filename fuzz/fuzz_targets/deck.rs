@@ -0,0 +1,20 @@
+#![no_main]
+
+use {
+    battlezips_halo2::{utils::ship::DEFAULT_WITNESS_OPTIONS, Board, Deck},
+    libfuzzer_sys::fuzz_target,
+};
+
+// deck JSON as sent by the wasm frontend never panics and any parsed deck round trips
+fuzz_target!(|data: &[u8]| {
+    if let Ok(ships) = serde_json::from_slice::<[Option<(u8, u8, bool)>; 5]>(data) {
+        if let Ok(deck) = Deck::try_from(ships) {
+            let placements = deck.iterator().map(|ship| ship.map(|ship| (ship.x, ship.y, ship.z)));
+            assert_eq!(placements, ships);
+            // witness generation for any accepted deck must not panic
+            let board = Board::from(&deck);
+            board.state(DEFAULT_WITNESS_OPTIONS);
+            board.witness(DEFAULT_WITNESS_OPTIONS);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use {
+    battlezips_halo2::utils::golden::{decode_instance, encode_instance},
+    libfuzzer_sys::fuzz_target,
+};
+
+// public input decoding never panics and any decoded instance round trips
+fuzz_target!(|data: &[u8]| {
+    if let Ok(instance) = serde_json::from_slice::<Vec<String>>(data) {
+        if let Ok(decoded) = decode_instance(&instance) {
+            assert_eq!(decode_instance(&encode_instance(&decoded)), Ok(decoded));
+        }
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use {battlezips_halo2::circuits::bundle::ProofBundle, libfuzzer_sys::fuzz_target};
+
+// bundle decoding never panics and any accepted encoding is the canonical one
+fuzz_target!(|data: &[u8]| {
+    if let Ok(bundle) = ProofBundle::from_bytes(data) {
+        assert_eq!(bundle.to_bytes(), data);
+        assert_eq!(ProofBundle::from_bytes(&bundle.to_bytes()), Ok(bundle));
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use {
+    battlezips_halo2::{
+        utils::shot::{deserialize, serialize},
+        BinaryValue,
+    },
+    libfuzzer_sys::fuzz_target,
+};
+
+// shot deserialization never panics and any parsed shot round trips through serialize
+fuzz_target!(|data: [u8; 32]| {
+    let shot = BinaryValue::from_repr(data);
+    if let Ok((x, y)) = deserialize(shot) {
+        assert_eq!(serialize::<1>([x], [y]), shot);
+    }
+});
@@ -0,0 +1,34 @@
+const assert = require('node:assert');
+const { test } = require('node:test');
+const { proveBoard, proveShot, verifyBundle } = require('../index.js');
+
+// board pattern 1 from utils::fixtures as [x, y, vertical] placements
+const deck = JSON.stringify([
+  [3, 3, true],
+  [5, 4, false],
+  [0, 1, false],
+  [0, 5, true],
+  [6, 1, false],
+]);
+const seed = Buffer.alloc(32, 7);
+
+test('proves and verifies a board and a shot', async () => {
+  const board = await proveBoard(deck, seed);
+  assert.ok(Buffer.isBuffer(board));
+  assert.strictEqual(await verifyBundle(board), true);
+
+  // (3, 3) is the first cell of the carrier
+  const shot = await proveShot(deck, seed, 3, 3);
+  assert.strictEqual(await verifyBundle(shot), true);
+
+  // a corrupted proof fails verification without throwing
+  shot[shot.length - 1] ^= 1;
+  assert.strictEqual(await verifyBundle(shot), false);
+});
+
+test('rejects malformed arguments', async () => {
+  await assert.rejects(proveBoard('not json', seed));
+  await assert.rejects(proveBoard(deck, Buffer.alloc(31)));
+  await assert.rejects(proveShot(deck, seed, 10, 0));
+  await assert.rejects(verifyBundle(Buffer.alloc(1)));
+});
@@ -0,0 +1,155 @@
+use {
+    battlezips_halo2::{
+        circuits::{
+            board::BoardCircuit, bundle::ProofBundle, game::ProverContext, shot::ShotCircuit,
+        },
+        utils::{
+            commitment::{BoardCommitment, ShotCommitment},
+            ship::DEFAULT_WITNESS_OPTIONS,
+        },
+        Board, Deck, ShotResult,
+    },
+    halo2_proofs::{arithmetic::Field, pasta::pallas},
+    napi::{bindgen_prelude::Buffer, Error, Result, Status},
+    napi_derive::napi,
+    once_cell::sync::OnceCell,
+    rand::{rngs::StdRng, SeedableRng},
+};
+
+// keys shared by every call, generated on first use
+static CONTEXT: OnceCell<ProverContext> = OnceCell::new();
+
+/**
+ * Reject a call whose arguments could not be parsed
+ *
+ * @param reason - why the argument was rejected
+ * @return - napi error thrown as a JS exception
+ */
+fn invalid_arg(reason: impl ToString) -> Error {
+    Error::new(Status::InvalidArg, reason.to_string())
+}
+
+/**
+ * Run a prover or verifier on the tokio blocking pool so the node event loop is never blocked
+ * @dev the first call also runs keygen for both circuits
+ *
+ * @param task - work to run against the shared prover context
+ * @return - the result of the task
+ */
+async fn blocking<T: Send + 'static>(
+    task: impl FnOnce(&ProverContext) -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(move || {
+        let context = CONTEXT
+            .get_or_try_init(ProverContext::new)
+            .map_err(|error| Error::from_reason(error.to_string()))?;
+        task(context)
+    })
+    .await
+    .map_err(|error| Error::from_reason(error.to_string()))?
+}
+
+/**
+ * Parse a deck and check that it is a valid board
+ *
+ * @param deck_json - JSON array of five [x, y, vertical] placements in deck order
+ * @return - the board, or why it was rejected
+ */
+fn board(deck_json: &str) -> Result<Board> {
+    let ships: [Option<(u8, u8, bool)>; 5] =
+        serde_json::from_str(deck_json).map_err(invalid_arg)?;
+    let board = Board::from(&Deck::try_from(ships).map_err(invalid_arg)?);
+    board
+        .is_valid()
+        .map_err(|violations| invalid_arg(format!("Invalid board: {:?}", violations)))?;
+    Ok(board)
+}
+
+/**
+ * Derive the board commitment trapdoor from a seed
+ * @dev the same seed always yields the same trapdoor, so shots can be proven against an earlier board proof
+ *
+ * @param seed - 32 byte seed
+ * @return - the trapdoor, or why the seed was rejected
+ */
+fn trapdoor(seed: &[u8]) -> Result<pallas::Scalar> {
+    let seed: [u8; 32] = seed
+        .try_into()
+        .map_err(|_| invalid_arg("Seed must be 32 bytes"))?;
+    Ok(pallas::Scalar::random(StdRng::from_seed(seed)))
+}
+
+/**
+ * Prove a valid board
+ *
+ * @param deck_json - JSON array of five [x, y, vertical] placements in deck order
+ * @param seed - 32 byte seed the board commitment trapdoor is derived from
+ * @return - ProofBundle::to_bytes() of the board proof
+ */
+#[napi]
+pub async fn prove_board(deck_json: String, seed: Buffer) -> Result<Buffer> {
+    let board = board(&deck_json)?;
+    let trapdoor = trapdoor(&seed)?;
+    blocking(move |context| {
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            trapdoor,
+        );
+        let commitment = circuit.try_commitment().map_err(invalid_arg)?;
+        let proof = context
+            .prove_board(&circuit)
+            .map_err(|error| Error::from_reason(error.to_string()))?;
+        let bundle = ProofBundle::board(&commitment, proof, context.board_vk_fingerprint());
+        Ok(bundle.to_bytes().into())
+    })
+    .await
+}
+
+/**
+ * Prove the result of a shot against a board
+ *
+ * @param deck_json - JSON array of five [x, y, vertical] placements in deck order
+ * @param seed - 32 byte seed the board commitment trapdoor was derived from in proveBoard()
+ * @param x - horizontal coordinate of the shot
+ * @param y - vertical coordinate of the shot
+ * @return - ProofBundle::to_bytes() of the shot proof
+ */
+#[napi]
+pub async fn prove_shot(deck_json: String, seed: Buffer, x: u32, y: u32) -> Result<Buffer> {
+    let board = board(&deck_json)?;
+    let trapdoor = trapdoor(&seed)?;
+    let (x, y) = (
+        u8::try_from(x).map_err(invalid_arg)?,
+        u8::try_from(y).map_err(invalid_arg)?,
+    );
+    let shot = ShotCommitment::new(x, y).map_err(invalid_arg)?;
+    let hit = ShotResult::from(board.hit(x, y).map_err(invalid_arg)?);
+    blocking(move |context| {
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let commitment = BoardCommitment::try_commit(&state, &trapdoor).map_err(invalid_arg)?;
+        let circuit = ShotCircuit::new(state, trapdoor, shot.0, hit);
+        let proof = context
+            .prove_shot(&circuit)
+            .map_err(|error| Error::from_reason(error.to_string()))?;
+        let bundle = ProofBundle::shot(
+            ShotCircuit::instance(&commitment, &shot, hit),
+            proof,
+            context.shot_vk_fingerprint(),
+        );
+        Ok(bundle.to_bytes().into())
+    })
+    .await
+}
+
+/**
+ * Verify a board or shot proof bundle
+ *
+ * @param bytes - ProofBundle::to_bytes() of the proof
+ * @return - true if the proof is valid for its bundled public inputs
+ */
+#[napi]
+pub async fn verify_bundle(bytes: Buffer) -> Result<bool> {
+    let bundle = ProofBundle::from_bytes(&bytes).map_err(invalid_arg)?;
+    blocking(move |context| Ok(bundle.verify(context).is_ok())).await
+}
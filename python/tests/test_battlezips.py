@@ -0,0 +1,50 @@
+import pytest
+
+import battlezips
+
+# board pattern 1 from utils::fixtures as (x, y, vertical) placements
+PATTERN_1 = [(3, 3, True), (5, 4, False), (0, 1, False), (0, 5, True), (6, 1, False)]
+
+
+def test_deck_and_board():
+    deck = battlezips.Deck(PATTERN_1)
+    assert battlezips.Deck.from_bytes(deck.to_bytes()).to_bytes() == deck.to_bytes()
+    board = battlezips.Board(deck)
+    assert board.is_valid()
+    assert board.hit(3, 3)
+    assert not board.hit(9, 9)
+    assert len(board.cells()) == 17
+    with pytest.raises(ValueError):
+        battlezips.Deck(PATTERN_1[:4])
+    with pytest.raises(ValueError):
+        board.hit(10, 0)
+
+
+def test_prove_and_verify_game():
+    decks = [battlezips.Deck(PATTERN_1), battlezips.Deck(PATTERN_1)]
+    boards = [battlezips.prove_board(deck) for deck in decks]
+    for bundle, _ in boards:
+        assert battlezips.verify_bundle(bundle)
+
+    transcript = battlezips.GameTranscript((boards[0][0], boards[1][0]))
+    # player 0 fires at the first cell of player 1's carrier
+    bundle, hit = battlezips.prove_shot(decks[1], boards[1][1], 3, 3, turn=0)
+    assert hit
+    assert battlezips.verify_bundle(bundle)
+    transcript.record_shot(1, bundle)
+    assert transcript.verify()
+    assert transcript.winner() is None
+
+    # a shot proven against the other board does not verify in the transcript
+    bundle, _ = battlezips.prove_shot(decks[0], boards[0][1], 9, 9, turn=1)
+    transcript.record_shot(1, bundle)
+    assert not transcript.verify()
+
+    # shots without a turn cannot be recorded
+    bundle, _ = battlezips.prove_shot(decks[1], boards[1][1], 4, 3)
+    assert battlezips.verify_bundle(bundle)
+    with pytest.raises(ValueError):
+        transcript.record_shot(1, bundle)
+
+    with pytest.raises(ValueError):
+        battlezips.verify_bundle(b"\x00")
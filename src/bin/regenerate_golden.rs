@@ -0,0 +1,32 @@
+use {
+    battlezips_halo2::utils::golden::{generate, GoldenProof},
+    std::{fs, path::Path},
+};
+
+/**
+ * Write a golden fixture and its proof to tests/golden
+ *
+ * @param golden - the fixture and the serialized proof it describes
+ */
+fn write_fixture(golden: &GoldenProof) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    fs::create_dir_all(&dir).unwrap();
+    let name = golden.name;
+    fs::write(dir.join(format!("{}.json", name)), golden.fixture_json()).unwrap();
+    fs::write(dir.join(format!("{}.proof", name)), &golden.proof).unwrap();
+    println!(
+        "wrote tests/golden/{}.json and tests/golden/{}.proof",
+        name, name
+    );
+}
+
+/**
+ * Regenerate the golden board and shot proofs used by tests/golden.rs
+ * @dev only run when a change to the verifying key or instance layout is intended; every trapdoor
+ *      and blinding factor is drawn from GOLDEN_SEED, so rerunning reproduces the fixtures exactly
+ */
+fn main() {
+    for golden in generate().unwrap().iter() {
+        write_fixture(golden);
+    }
+}
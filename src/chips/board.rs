@@ -2,14 +2,17 @@ use {
     crate::{
         chips::{
             bitify::{BitifyConfig, Bits2NumChip, Num2BitsChip},
+            columns::ColumnSet,
+            gate_names::GATE_ORIENTATION_ZERO,
             pedersen::{PedersenCommitmentChip, PedersenCommitmentConfig},
             placement::{AssignedBits, PlacementChip, PlacementConfig},
+            trace::traced,
             transpose::{TransposeChip, TransposeConfig},
         },
-        utils::{binary::BinaryValue, board::BOARD_SIZE},
+        utils::{binary::BinaryValue, board::BOARD_SIZE, ship::FleetConfig},
     },
     halo2_proofs::{
-        arithmetic::{CurveAffine, FieldExt},
+        arithmetic::CurveAffine,
         circuit::{AssignedCell, Chip, Layouter, Region, Value},
         pasta::{group::Curve, pallas},
         plonk::{
@@ -20,8 +23,8 @@ use {
     },
 };
 
-pub type Commitments = [AssignedCell<pallas::Base, pallas::Base>; 10];
-pub type Placements = [AssignedBits<pallas::Base>; 10];
+pub type Commitments = Vec<AssignedCell<pallas::Base, pallas::Base>>;
+pub type Placements = Vec<AssignedBits<pallas::Base>>;
 
 /**
  * Return a label for commitments in debugging
@@ -45,36 +48,231 @@ pub fn commitment_label(i: usize) -> String {
     })
 }
 
-// bundles all placement configs together
+/**
+ * Return a name for the orientation constraint of a ship in a fleet
+ * @dev ships are named by length, with the second ship of length 3 named the submarine
+ *
+ * @param fleet - the fleet the ship belongs to
+ * @param i - the index of the ship in the fleet
+ * @return - the name of the H OR V == 0 constraint for the ship
+ */
+pub fn orientation_label(fleet: &FleetConfig, i: usize) -> &'static str {
+    let length = fleet.ships[i];
+    let duplicate = fleet.ships[..i].contains(&length);
+    match (length, duplicate) {
+        (5, _) => "Aircraft Carrier H OR V == 0",
+        (4, _) => "Battleship H OR V == 0",
+        (3, false) => "Cruiser H OR V == 0",
+        (3, true) => "Submarine H OR V == 0",
+        (2, _) => "Destroyer H OR V == 0",
+        _ => "Ship H OR V == 0",
+    }
+}
+
+// placement config for a single ship, dispatched on ship length
 #[derive(Clone, Copy, Debug)]
-pub struct PlacementConfigs {
-    carrier: PlacementConfig<pallas::Base, 5>,
-    battleship: PlacementConfig<pallas::Base, 4>,
-    cruiser: PlacementConfig<pallas::Base, 3>,
-    submarine: PlacementConfig<pallas::Base, 3>,
-    destroyer: PlacementConfig<pallas::Base, 2>,
+pub enum ShipPlacementConfig {
+    Length2(PlacementConfig<pallas::Base, 2>),
+    Length3(PlacementConfig<pallas::Base, 3>),
+    Length4(PlacementConfig<pallas::Base, 4>),
+    Length5(PlacementConfig<pallas::Base, 5>),
+}
+
+// bundles all placement configs together in fleet order
+pub type PlacementConfigs = Vec<ShipPlacementConfig>;
+
+impl ShipPlacementConfig {
+    /**
+     * Configure a placement chip for a ship of a given length
+     * @dev panics if the length is not in [MIN_SHIP_LENGTH, MAX_SHIP_LENGTH]
+     *
+     * @param length - the length of the ship
+     * @return - the placement config wrapped for the given length
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        length: usize,
+        bits: Column<Advice>,
+        bit_sum: Column<Advice>,
+        full_window_sum: Column<Advice>,
+        fixed: Column<Fixed>,
+    ) -> ShipPlacementConfig {
+        match length {
+            2 => ShipPlacementConfig::Length2(PlacementChip::<pallas::Base, 2>::configure(
+                meta,
+                bits,
+                bit_sum,
+                full_window_sum,
+                fixed,
+            )),
+            3 => ShipPlacementConfig::Length3(PlacementChip::<pallas::Base, 3>::configure(
+                meta,
+                bits,
+                bit_sum,
+                full_window_sum,
+                fixed,
+            )),
+            4 => ShipPlacementConfig::Length4(PlacementChip::<pallas::Base, 4>::configure(
+                meta,
+                bits,
+                bit_sum,
+                full_window_sum,
+                fixed,
+            )),
+            5 => ShipPlacementConfig::Length5(PlacementChip::<pallas::Base, 5>::configure(
+                meta,
+                bits,
+                bit_sum,
+                full_window_sum,
+                fixed,
+            )),
+            _ => panic!("Unsupported ship length {}", length),
+        }
+    }
+
+    /**
+     * Synthesize the placement chip for this ship
+     *
+     * @param ship - the zipped H/V ship commitment
+     * @param horizontal - assigned bits of the horizontal commitment
+     * @param vertical - assigned bits of the vertical commitment
+     * @return - Ok if the placement synthesized successfully
+     */
+    pub fn synthesize(
+        self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        ship: &BinaryValue,
+        horizontal: &AssignedBits<pallas::Base>,
+        vertical: &AssignedBits<pallas::Base>,
+    ) -> Result<(), Error> {
+        match self {
+            ShipPlacementConfig::Length2(config) => PlacementChip::<pallas::Base, 2>::new(config)
+                .synthesize(layouter, ship, horizontal, vertical),
+            ShipPlacementConfig::Length3(config) => PlacementChip::<pallas::Base, 3>::new(config)
+                .synthesize(layouter, ship, horizontal, vertical),
+            ShipPlacementConfig::Length4(config) => PlacementChip::<pallas::Base, 4>::new(config)
+                .synthesize(layouter, ship, horizontal, vertical),
+            ShipPlacementConfig::Length5(config) => PlacementChip::<pallas::Base, 5>::new(config)
+                .synthesize(layouter, ship, horizontal, vertical),
+        }
+    }
 }
 
 /**
  * Contains all storage needed to verify a battleship board
+ * @dev column sharing: every sub-chip is configured over the same ColumnSet rather than its own
+ *      columns, so the width of the board circuit is set by its widest chip, not the sum of them
+ *      * advice[0..3] and fixed[0] carry all num2bits, bits2num and placement chips (bits, running
+ *        sums and constants), which is why their regions stack vertically
+ *      * advice[0..10] hold the 10 ship commitments side by side in one row, are read as the
+ *        permuted bits by the transpose chip, and are the 10 ecc columns of the pedersen chip
+ *      * the extra advice column past the shared ten holds the transposed board bits
+ *      * fixed[0..8] are the lagrange coefficients of the pedersen fixed base multiplications
+ *      approximate row ranges with the standard fleet, in synthesis order:
+ *      * 0: ship commitments and the H OR V == 0 orientation gate
+ *      * ~1-1010: 10 num2bits decompositions of 101 rows each
+ *      * ~1011-2020: 5 placement chips of 202 rows each (100 rows collapsing H + V, a padding
+ *        row, 100 running sum rows and 1 output row)
+ *      * ~2021-2120: transposition of the 10 placements into the board state
+ *      * ~2121-2221: bits2num recomposition of the board state
+ *      * ~2222 onwards: pedersen commitment (scalar decompositions and fixed base multiplications)
+ *      the 2^10 row range check table sits in table_idx alongside the advice rows
  */
 #[derive(Clone, Debug)]
 pub struct BoardConfig {
-    // chip configs
-    pub num2bits: [BitifyConfig; 10],
+    /**
+     * Ship lengths the board is configured for
+     * @dev sets the number of commitments (2 per ship) and placement chips
+     */
+    pub fleet: FleetConfig,
+    /**
+     * Num2Bits chip for each ship commitment, in commitment order
+     * @dev all are configured over advice[0..3] and fixed[0]
+     */
+    pub num2bits: Vec<BitifyConfig>,
+    /**
+     * Bits2Num chip recomposing the transposed board bits into the committed board state
+     * @dev configured over advice[0..3] and fixed[0]
+     */
     pub bits2num: BitifyConfig,
+    /**
+     * Placement chip for each ship of the fleet, dispatched on ship length
+     * @dev configured over advice[0..3] and fixed[0]
+     */
     pub placement: PlacementConfigs,
+    /**
+     * Chip summing the 10 decomposed commitments bit by bit into the board state
+     * @dev reads advice[0..commitments] and writes the last advice column
+     */
     pub transpose: TransposeConfig<pallas::Base>,
+    /**
+     * Pedersen commitment to the recomposed board state
+     * @dev ecc chip over advice[0..10] and fixed[0..8]; range check lookup on advice[9]
+     */
     pub pedersen: PedersenCommitmentConfig,
-    // columns
-    pub advice: [Column<Advice>; 11],
+    /**
+     * Equality enabled advice columns: the 10 shared columns then any extra columns
+     * @dev the last column always holds the transposed board bits
+     */
+    pub advice: Vec<Column<Advice>>,
+    /**
+     * Fixed columns; fixed[0] has constants enabled for the bitify and placement chips
+     */
     pub fixed: [Column<Fixed>; 8],
+    /**
+     * Lookup table column for the pedersen range check
+     */
     pub table_idx: TableColumn,
+    /**
+     * Instance column the (x, y) board commitment is exported to
+     */
     pub instance: Column<Instance>,
-    // selectors
+    /**
+     * Selectors owned by the board chip itself
+     * @dev [orientation], toggling the H OR V == 0 gate on the commitment row
+     */
     pub selectors: [Selector; 1],
 }
 
+impl BoardConfig {
+    /**
+     * Return the advice column holding bit decompositions
+     * @dev shared by every num2bits, bits2num and placement chip of the board
+     *
+     * @return - the bits column
+     */
+    pub fn bits_column(&self) -> Column<Advice> {
+        self.advice[0]
+    }
+
+    /**
+     * Return the instance column the board commitment is exported to
+     *
+     * @return - the instance column
+     */
+    pub fn instance_column(&self) -> Column<Instance> {
+        self.instance
+    }
+
+    /**
+     * Return the fixed column shared by the bitify and placement chips for constants
+     *
+     * @return - the constant enabled fixed column
+     */
+    pub fn shared_fixed(&self) -> Column<Fixed> {
+        self.fixed[0]
+    }
+
+    /**
+     * Return the placement chip config of each ship
+     *
+     * @return - placement configs in fleet order
+     */
+    pub fn placement_configs(&self) -> &[ShipPlacementConfig] {
+        &self.placement
+    }
+}
+
 /**
  * Circuit for proving a valid battleship board configuration
  *    * prove 5 types of ships placed correctly
@@ -102,15 +300,15 @@ impl Chip<pallas::Base> for BoardChip {
  */
 pub trait BoardInstructions {
     /**
-     * Load the 10 ship placement commitments
+     * Load the H and V ship placement commitments for each ship in the fleet
      *
-     * @param ship_commitments - array of 10 BinaryValues - H and V commitments for each ship
-     * @return - array of 10 AssignedCells storing ship commitments in chip
+     * @param ship_commitments - BinaryValues - H and V commitments for each ship
+     * @return - AssignedCells storing ship commitments in chip
      */
     fn load_commitments(
         &self,
         layouter: &mut impl Layouter<pallas::Base>,
-        ship_commitments: &[BinaryValue; 10],
+        ship_commitments: &[BinaryValue],
     ) -> Result<Commitments, Error>;
 
     /**
@@ -122,8 +320,8 @@ pub trait BoardInstructions {
     fn decompose_commitments(
         &self,
         layouter: &mut impl Layouter<pallas::Base>,
-        ship_commitments: &[BinaryValue; 10],
-        commitment: &[AssignedCell<pallas::Base, pallas::Base>; 10],
+        ship_commitments: &[BinaryValue],
+        commitment: &Commitments,
     ) -> Result<Placements, Error>;
 
     /**
@@ -136,7 +334,7 @@ pub trait BoardInstructions {
     fn synth_placements(
         &self,
         layouter: &mut impl Layouter<pallas::Base>,
-        ships: &[BinaryValue; 5],
+        ships: &[BinaryValue],
         placements: &Placements,
     ) -> Result<(), Error>;
 
@@ -190,47 +388,88 @@ impl BoardChip {
 
     /**
      * Configure the computation space of the circuit & return BoardConfig
+     * @dev panics if the fleet fails FleetConfig::validate()
+     * @dev allocates one ColumnSet plus the extra advice columns and configures every sub-chip
+     *      over it (see BoardConfig for which chip uses which columns)
+     *      * num2bits x 2 per ship, bits2num and placement x 1 per ship share advice[0..3]
+     *      * transpose reads the commitment columns and writes the last advice column
+     *      * pedersen uses advice[0..10], fixed[0..8] and the lookup table column
+     * @dev layout: BoardCircuit uses SimpleFloorPlanner rather than V1. Since nearly every region
+     *      touches advice[0], V1 could not pack regions side by side anyway, while the simple
+     *      planner lays regions out in synthesis order in a single pass. That keeps the layout,
+     *      and with it the golden verifying keys and the region offsets tests assert failures
+     *      at, stable across refactors
+     * @dev k: the num2bits and placement regions alone take ~2,020 rows with the standard fleet,
+     *      which leaves no room for the transpose, bits2num and pedersen regions in 2^11 rows
+     *      (less the blinding rows halo2 reserves), so BOARD_CIRCUIT_K = 12. BoardCircuit::min_k()
+     *      derives this from the layout and is tested to match the constant
+     *
+     * @param fleet - the ship lengths to configure placement chips for
      */
-    pub fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> BoardConfig {
-        // define advice
-        let mut advice = Vec::<Column<Advice>>::new();
-        for _ in 0..11 {
-            let col = meta.advice_column();
-            meta.enable_equality(col);
-            advice.push(col);
-        }
-        let advice: [Column<Advice>; 11] = advice.try_into().unwrap();
-
-        // define fixed
-        let mut fixed = Vec::<Column<Fixed>>::new();
-        for _ in 0..8 {
-            fixed.push(meta.fixed_column());
-        }
-        let fixed: [Column<Fixed>; 8] = fixed.try_into().unwrap();
-        meta.enable_constant(fixed[0]);
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        fleet: &FleetConfig,
+    ) -> BoardConfig {
+        let (columns, extra_advice) =
+            ColumnSet::configure(meta, BoardChip::extra_advice_columns(fleet));
+        BoardChip::configure_with_columns(meta, fleet, columns, &extra_advice)
+    }
 
-        // define table column
-        let table_idx = meta.lookup_table_column();
+    /**
+     * Return the number of advice columns the board needs beyond those of a ColumnSet
+     * @dev one column per commitment (at least 10 for pedersen) plus one for the transposed bits
+     *
+     * @param fleet - the ship lengths the board is configured for
+     * @return - the number of extra advice columns to pass to configure_with_columns()
+     */
+    pub fn extra_advice_columns(fleet: &FleetConfig) -> usize {
+        fleet.commitments().max(10) + 1 - 10
+    }
 
-        // define instance column
-        let instance = meta.instance_column();
-        meta.enable_equality(instance);
+    /**
+     * Configure the chip over columns owned by the circuit & return BoardConfig
+     * @dev panics if the fleet fails FleetConfig::validate() or extra_advice does not hold
+     *      extra_advice_columns(fleet) columns
+     *
+     * @param fleet - the ship lengths to configure placement chips for
+     * @param columns - the columns to configure the chip over
+     * @param extra_advice - equality enabled advice columns following the shared advice columns
+     */
+    pub fn configure_with_columns(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        fleet: &FleetConfig,
+        columns: ColumnSet,
+        extra_advice: &[Column<Advice>],
+    ) -> BoardConfig {
+        if let Err(reason) = fleet.validate() {
+            panic!("{}", reason);
+        }
+        let commitments = fleet.commitments();
+        if extra_advice.len() != BoardChip::extra_advice_columns(fleet) {
+            panic!(
+                "Board needs {} extra advice columns, found {}",
+                BoardChip::extra_advice_columns(fleet),
+                extra_advice.len()
+            );
+        }
+        let ColumnSet {
+            fixed,
+            instance,
+            table_idx,
+            ..
+        } = columns;
+        let advice = [columns.advice.as_slice(), extra_advice].concat();
 
         // define selectors
-        let mut selectors = Vec::<Selector>::new();
-        for _ in 0..1 {
-            selectors.push(meta.selector());
-        }
-        let selectors: [Selector; 1] = selectors.try_into().unwrap();
+        let selectors = [(); 1].map(|_| meta.selector());
 
         // define num2bits chips
         let mut num2bits = Vec::<BitifyConfig>::new();
-        for _ in 0..10 {
+        for _ in 0..commitments {
             num2bits.push(Num2BitsChip::<_, BOARD_SIZE>::configure(
                 meta, advice[0], advice[1], advice[2], fixed[0],
             ));
         }
-        let num2bits: [BitifyConfig; 10] = num2bits.try_into().unwrap();
 
         // define bits2num chip
         let bits2num = Bits2NumChip::<_, BOARD_SIZE>::configure(
@@ -238,75 +477,49 @@ impl BoardChip {
         );
 
         // define placement chips
-        let placement = PlacementConfigs {
-            carrier: PlacementChip::<pallas::Base, 5>::configure(
-                meta, advice[0], advice[1], advice[2], fixed[0],
-            ),
-            battleship: PlacementChip::<pallas::Base, 4>::configure(
-                meta, advice[0], advice[1], advice[2], fixed[0],
-            ),
-            cruiser: PlacementChip::<pallas::Base, 3>::configure(
-                meta, advice[0], advice[1], advice[2], fixed[0],
-            ),
-            submarine: PlacementChip::<pallas::Base, 3>::configure(
-                meta, advice[0], advice[1], advice[2], fixed[0],
-            ),
-            destroyer: PlacementChip::<pallas::Base, 2>::configure(
-                meta, advice[0], advice[1], advice[2], fixed[0],
-            ),
-        };
+        let placement: PlacementConfigs = fleet
+            .ships
+            .iter()
+            .map(|length| {
+                ShipPlacementConfig::configure(
+                    meta, *length, advice[0], advice[1], advice[2], fixed[0],
+                )
+            })
+            .collect();
 
         // define transpose chip
         let transpose = TransposeChip::<pallas::Base>::configure(
             meta,
-            advice[0..10].try_into().unwrap(),
-            advice[10],
+            advice[0..commitments].to_vec(),
+            advice[advice.len() - 1],
         );
 
         // define pedersen chip
-        let pedersen = PedersenCommitmentChip::configure(
-            meta,
-            advice[0..10].try_into().unwrap(),
-            fixed,
-            table_idx,
-        );
+        let pedersen = PedersenCommitmentChip::configure(meta, columns.advice, fixed, table_idx);
 
         // define gates
-        meta.create_gate("Commitment orientation H OR V == 0 constraint", |meta| {
+        meta.create_gate(GATE_ORIENTATION_ZERO, |meta| {
             let mut commitments = Vec::<Expression<pallas::Base>>::new();
-            for i in 0..10 {
-                commitments.push(meta.query_advice(advice[i], Rotation::cur()));
+            for column in advice[0..fleet.commitments()].iter() {
+                commitments.push(meta.query_advice(*column, Rotation::cur()));
             }
             let selector = meta.query_selector(selectors[0]);
             Constraints::with_selector(
                 selector,
-                [
-                    (
-                        "Aircraft Carrier H OR V == 0",
-                        commitments[0].clone() * commitments[1].clone(),
-                    ),
-                    (
-                        "Battleship H OR V == 0",
-                        commitments[2].clone() * commitments[3].clone(),
-                    ),
-                    (
-                        "Cruiser H OR V == 0",
-                        commitments[4].clone() * commitments[5].clone(),
-                    ),
-                    (
-                        "Submarine H OR V == 0",
-                        commitments[6].clone() * commitments[7].clone(),
-                    ),
-                    (
-                        "Destroyer H OR V == 0",
-                        commitments[8].clone() * commitments[9].clone(),
-                    ),
-                ],
+                (0..fleet.ships.len())
+                    .map(|i| {
+                        (
+                            orientation_label(fleet, i),
+                            commitments[i * 2].clone() * commitments[i * 2 + 1].clone(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
             )
         });
 
         // return config
         BoardConfig {
+            fleet: *fleet,
             num2bits,
             bits2num,
             placement,
@@ -323,7 +536,7 @@ impl BoardChip {
     /**
      * Synthesize a proof of a valid board
      *
-     * @param ship_commitments - 10x private ship commitments indicating a horizontal or vertical placement
+     * @param ship_commitments - 2 private ship commitments per ship in the fleet indicating a horizontal or vertical placement
      * @param board - board state as a BinaryValue
      * @param board_commitment_trapdoor - the trapdoor for the board commitment
      * @return - Ok if the proof synthesizes successfully
@@ -331,60 +544,107 @@ impl BoardChip {
     pub fn synthesize(
         &self,
         mut layouter: impl Layouter<pallas::Base>,
-        ship_commitments: [BinaryValue; 10],
+        ship_commitments: &[BinaryValue],
         board: BinaryValue,
         board_commitment_trapdoor: pallas::Scalar,
     ) -> Result<(), Error> {
-        // compute combined ship commitments
-        let mut ships = Vec::<BinaryValue>::new();
-        for i in 0..5 {
-            ships.push(ship_commitments[i * 2].zip(ship_commitments[i * 2 + 1]));
-        }
-        let ships: [BinaryValue; 5] = ships.try_into().unwrap();
-        // load ship commitments into advice
-        let assigned_commitments = self.load_commitments(&mut layouter, &ship_commitments)?;
-        // decompose commitments into 100 bits each
-        let placements =
-            self.decompose_commitments(&mut layouter, &ship_commitments, &assigned_commitments)?;
-        // run individual ship placement rule checks
-        self.synth_placements(&mut layouter, &ships, &placements)?;
-        // check that ships can all be placed together to form a valid board
-        let transposed_bits = self.transpose_placements(&mut layouter, &board, &placements)?;
-        // recompose the 100 bit board state into a single value
-        let transposed = self.recompose_board(&mut layouter, &board, &transposed_bits)?;
+        // constrain a valid board state from the ship placements
+        let (transposed, _) =
+            self.synthesize_board_state(&mut layouter, ship_commitments, board)?;
         // synthesize pedersen commitment to board state
-        let commitment =
-            self.commit_board(&mut layouter, &transposed, &board_commitment_trapdoor)?;
+        let commitment = traced("board", "commit_board", 1, || {
+            self.commit_board(&mut layouter, &transposed, &board_commitment_trapdoor)
+        })?;
         // export constained board commitment to public instance column
         layouter.constrain_instance(commitment[0].cell(), self.config.instance, 0)?;
         layouter.constrain_instance(commitment[1].cell(), self.config.instance, 1)?;
 
         Ok(())
     }
+
+    /**
+     * Synthesize the placement and transposition checks of a valid board without committing to it
+     * @dev used by circuits that reuse the board decomposition (e.g. TurnCircuit)
+     *
+     * @param ship_commitments - 2 private ship commitments per ship in the fleet indicating a horizontal or vertical placement
+     * @param board - board state as a BinaryValue
+     * @return - assigned board state and its constrained LE decomposition
+     */
+    pub fn synthesize_board_state(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        ship_commitments: &[BinaryValue],
+        board: BinaryValue,
+    ) -> Result<
+        (
+            AssignedCell<pallas::Base, pallas::Base>,
+            AssignedBits<pallas::Base>,
+        ),
+        Error,
+    > {
+        assert_eq!(
+            ship_commitments.len(),
+            self.config.fleet.commitments(),
+            "Expected an H and V commitment for each ship in the fleet"
+        );
+        // compute combined ship commitments
+        let ships = ship_commitments
+            .chunks(2)
+            .map(|commitments| commitments[0].zip(commitments[1]))
+            .collect::<Vec<BinaryValue>>();
+        let commitments = ship_commitments.len();
+        // load ship commitments into advice
+        let assigned_commitments = traced("board", "load_commitments", commitments, || {
+            self.load_commitments(layouter, ship_commitments)
+        })?;
+        // decompose commitments into 100 bits each
+        let placements = traced("board", "decompose_commitments", commitments, || {
+            self.decompose_commitments(layouter, ship_commitments, &assigned_commitments)
+        })?;
+        // run individual ship placement rule checks
+        traced(
+            "board",
+            "synth_placements",
+            commitments * BOARD_SIZE,
+            || self.synth_placements(layouter, &ships, &placements),
+        )?;
+        // check that ships can all be placed together to form a valid board
+        let transposed_bits = traced(
+            "board",
+            "transpose_placements",
+            (commitments + 1) * BOARD_SIZE,
+            || self.transpose_placements(layouter, &board, &placements),
+        )?;
+        // recompose the 100 bit board state into a single value
+        let transposed = traced("board", "recompose_board", BOARD_SIZE, || {
+            self.recompose_board(layouter, &board, &transposed_bits)
+        })?;
+        Ok((transposed, transposed_bits))
+    }
 }
 
 impl BoardInstructions for BoardChip {
     fn load_commitments(
         &self,
         layouter: &mut impl Layouter<pallas::Base>,
-        ship_commitments: &[BinaryValue; 10],
+        ship_commitments: &[BinaryValue],
     ) -> Result<Commitments, Error> {
-        let assigned: [AssignedCell<pallas::Base, pallas::Base>; 10] = layouter.assign_region(
+        let assigned: Commitments = layouter.assign_region(
             || "load ship placements",
             |mut region: Region<pallas::Base>| {
                 // assign ship commitments
                 let mut cells = Vec::<AssignedCell<pallas::Base, pallas::Base>>::new();
-                for i in 0..10 {
+                for i in 0..ship_commitments.len() {
                     let label = commitment_label(i);
                     cells.push(region.assign_advice(
                         || format!("{} ship commitment", label),
                         self.config.advice[i],
                         0,
-                        || Value::known(pallas::Base::from_u128(ship_commitments[i].lower_u128())),
+                        || Value::known(ship_commitments[i].to_fp::<pallas::Base>()),
                     )?);
                 }
-                _ = self.config.selectors[0].enable(&mut region, 0);
-                Ok(cells.try_into().unwrap())
+                self.config.selectors[0].enable(&mut region, 0)?;
+                Ok(cells)
             },
         )?;
         Ok(assigned)
@@ -393,11 +653,11 @@ impl BoardInstructions for BoardChip {
     fn decompose_commitments(
         &self,
         layouter: &mut impl Layouter<pallas::Base>,
-        ship_commitments: &[BinaryValue; 10],
-        assigned_commitments: &[AssignedCell<pallas::Base, pallas::Base>; 10],
+        ship_commitments: &[BinaryValue],
+        assigned_commitments: &Commitments,
     ) -> Result<Placements, Error> {
         let mut placements = Vec::<AssignedBits<pallas::Base>>::new();
-        for i in 0..10 {
+        for i in 0..ship_commitments.len() {
             let bits = ship_commitments[i].bitfield::<pallas::Base, BOARD_SIZE>();
             let num2bits = Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(
                 assigned_commitments[i].clone(),
@@ -410,45 +670,23 @@ impl BoardInstructions for BoardChip {
             )?;
             placements.push(AssignedBits::<pallas::Base>::from(assigned_bits));
         }
-        Ok(placements.try_into().unwrap())
+        Ok(placements)
     }
 
     fn synth_placements(
         &self,
         layouter: &mut impl Layouter<pallas::Base>,
-        ships: &[BinaryValue; 5],
+        ships: &[BinaryValue],
         placements: &Placements,
     ) -> Result<(), Error> {
-        PlacementChip::<pallas::Base, 5>::new(self.config.placement.carrier).synthesize(
-            layouter,
-            &ships[0],
-            &placements[0],
-            &placements[1],
-        )?;
-        PlacementChip::<pallas::Base, 4>::new(self.config.placement.battleship).synthesize(
-            layouter,
-            &ships[1],
-            &placements[2],
-            &placements[3],
-        )?;
-        PlacementChip::<pallas::Base, 3>::new(self.config.placement.cruiser).synthesize(
-            layouter,
-            &ships[2],
-            &placements[4],
-            &placements[5],
-        )?;
-        PlacementChip::<pallas::Base, 3>::new(self.config.placement.submarine).synthesize(
-            layouter,
-            &ships[3],
-            &placements[6],
-            &placements[7],
-        )?;
-        PlacementChip::<pallas::Base, 2>::new(self.config.placement.destroyer).synthesize(
-            layouter,
-            &ships[4],
-            &placements[8],
-            &placements[9],
-        )?;
+        for (i, placement) in self.config.placement.iter().enumerate() {
+            placement.synthesize(
+                layouter,
+                &ships[i],
+                &placements[i * 2],
+                &placements[i * 2 + 1],
+            )?;
+        }
         Ok(())
     }
 
@@ -458,9 +696,9 @@ impl BoardInstructions for BoardChip {
         board: &BinaryValue,
         placements: &Placements,
     ) -> Result<AssignedBits<pallas::Base>, Error> {
-        let chip = TransposeChip::<pallas::Base>::new(self.config.transpose);
+        let chip = TransposeChip::<pallas::Base>::new(self.config.transpose.clone());
         let bits = board.bitfield::<pallas::Base, BOARD_SIZE>();
-        Ok(chip.synthesize(layouter, &bits, placements).unwrap())
+        chip.synthesize(layouter, &bits, placements)
     }
 
     fn recompose_board(
@@ -470,7 +708,7 @@ impl BoardInstructions for BoardChip {
         transposed: &[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE],
     ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
         Ok(Bits2NumChip::<pallas::Base, BOARD_SIZE>::new(
-            pallas::Base::from_u128(board.lower_u128()),
+            board.to_fp(),
             transposed,
         )
         .synthesize(
@@ -492,9 +730,6 @@ impl BoardInstructions for BoardChip {
             Value::known(board_commitment_trapdoor.clone()),
         )?;
         // return pedersen commitment points
-        Ok([
-            commitment.clone().inner().x(),
-            commitment.clone().inner().y(),
-        ])
+        Ok(commitment.coordinates())
     }
 }
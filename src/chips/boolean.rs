@@ -0,0 +1,223 @@
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Region, Value},
+    pasta::pallas,
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+/**
+ * Storage for a gate constraining advice cells to 0 or 1
+ *
+ * @param column - equality enabled advice column the checked values are assigned in
+ * @param selector - selector toggling the boolean gate
+ */
+#[derive(Clone, Copy, Debug)]
+pub struct BoolCheckConfig {
+    pub column: Column<Advice>,
+    pub selector: Selector,
+}
+
+pub struct BoolCheckChip {
+    config: BoolCheckConfig,
+}
+
+impl Chip<pallas::Base> for BoolCheckChip {
+    type Config = BoolCheckConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl BoolCheckChip {
+    pub fn new(config: BoolCheckConfig) -> Self {
+        BoolCheckChip { config }
+    }
+
+    /**
+     * Configure the boolean gate & return BoolCheckConfig
+     * @dev the caller allocates the selector so the gate keeps its place among the caller's
+     *      selectors, leaving the verifying keys of existing circuits unchanged
+     *
+     * @param name - name of the created gate, reported by VerifyFailure
+     * @param column - equality enabled advice column the checked values are assigned in
+     * @param selector - selector toggling the gate
+     * @return - configured BoolCheckConfig
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        name: &'static str,
+        column: Column<Advice>,
+        selector: Selector,
+    ) -> BoolCheckConfig {
+        meta.create_gate(name, |meta| {
+            let value = meta.query_advice(column, Rotation::cur());
+            let one = Expression::Constant(pallas::Base::one());
+            // constrain using selector
+            // - (1 - value) * value == 0
+            let selector = meta.query_selector(selector);
+            Constraints::with_selector(
+                selector,
+                [("value is boolean", (one - value.clone()) * value)],
+            )
+        });
+        BoolCheckConfig { column, selector }
+    }
+
+    /**
+     * Assign a value constrained to 0 or 1
+     *
+     * @param region - region to assign the value in
+     * @param annotation - name of the assigned cell
+     * @param offset - row of the region to assign the value at
+     * @param value - the value to assign
+     * @return - the assigned cell
+     */
+    pub fn assign_bool(
+        &self,
+        region: &mut Region<'_, pallas::Base>,
+        annotation: &str,
+        offset: usize,
+        value: Value<pallas::Base>,
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let cell = region.assign_advice(|| annotation, self.config.column, offset, || value)?;
+        self.config.selector.enable(region, offset)?;
+        Ok(cell)
+    }
+
+    /**
+     * Constrain an already assigned cell to 0 or 1
+     * @dev copies the cell into the boolean column of the region
+     *
+     * @param region - region to copy the cell into
+     * @param cell - the assigned cell to check
+     * @param offset - row of the region to copy the cell to
+     * @return - the copy of the cell
+     */
+    pub fn constrain_bool(
+        &self,
+        region: &mut Region<'_, pallas::Base>,
+        cell: &AssignedCell<pallas::Base, pallas::Base>,
+        offset: usize,
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let copied = cell.copy_advice(|| "copy boolean", region, self.config.column, offset)?;
+        self.config.selector.enable(region, offset)?;
+        Ok(copied)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        halo2_proofs::{
+            circuit::{Layouter, SimpleFloorPlanner},
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            plonk::{Any, Circuit},
+        },
+    };
+
+    const GATE_TEST_BOOLEAN: &str = "test boolean";
+
+    #[derive(Clone, Debug)]
+    struct BoolCheckCircuit {
+        values: [u64; 2],
+    }
+
+    impl Circuit<pallas::Base> for BoolCheckCircuit {
+        type Config = (BoolCheckConfig, Column<Advice>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let [input, column] = [(); 2].map(|_| {
+                let column = meta.advice_column();
+                meta.enable_equality(column);
+                column
+            });
+            let selector = meta.selector();
+            let config = BoolCheckChip::configure(meta, GATE_TEST_BOOLEAN, column, selector);
+            (config, input)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = BoolCheckChip::new(config.0);
+            // the first value is assigned through the chip
+            layouter.assign_region(
+                || "assign boolean",
+                |mut region| {
+                    let value = Value::known(pallas::Base::from(self.values[0]));
+                    chip.assign_bool(&mut region, "assigned value", 0, value)
+                },
+            )?;
+            // the second value is assigned elsewhere and checked afterwards
+            let cell = layouter.assign_region(
+                || "assign unchecked value",
+                |mut region| {
+                    region.assign_advice(
+                        || "unchecked value",
+                        config.1,
+                        0,
+                        || Value::known(pallas::Base::from(self.values[1])),
+                    )
+                },
+            )?;
+            layouter.assign_region(
+                || "constrain boolean",
+                |mut region| chip.constrain_bool(&mut region, &cell, 0),
+            )?;
+            Ok(())
+        }
+    }
+
+    /**
+     * Expected failure of a non boolean value
+     *
+     * @param region - (index, name) of the region the value is checked in
+     * @param value - the displayed value of the checked cell
+     * @return - the gate failure reported by the mock prover
+     */
+    fn failure(region: (usize, &str), value: &str) -> VerifyFailure {
+        VerifyFailure::ConstraintNotSatisfied {
+            constraint: ((0, GATE_TEST_BOOLEAN).into(), 0, "value is boolean").into(),
+            location: FailureLocation::InRegion {
+                region: region.into(),
+                offset: 0,
+            },
+            cell_values: vec![(((Any::Advice, 1).into(), 0).into(), String::from(value))],
+        }
+    }
+
+    #[test]
+    fn valid_booleans() {
+        for values in [[0, 0], [0, 1], [1, 0], [1, 1]] {
+            let prover = MockProver::run(4, &BoolCheckCircuit { values }, vec![]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn invalid_two() {
+        // 2 fails the gate whether it was assigned or copied into the boolean column
+        let prover = MockProver::run(4, &BoolCheckCircuit { values: [2, 2] }, vec![]).unwrap();
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                failure((0, "assign boolean"), "0x2"),
+                failure((2, "constrain boolean"), "0x2"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use halo2_proofs::{
+    pasta::pallas,
+    plonk::{Advice, Column, ConstraintSystem, Fixed, Instance, TableColumn},
+};
+
+/**
+ * Columns a chip is configured over, owned by the circuit so several chips can share them
+ *
+ * @param advice - equality enabled advice columns
+ * @param fixed - fixed columns; fixed[0] has constants enabled
+ * @param instance - equality enabled instance column public values are exported to
+ * @param table_idx - lookup table column for the pedersen range check
+ */
+#[derive(Clone, Copy, Debug)]
+pub struct ColumnSet {
+    pub advice: [Column<Advice>; 10],
+    pub fixed: [Column<Fixed>; 8],
+    pub instance: Column<Instance>,
+    pub table_idx: TableColumn,
+}
+
+impl ColumnSet {
+    /**
+     * Allocate a column set & any additional advice columns a chip needs
+     * @dev the extra advice columns are allocated right after the shared advice columns, which is
+     *      the order BoardChip and ShotChip always allocated their columns in, so verifying keys of
+     *      circuits built on their convenience constructors do not change
+     *
+     * @param extra_advice - number of equality enabled advice columns to allocate beyond the 10 shared
+     * @return - the column set and the extra advice columns
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        extra_advice: usize,
+    ) -> (ColumnSet, Vec<Column<Advice>>) {
+        // define advice
+        let mut advice = Vec::<Column<Advice>>::new();
+        for _ in 0..10 + extra_advice {
+            let col = meta.advice_column();
+            meta.enable_equality(col);
+            advice.push(col);
+        }
+        let extra = advice.split_off(10);
+
+        // define fixed
+        let fixed = [(); 8].map(|_| meta.fixed_column());
+
+        // fixed[0] has constant enabled
+        meta.enable_constant(fixed[0]);
+
+        // define table column
+        let table_idx = meta.lookup_table_column();
+
+        // define instance
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        let columns = ColumnSet {
+            advice: advice.try_into().unwrap(),
+            fixed,
+            instance,
+            table_idx,
+        };
+        (columns, extra)
+    }
+}
@@ -0,0 +1,49 @@
+// Names of every custom gate created by the chips, shared by create_gate() and VerifyFailure assertions
+
+// BitifyConfig: decompose a number into constrained bits
+pub const GATE_NUM2BITS: &str = "num2bits";
+// BitifyConfig: compose constrained bits into a number
+pub const GATE_BITS2NUM: &str = "bits2num";
+
+// PlacementChip: add the H and V commitment bits of a ship
+pub const GATE_SUM_HV_BITS: &str = "sum inputted H, V bits";
+// PlacementChip: running count of placed bits
+pub const GATE_PLACEMENT_BIT_COUNT: &str = "placement bit count";
+// PlacementChip: count of consecutive bits in the current window
+pub const GATE_ADJACENCY_BIT_COUNT: &str = "adjacency bit count";
+// PlacementChip: permute the adjacency count into the running sum
+pub const GATE_PERMUTE_ADJACENCY_BIT_COUNT: &str = "permute adjaceny bit count";
+// PlacementChip: constrain the running sum output to one full window of ship length
+pub const GATE_RUNNING_SUM_CONSTRAINTS: &str = "running sum constraints";
+
+// BoardChip: one of the H or V commitments of each ship is 0
+pub const GATE_ORIENTATION_ZERO: &str = "Commitment orientation H OR V == 0 constraint";
+
+// TransposeChip: transposed board bit equals the sum of permuted placement bits
+pub const GATE_TRANSPOSE_ROW: &str = "transpose row constraint";
+
+// ShotChip: hit assertion is 0 or 1
+pub const GATE_BOOLEAN_HIT_ASSERTION: &str = "boolean hit assertion";
+// ShotChip: running sum of shot bits and hits against the board
+pub const GATE_SHOT_RUNNING_SUM_ROW: &str = "shot running sum row";
+// ShotChip: one shot bit is set and the hit count matches the assertion
+pub const GATE_SHOT_RUNNING_SUM_OUTPUT: &str = "constrain shot running sum output";
+
+// ShotChip: pack the shot index and hit assertion into one public output
+pub const GATE_SHOT_PACKING: &str = "pack shot index and hit";
+
+// ShotIndexChip: running sum of bit index * bit over the shot decomposition
+pub const GATE_SHOT_INDEX_SUM: &str = "shot index weighted sum";
+// ShotIndexChip: shift the shot index so the lookup bounds it by the board size
+pub const GATE_SHOT_INDEX_BOUND: &str = "shot index bound";
+
+// RevealChip: claimed hits lie on the revealed board and claimed misses do not
+pub const GATE_REVEAL_CONSISTENCY: &str = "reveal consistency";
+
+// SymmetryChip: running count of bits a permuted board disagrees with the compared board on
+pub const GATE_SYMMETRY_DIFF: &str = "symmetry mismatch count";
+// SymmetryChip: select the index of the first symmetry with no mismatches
+pub const GATE_SYMMETRY_INDEX: &str = "symmetry index";
+
+// HistoryChip: a new shot differs from each of the last T shots in the history
+pub const GATE_HISTORY_NON_MEMBERSHIP: &str = "history non-membership";
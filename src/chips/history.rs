@@ -0,0 +1,241 @@
+use {
+    crate::chips::gate_names::GATE_HISTORY_NON_MEMBERSHIP,
+    halo2_gadgets::poseidon::{
+        primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+        Hash, Pow5Chip, Pow5Config,
+    },
+    halo2_proofs::{
+        circuit::{AssignedCell, Chip, Layouter, Value},
+        pasta::pallas,
+        plonk::{
+            Advice, Column, ConstraintSystem, Constraints, Error, Expression, Fixed, Selector,
+        },
+        poly::Rotation,
+    },
+};
+
+/**
+ * Fold one shot into the shot history accumulator outside of the circuit
+ *
+ * @param h_prev - accumulator over every earlier shot
+ * @param shot - serialized shot commitment
+ * @return - Poseidon(h_prev, shot)
+ */
+pub fn history_hash(h_prev: pallas::Base, shot: pallas::Base) -> pallas::Base {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([h_prev, shot])
+}
+
+/**
+ * Compute the accumulator a game starts from when the circuit checks the last T shots
+ * @dev the accumulator is seeded with T empty shots so the first turns have a full window; an empty
+ *      shot is 0 and never equals a serialized shot, which always has one bit set
+ *
+ * @param T - number of recent shots the circuit checks a new shot against
+ * @return - T empty shots folded into an accumulator of 0
+ */
+pub fn history_genesis<const T: usize>() -> pallas::Base {
+    (0..T).fold(pallas::Base::zero(), |h, _| {
+        history_hash(h, pallas::Base::zero())
+    })
+}
+
+/**
+ * Storage for a proof that a shot extends a Poseidon shot history accumulator without repeating
+ * any of the last T shots
+ *
+ * @param poseidon - Pow5 config over the first 4 shared advice columns and 6 shared fixed columns
+ * @param advice - [shot, recent shot, inverse of the difference] columns of the non-membership gate
+ * @param selector - selector toggling the non-membership gate
+ */
+#[derive(Clone, Debug)]
+pub struct HistoryConfig {
+    pub poseidon: Pow5Config<pallas::Base, 3, 2>,
+    pub advice: [Column<Advice>; 3],
+    pub selector: Selector,
+}
+
+pub struct HistoryChip {
+    config: HistoryConfig,
+}
+
+impl Chip<pallas::Base> for HistoryChip {
+    type Config = HistoryConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl HistoryChip {
+    pub fn new(config: HistoryConfig) -> Self {
+        HistoryChip { config }
+    }
+
+    /**
+     * Configure the computation space of the chip & return HistoryConfig
+     * @dev shares columns with the chip it extends (e.g. ShotChip); fixed[0] must have constants
+     *      enabled for the Poseidon capacity element
+     *
+     * @param advice - equality enabled advice columns
+     * @param fixed - fixed columns; the first 6 hold the Poseidon round constants
+     * @return - configured HistoryConfig
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        advice: [Column<Advice>; 10],
+        fixed: [Column<Fixed>; 8],
+    ) -> HistoryConfig {
+        let poseidon = Pow5Chip::configure::<P128Pow5T3>(
+            meta,
+            [advice[0], advice[1], advice[2]],
+            advice[3],
+            [fixed[0], fixed[1], fixed[2]],
+            [fixed[3], fixed[4], fixed[5]],
+        );
+
+        // define gates
+        let selector = meta.selector();
+        let advice = [advice[4], advice[5], advice[6]];
+        meta.create_gate(GATE_HISTORY_NON_MEMBERSHIP, |meta| {
+            let shot = meta.query_advice(advice[0], Rotation::cur());
+            let recent = meta.query_advice(advice[1], Rotation::cur());
+            let inverse = meta.query_advice(advice[2], Rotation::cur());
+            let one = Expression::Constant(pallas::Base::one());
+            // the difference has an inverse only if the shot is not the recent shot
+            let selector = meta.query_selector(selector);
+            Constraints::with_selector(
+                selector,
+                [(
+                    "shot was not fired recently",
+                    (shot - recent) * inverse - one,
+                )],
+            )
+        });
+
+        HistoryConfig {
+            poseidon,
+            advice,
+            selector,
+        }
+    }
+
+    /**
+     * Hash a shot into the accumulator in-circuit
+     *
+     * @param h_prev - assigned accumulator over every earlier shot
+     * @param shot - assigned serialized shot commitment
+     * @return - assigned Poseidon(h_prev, shot)
+     */
+    pub fn absorb(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        h_prev: &AssignedCell<pallas::Base, pallas::Base>,
+        shot: &AssignedCell<pallas::Base, pallas::Base>,
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let chip = Pow5Chip::construct(self.config.poseidon.clone());
+        let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<2>, 3, 2>::init(
+            chip,
+            layouter.namespace(|| "init history hash"),
+        )?;
+        hasher.hash(
+            layouter.namespace(|| "history hash"),
+            [h_prev.clone(), shot.clone()],
+        )
+    }
+
+    /**
+     * Synthesize a proof that a shot extends the shot history
+     * @dev recent is a private membership witness: it is only accepted if chaining it from h_base
+     *      reproduces h_prev, so the prover cannot omit or substitute any of the last T shots
+     *
+     * @param T - number of recent shots the new shot is checked against
+     * @param h_base - accumulator before the oldest of the recent shots
+     * @param recent - the last T shots, oldest first
+     * @param shot - serialized shot commitment being fired
+     * @return - assigned [shot, h_prev, h_next] for the circuit to export
+     */
+    pub fn synthesize<const T: usize>(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        h_base: pallas::Base,
+        recent: [pallas::Base; T],
+        shot: pallas::Base,
+    ) -> Result<[AssignedCell<pallas::Base, pallas::Base>; 3], Error> {
+        // load the accumulator base, recent shots and new shot as advice
+        let (h_base, recent, shot) = layouter.assign_region(
+            || "load history advice",
+            |mut region| {
+                let h_base = region.assign_advice(
+                    || "assign history base",
+                    self.config.advice[0],
+                    0,
+                    || Value::known(h_base),
+                )?;
+                let shot = region.assign_advice(
+                    || "assign shot",
+                    self.config.advice[1],
+                    0,
+                    || Value::known(shot),
+                )?;
+                let mut assigned = Vec::<AssignedCell<pallas::Base, pallas::Base>>::new();
+                for (i, value) in recent.iter().enumerate() {
+                    assigned.push(region.assign_advice(
+                        || format!("assign recent shot {}", i),
+                        self.config.advice[2],
+                        i,
+                        || Value::known(*value),
+                    )?);
+                }
+                Ok((h_base, assigned, shot))
+            },
+        )?;
+        // chain the recent shots onto the base to recover h_prev
+        let mut h_prev = h_base;
+        for (i, recent_shot) in recent.iter().enumerate() {
+            h_prev = self.absorb(
+                layouter.namespace(|| format!("absorb recent shot {}", i)),
+                &h_prev,
+                recent_shot,
+            )?;
+        }
+        // check the new shot against every recent shot
+        layouter.assign_region(
+            || "history non-membership",
+            |mut region| {
+                for (i, recent_shot) in recent.iter().enumerate() {
+                    shot.copy_advice(
+                        || format!("copy shot {}", i),
+                        &mut region,
+                        self.config.advice[0],
+                        i,
+                    )?;
+                    recent_shot.copy_advice(
+                        || format!("copy recent shot {}", i),
+                        &mut region,
+                        self.config.advice[1],
+                        i,
+                    )?;
+                    let inverse = shot.value().zip(recent_shot.value()).map(|(shot, recent)| {
+                        (*shot - recent).invert().unwrap_or(pallas::Base::zero())
+                    });
+                    region.assign_advice(
+                        || format!("assign difference inverse {}", i),
+                        self.config.advice[2],
+                        i,
+                        || inverse,
+                    )?;
+                    self.config.selector.enable(&mut region, i)?;
+                }
+                Ok(())
+            },
+        )?;
+        // fold the new shot into the accumulator
+        let h_next = self.absorb(layouter.namespace(|| "absorb shot"), &h_prev, &shot)?;
+        Ok([shot, h_prev, h_next])
+    }
+}
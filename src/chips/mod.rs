@@ -3,4 +3,11 @@ pub mod placement;
 pub mod transpose;
 pub mod board;
 pub mod shot;
-pub mod pedersen;
\ No newline at end of file
+pub mod pedersen;
+pub mod gate_names;
+pub mod shot_index;
+pub mod reveal;
+pub mod symmetry;
+pub mod history;
+pub mod boolean;pub mod columns;
+pub mod trace;
@@ -1,7 +1,10 @@
 use {
-    crate::utils::constants::{
-        fixed_bases::{BoardCommitR, BoardCommitV, BoardFixedBases},
-        LOOKUP_SIZE,
+    crate::utils::{
+        constants::{
+            fixed_bases::{BoardCommitR, BoardCommitV, BoardFixedBases},
+            LOOKUP_SIZE,
+        },
+        error::BattleshipError,
     },
     halo2_gadgets::{
         ecc::{
@@ -21,6 +24,45 @@ use {
 pub struct PedersenCommitmentConfig {
     pub table_idx: TableColumn,
     pub ecc: EccConfig<BoardFixedBases>,
+    // 10 bit lookup range check the ecc chip decomposes with, reusable by other chips on table_idx
+    pub range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>,
+}
+
+/**
+ * Affine coordinates of a pedersen commitment computed in-circuit
+ *
+ * @param x - x coordinate of the commitment point
+ * @param y - y coordinate of the commitment point
+ */
+#[derive(Clone, Debug)]
+pub struct AssignedCommitment {
+    pub x: AssignedCell<pallas::Base, pallas::Base>,
+    pub y: AssignedCell<pallas::Base, pallas::Base>,
+}
+
+impl AssignedCommitment {
+    /**
+     * Construct from a point computed by the ecc chip
+     *
+     * @param point - the commitment point
+     * @return - the assigned coordinates of the point
+     */
+    fn from_point(point: &Point<EpAffine, EccChip<BoardFixedBases>>) -> AssignedCommitment {
+        let point = point.inner();
+        AssignedCommitment {
+            x: point.x(),
+            y: point.y(),
+        }
+    }
+
+    /**
+     * Export the coordinates in public input order
+     *
+     * @return - [x, y]
+     */
+    pub fn coordinates(&self) -> [AssignedCell<pallas::Base, pallas::Base>; 2] {
+        [self.x.clone(), self.y.clone()]
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -58,16 +100,99 @@ impl PedersenCommitmentChip {
         // configure ecc chip
         let ecc = EccChip::<BoardFixedBases>::configure(meta, advice, lagrange, range_check);
         // return configuration
-        PedersenCommitmentConfig { table_idx, ecc }
+        PedersenCommitmentConfig {
+            table_idx,
+            ecc,
+            range_check,
+        }
     }
 
+    /**
+     * Commit to an assigned message
+     * @dev loads the lookup table, so use batch_synthesize() to make more than one commitment
+     *
+     * @param value - assigned message to commit to
+     * @param trapdoor - blinding factor for the commitment
+     * @return - (x, y) coordinates of the commitment
+     */
     pub fn synthesize(
         &self,
         mut layouter: impl Layouter<pallas::Base>,
         value: &AssignedCell<pallas::Base, pallas::Base>,
         trapdoor: Value<pallas::Scalar>,
-    ) -> Result<Point<EpAffine, EccChip<BoardFixedBases>>, Error> {
+    ) -> Result<AssignedCommitment, Error> {
         // load the lookup table
+        self.load_table(&mut layouter)?;
+        // construct ecc chip
+        let ecc_chip = EccChip::construct(self.config.ecc.clone());
+        // instantiate commitment trapdoor as a full-width scalar
+        let trapdoor = ScalarFixed::new(
+            ecc_chip.clone(),
+            layouter.namespace(|| "trapdoor"),
+            trapdoor,
+        )?;
+        // synthesize the pedersen commitment computation
+        let commitment = pedersen_commitment(
+            layouter.namespace(|| "pedersen commitment"),
+            ecc_chip.clone(),
+            value.clone(),
+            trapdoor,
+        )?;
+        Ok(AssignedCommitment::from_point(&commitment))
+    }
+
+    /**
+     * Commit to several messages with one lookup table and ecc chip
+     * @dev the range check table can only be assigned once per circuit, so a circuit making more
+     *      than one commitment must use this instead of calling synthesize() repeatedly
+     *
+     * @param messages - assigned messages to commit to
+     * @param trapdoors - trapdoor blinding each message, in the same order
+     * @return - coordinates of the commitment to each message, in the same order
+     */
+    pub fn batch_synthesize(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        messages: &[AssignedCell<pallas::Base, pallas::Base>],
+        trapdoors: &[pallas::Scalar],
+    ) -> Result<Vec<AssignedCommitment>, Error> {
+        if messages.len() != trapdoors.len() {
+            return Err(BattleshipError::LengthMismatch {
+                expected: messages.len(),
+                found: trapdoors.len(),
+            }
+            .into());
+        }
+        // load the lookup table shared by every commitment
+        self.load_table(&mut layouter)?;
+        let ecc_chip = EccChip::construct(self.config.ecc.clone());
+        messages
+            .iter()
+            .zip(trapdoors)
+            .enumerate()
+            .map(|(i, (message, trapdoor))| {
+                let trapdoor = ScalarFixed::new(
+                    ecc_chip.clone(),
+                    layouter.namespace(|| format!("trapdoor {}", i)),
+                    Value::known(*trapdoor),
+                )?;
+                let commitment = pedersen_commitment(
+                    layouter.namespace(|| format!("pedersen commitment {}", i)),
+                    ecc_chip.clone(),
+                    message.clone(),
+                    trapdoor,
+                )?;
+                Ok(AssignedCommitment::from_point(&commitment))
+            })
+            .collect()
+    }
+
+    /**
+     * Assign the 10 bit range check lookup table used by the ecc chip
+     * @dev synthesize() and batch_synthesize() load it; only call this in circuits that use
+     *      range_check without making a commitment
+     */
+    pub fn load_table(&self, layouter: &mut impl Layouter<pallas::Base>) -> Result<(), Error> {
         layouter.assign_table(
             || "table_idx",
             |mut table| {
@@ -82,22 +207,7 @@ impl PedersenCommitmentChip {
                 }
                 Ok(())
             },
-        )?;
-        // construct ecc chip
-        let ecc_chip = EccChip::construct(self.config.ecc.clone());
-        // instantiate commitment trapdoor as a full-width scalar
-        let trapdoor = ScalarFixed::new(
-            ecc_chip.clone(),
-            layouter.namespace(|| "trapdoor"),
-            trapdoor,
-        )?;
-        // synthesize the pedersen commitment computation
-        Ok(pedersen_commitment(
-            layouter.namespace(|| "pedersen commitment"),
-            ecc_chip.clone(),
-            value.clone(),
-            trapdoor,
-        )?)
+        )
     }
 }
 
@@ -132,3 +242,242 @@ pub fn pedersen_commitment<
     // [v] ValueCommitV + [rcv] ValueCommitR
     commitment.add(layouter.namespace(|| "cv"), &blind)
 }
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::pedersen::pedersen_commit,
+        halo2_proofs::{
+            arithmetic::{CurveAffine, Field, FieldExt},
+            circuit::SimpleFloorPlanner,
+            dev::{MockProver, VerifyFailure},
+            pasta::group::Curve,
+            plonk::{Circuit, Instance},
+        },
+        rand::rngs::OsRng,
+    };
+
+    #[derive(Clone, Debug)]
+    struct TestPedersenConfig {
+        input: Column<Advice>,
+        pedersen: PedersenCommitmentConfig,
+        instance: Column<Instance>,
+    }
+
+    impl TestPedersenConfig {
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self {
+            let advice = [(); 10].map(|_| {
+                let column = meta.advice_column();
+                meta.enable_equality(column);
+                column
+            });
+            let fixed = [(); 8].map(|_| meta.fixed_column());
+            meta.enable_constant(fixed[0]);
+            let table_idx = meta.lookup_table_column();
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            TestPedersenConfig {
+                input: advice[0],
+                pedersen: PedersenCommitmentChip::configure(meta, advice, fixed, table_idx),
+                instance,
+            }
+        }
+    }
+
+    // commit to a single message and export the commitment
+    #[derive(Clone, Debug)]
+    struct PedersenTestCircuit {
+        message: Value<pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    }
+
+    impl Circuit<pallas::Base> for PedersenTestCircuit {
+        type Config = TestPedersenConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            PedersenTestCircuit {
+                message: Value::unknown(),
+                trapdoor: Value::unknown(),
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            TestPedersenConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let message = layouter.assign_region(
+                || "load message",
+                |mut region| region.assign_advice(|| "message", config.input, 0, || self.message),
+            )?;
+            let commitment = PedersenCommitmentChip::new(config.pedersen).synthesize(
+                layouter.namespace(|| "pedersen"),
+                &message,
+                self.trapdoor,
+            )?;
+            layouter.constrain_instance(commitment.x.cell(), config.instance, 0)?;
+            layouter.constrain_instance(commitment.y.cell(), config.instance, 1)
+        }
+    }
+
+    /**
+     * Run the single commitment circuit against a claimed commitment
+     *
+     * @param message - the message committed to in-circuit
+     * @param trapdoor - the trapdoor blinding the in-circuit commitment
+     * @param instance - the claimed (x, y) commitment
+     * @return - the mock prover for the circuit
+     */
+    fn prove_commitment(
+        message: pallas::Base,
+        trapdoor: pallas::Scalar,
+        instance: [pallas::Base; 2],
+    ) -> MockProver<pallas::Base> {
+        let circuit = PedersenTestCircuit {
+            message: Value::known(message),
+            trapdoor: Value::known(trapdoor),
+        };
+        MockProver::run(11, &circuit, vec![instance.to_vec()]).unwrap()
+    }
+
+    /**
+     * Compute the commitment coordinates outside of the circuit
+     */
+    fn native_commitment(message: &pallas::Base, trapdoor: &pallas::Scalar) -> [pallas::Base; 2] {
+        let commitment = pedersen_commit(message, trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        [*coordinates.x(), *coordinates.y()]
+    }
+
+    #[test]
+    fn valid_commitment() {
+        let random = || pallas::Scalar::random(&mut OsRng);
+        let cases = [
+            (pallas::Base::random(&mut OsRng), random()),
+            (pallas::Base::random(&mut OsRng), random()),
+            // only the trapdoor term contributes
+            (pallas::Base::zero(), random()),
+            // only the message term contributes
+            (pallas::Base::from(53), pallas::Scalar::zero()),
+            // widest board state
+            (pallas::Base::from_u128((1 << 100) - 1), random()),
+        ];
+        for (message, trapdoor) in cases {
+            let instance = native_commitment(&message, &trapdoor);
+            let prover = prove_commitment(message, trapdoor, instance);
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn invalid_commitment() {
+        let message = pallas::Base::from(53);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // claim the commitment to a different message and to a different trapdoor
+        let claims = [
+            native_commitment(&(message + pallas::Base::one()), &trapdoor),
+            native_commitment(&message, &(trapdoor + pallas::Scalar::one())),
+        ];
+        for instance in claims {
+            let failures = prove_commitment(message, trapdoor, instance)
+                .verify()
+                .unwrap_err();
+            // the exported coordinates fail to copy into the instance column
+            assert!(failures
+                .iter()
+                .all(|failure| matches!(failure, VerifyFailure::Permutation { .. })));
+        }
+    }
+
+    // commit to every message in one batch and export each commitment
+    #[derive(Clone, Debug)]
+    struct BatchCommitCircuit {
+        messages: Vec<pallas::Base>,
+        trapdoors: Vec<pallas::Scalar>,
+    }
+
+    impl Circuit<pallas::Base> for BatchCommitCircuit {
+        type Config = TestPedersenConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            TestPedersenConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let messages = layouter.assign_region(
+                || "load messages",
+                |mut region| {
+                    self.messages
+                        .iter()
+                        .enumerate()
+                        .map(|(i, message)| {
+                            region.assign_advice(
+                                || format!("message {}", i),
+                                config.input,
+                                i,
+                                || Value::known(*message),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()
+                },
+            )?;
+            let chip = PedersenCommitmentChip::new(config.pedersen);
+            let commitments = chip.batch_synthesize(
+                layouter.namespace(|| "batch pedersen"),
+                &messages,
+                &self.trapdoors,
+            )?;
+            let coordinates = commitments.iter().flat_map(AssignedCommitment::coordinates);
+            for (i, coordinate) in coordinates.enumerate() {
+                layouter.constrain_instance(coordinate.cell(), config.instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn batch_matches_native() {
+        let messages = vec![pallas::Base::from(53), pallas::Base::from(1 << 40)];
+        let trapdoors = vec![
+            pallas::Scalar::random(&mut OsRng),
+            pallas::Scalar::random(&mut OsRng),
+        ];
+        // each commitment matches the commitment computed outside the circuit
+        let instance = messages
+            .iter()
+            .zip(&trapdoors)
+            .flat_map(|(message, trapdoor)| {
+                let commitment = pedersen_commit(message, trapdoor).to_affine();
+                let coordinates = commitment.coordinates().unwrap();
+                [*coordinates.x(), *coordinates.y()]
+            })
+            .collect::<Vec<_>>();
+        let circuit = BatchCommitCircuit {
+            messages: messages.clone(),
+            trapdoors: trapdoors.clone(),
+        };
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // a trapdoor missing for a message is rejected during synthesis
+        let circuit = BatchCommitCircuit {
+            messages,
+            trapdoors: trapdoors[..1].to_vec(),
+        };
+        assert!(MockProver::run(11, &circuit, vec![vec![]]).is_err());
+    }
+}
@@ -1,5 +1,11 @@
 use {
-    crate::utils::{binary::BinaryValue, board::BOARD_SIZE},
+    crate::{
+        chips::gate_names::{
+            GATE_ADJACENCY_BIT_COUNT, GATE_PERMUTE_ADJACENCY_BIT_COUNT, GATE_PLACEMENT_BIT_COUNT,
+            GATE_RUNNING_SUM_CONSTRAINTS, GATE_SUM_HV_BITS,
+        },
+        utils::{binary::BinaryValue, board::BOARD_SIZE, error::to_fixed_array},
+    },
     halo2_proofs::{
         arithmetic::{lagrange_interpolate, FieldExt},
         circuit::{AssignedCell, Chip, Layouter, Region, Value},
@@ -21,7 +27,8 @@ use {
  *     * rows 1-100 perform running sum operations on bits. last row constrains output
  * @param selectors - array of 5 selectors that toggle constraints in chip
  *     * [placement_orientation, bit_sum, bit_adjacency, adjacency_permute, constrain_trace]
- * @param ship - Object storing/ exporting ship positioning
+ * @dev holds no witness data; the ship placement is passed to PlacementChip::synthesize so one config
+ *      can constrain any number of placements
  */
 #[derive(Clone, Copy, Debug)]
 pub struct PlacementConfig<F: FieldExt, const S: usize> {
@@ -34,6 +41,7 @@ pub struct PlacementConfig<F: FieldExt, const S: usize> {
     pub s_adjacency: Selector, // count bits in bit window and increment prev window sum if full
     pub s_permute: Selector,   // copy previous window sum to current window sum
     pub s_constrain: Selector, // constrain full_window_sum to be 1 and bit_sum to be S
+    pub vertical: bool,        // bit windows span columns (stride 10) instead of rows
     _marker: PhantomData<F>,
 }
 
@@ -104,6 +112,10 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
         PlacementChip { config }
     }
 
+    /**
+     * Configure a placement chip for a horizontal (or transposed vertical) ship placement
+     * @dev bit windows are S consecutive bits within a row
+     */
     pub fn configure(
         meta: &mut ConstraintSystem<F>,
         bits: Column<Advice>,
@@ -111,6 +123,40 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
         full_window_sum: Column<Advice>,
         fixed: Column<Fixed>,
     ) -> PlacementConfig<F, S> {
+        Self::configure_oriented(meta, bits, bit_sum, full_window_sum, fixed, false)
+    }
+
+    /**
+     * Configure a placement chip for an untransposed vertical ship placement
+     * @dev bit windows are S bits spaced 10 apart within a column
+     */
+    pub fn configure_vertical(
+        meta: &mut ConstraintSystem<F>,
+        bits: Column<Advice>,
+        bit_sum: Column<Advice>,
+        full_window_sum: Column<Advice>,
+        fixed: Column<Fixed>,
+    ) -> PlacementConfig<F, S> {
+        Self::configure_oriented(meta, bits, bit_sum, full_window_sum, fixed, true)
+    }
+
+    fn configure_oriented(
+        meta: &mut ConstraintSystem<F>,
+        bits: Column<Advice>,
+        bit_sum: Column<Advice>,
+        full_window_sum: Column<Advice>,
+        fixed: Column<Fixed>,
+        vertical: bool,
+    ) -> PlacementConfig<F, S> {
+        // aliased columns would overlap the repurposed h/v/sum cells and running sums
+        assert!(
+            bits != bit_sum && bit_sum != full_window_sum && bits != full_window_sum,
+            "PlacementChip columns must be distinct"
+        );
+
+        // distance in rows between bits in the same window
+        let stride = if vertical { 10 } else { 1 };
+
         // define selectors
         let s_input = meta.selector();
         let s_sum_bits = meta.selector();
@@ -118,7 +164,7 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
         let s_permute = meta.selector();
         let s_constrain = meta.selector();
 
-        meta.create_gate("sum inputted H, V bits", |meta| {
+        meta.create_gate(GATE_SUM_HV_BITS, |meta| {
             // retrieve witnessed cells
             // repurposing columns:
             // - bit_sum: holds horizontal bit decomposition
@@ -133,7 +179,7 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
         });
 
         // selector[1] gate: bit count running sum
-        meta.create_gate("placement bit count", |meta| {
+        meta.create_gate(GATE_PLACEMENT_BIT_COUNT, |meta| {
             // check that this row's bit count is sum of prev row's bit count + current row's bit value
             let bit = meta.query_advice(bits, Rotation::cur());
             // store running bit sum in advice[0]
@@ -145,11 +191,11 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
         });
 
         // selector[2] gate: full bit window running sum
-        meta.create_gate("adjacency bit count", |meta| {
-            // count the number of bits in this gate and the proceeding `S` rows in bit column (A^2)
+        meta.create_gate(GATE_ADJACENCY_BIT_COUNT, |meta| {
+            // count the number of bits in this gate and the proceeding `S` windowed rows in bit column (A^2)
             let mut bit_count = meta.query_advice(bits, Rotation::cur());
             for i in 1..S {
-                let bit = meta.query_advice(bits, Rotation(i as i32));
+                let bit = meta.query_advice(bits, Rotation((i * stride) as i32));
                 bit_count = bit_count + bit;
             }
 
@@ -207,17 +253,25 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
             // bit_count = bit_count
             // - if bit_count == ship_len, running_sum = prev_running_sum + 1
             // - if bit_count != ship_len, running_sum = prev_running
+            // - running_sum - prev_running_sum is boolean so the sum is bounded by max_full_windows(S)
             let selector = meta.query_selector(s_adjacency);
-            let constraint = full_window_count.clone()
-                - prev_full_window_count
-                - interpolate_incrementor(bit_count);
-            Constraints::with_selector(selector, [("Full Window Running Sum", constraint)])
+            let one = Expression::Constant(F::one());
+            let increment = full_window_count - prev_full_window_count;
+            let constraint = increment.clone() - interpolate_incrementor(bit_count);
+            let range_check = increment.clone() * (one - increment);
+            Constraints::with_selector(
+                selector,
+                [
+                    ("Full Window Running Sum", constraint),
+                    ("Full Window Increment is boolean", range_check),
+                ],
+            )
         });
 
         // selector[3] gate: permute bit window running sum
-        meta.create_gate("permute adjaceny bit count", |meta| {
+        meta.create_gate(GATE_PERMUTE_ADJACENCY_BIT_COUNT, |meta| {
             // confirm that the current row's adjacent bit count is the same as the previous rows
-            // @dev used in rows where ship cannot be placed (offset % 10 + ship_length >= 10)
+            // @dev used in rows where ship cannot be placed (offset % 10 + ship_length > 10)
             // store running adjacency count in advice[2]
             let previous = meta.query_advice(full_window_sum, Rotation::prev());
             let current = meta.query_advice(full_window_sum, Rotation::cur());
@@ -230,7 +284,7 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
         });
 
         // selector[4] gate: constrain running sum trace
-        meta.create_gate("running sum constraints", |meta| {
+        meta.create_gate(GATE_RUNNING_SUM_CONSTRAINTS, |meta| {
             // confirm the final output of the placement computation does not violate ship placement rules
             // @dev constraining of sum(h,v) to bits2num output occurs in synthesis
             let ship_len = Expression::Constant(F::from(S as u64));
@@ -260,6 +314,7 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
             s_adjacency,
             s_permute,
             s_constrain,
+            vertical,
             _marker: PhantomData,
         }
     }
@@ -273,7 +328,7 @@ impl<F: FieldExt, const S: usize> PlacementChip<F, S> {
     ) -> Result<(), Error> {
         // load values in memoru
         let bits = ship.bitfield();
-        let trace = compute_placement_trace::<F, S>(ship);
+        let trace = compute_oriented_placement_trace::<F, S>(ship, self.config.vertical);
         // begin proof synthesis
         let assigned_bits = self.load_bits(layouter, &bits, horizontal, vertical)?;
         let running_sums = self.placement_sums(layouter, &assigned_bits, &trace)?;
@@ -315,7 +370,7 @@ impl<F: FieldExt, const S: usize> PlacementInstructions<F, S> for PlacementChip<
                         || Value::known(bits[i]),
                     )?);
                 }
-                Ok(AssignedBits::<F>::from(assigned.try_into().unwrap()))
+                Ok(AssignedBits::<F>::from(to_fixed_array(assigned)?))
             },
         )?)
     }
@@ -333,7 +388,8 @@ impl<F: FieldExt, const S: usize> PlacementInstructions<F, S> for PlacementChip<
                 // Rotation::prev() from unintended consequences
                 let mut state = PlacementState::<F>::assign_padding_row(&mut region, &self.config)?;
                 // permute bits constrained in "load placement encoded values" region to this region
-                let _ = state.permute_bits2num(&bits2num, &mut region, &self.config)?;
+                let permuted = state.permute_bits2num(&bits2num, &mut region, &self.config)?;
+                PlacementState::<F>::constrain_sampled_bits(&mut region, bits2num, &permuted)?;
                 // assign running sum trace across 100 (BOARD_SIZE) rows
                 state = state.assign_running_sum_trace(&mut region, &self.config, &trace)?;
                 Ok(state)
@@ -368,53 +424,144 @@ impl<F: FieldExt, const S: usize> PlacementInstructions<F, S> for PlacementChip<
     }
 }
 
+/**
+ * Return the upper bound of the full bit window running sum for a ship of a given length
+ * @dev each row can fit 10 - S + 1 windows, and the window count can increment by at most 1 per row
+ *
+ * @param ship_len - the length of the ship being placed
+ * @return - number of rows where the full bit window running sum can increment
+ */
+pub const fn max_full_windows(ship_len: usize) -> usize {
+    (BOARD_SIZE / 10) * (10 + 1 - ship_len)
+}
+
 pub type AssignedBits<F> = [AssignedCell<F, F>; BOARD_SIZE];
 pub type PlacementTrace<F> = [[F; BOARD_SIZE]; 2];
 
+// bit indices whose collapsed cell is explicitly tied to its running sum copy (first, middle, last)
+pub const SAMPLED_BIT_INDICES: [usize; 3] = [0, 49, 99];
+
+/**
+ * Determine whether a bit window starting at a given offset would wrap off the board
+ *
+ * @param offset - the index [0, 99] of the first bit in the window
+ * @param vertical - if true, the window extends down a column instead of across a row
+ * @return - true if no ship can start at offset (window running sum is permuted instead)
+ */
+pub const fn window_overflows<const S: usize>(offset: usize, vertical: bool) -> bool {
+    let start = if vertical { offset / 10 } else { offset % 10 };
+    start + S > 10
+}
+
+/**
+ * Copy the S cells of a bit window out of a board's bits
+ * @dev borrows the bits, so any number of overlapping windows can be read from the same board
+ *
+ * @param bits - the board bits in little endian order
+ * @param offset - the index [0, 99] of the first bit in the window
+ * @param vertical - if true, the window extends down a column instead of across a row
+ * @return - the S bits of the window, or None if the window would wrap off the board
+ */
+pub fn bit_window<F: FieldExt, const S: usize>(
+    bits: &[F; BOARD_SIZE],
+    offset: usize,
+    vertical: bool,
+) -> Option<[F; S]> {
+    if offset >= BOARD_SIZE || window_overflows::<S>(offset, vertical) {
+        return None;
+    }
+    let stride = if vertical { 10 } else { 1 };
+    Some(core::array::from_fn(|i| bits[offset + i * stride]))
+}
+
 /**
- * Given a ShipPlacement object, construct the running sum traces
+ * Given a ship placement, construct the running sum traces
  *
- * @param ship - ship helper object
+ * @param ship - serialized ship placement
  * @return - bit_sum and full_bit_window cell values for assignment
  */
 pub fn compute_placement_trace<F: FieldExt, const S: usize>(
     ship: &BinaryValue,
+) -> PlacementTrace<F> {
+    compute_oriented_placement_trace::<F, S>(ship, false)
+}
+
+/**
+ * Given a ship placement, construct the running sum traces for a given bit window orientation
+ *
+ * @param ship - serialized ship placement
+ * @param vertical - if true, count bit windows down columns instead of across rows
+ * @return - bit_sum and full_bit_window cell values for assignment
+ */
+pub fn compute_oriented_placement_trace<F: FieldExt, const S: usize>(
+    ship: &BinaryValue,
+    vertical: bool,
 ) -> PlacementTrace<F> {
     let bits = ship.bitfield::<F, BOARD_SIZE>();
     // compute bit_sum trace
-    let mut trace: Vec<F> = Vec::<F>::new();
-    trace.push(bits[0]);
+    let mut bit_sum = [F::zero(); BOARD_SIZE];
+    bit_sum[0] = bits[0];
     for i in 1..bits.len() {
-        trace.push(bits[i] + trace[i - 1]);
+        bit_sum[i] = bits[i] + bit_sum[i - 1];
     }
-    let bit_sum: [F; BOARD_SIZE] = trace.try_into().unwrap();
 
     // function for returning increment
-    // expects permute case check to be done lower in stack
-    let increment = |offset: usize| {
-        let bit_count = bits[offset..offset + S]
-            .iter()
-            .fold(F::zero(), |sum: F, elem: &F| sum + elem);
-        let v = if bit_count.eq(&F::from(S as u64)) {
-            F::one()
-        } else {
-            F::zero()
-        };
-        v
+    // windows that wrap off the board never increment
+    let increment = |offset: usize| match bit_window::<F, S>(&bits, offset, vertical) {
+        Some(window) => {
+            let bit_count = window.iter().fold(F::zero(), |sum: F, elem: &F| sum + elem);
+            if bit_count.eq(&F::from(S as u64)) {
+                F::one()
+            } else {
+                F::zero()
+            }
+        }
+        None => F::zero(),
     };
 
     // compute full bit window trace
-    trace = vec![increment(0)];
+    let mut full_window_sum = [F::zero(); BOARD_SIZE];
+    full_window_sum[0] = increment(0);
     for i in 1..bits.len() {
-        if i % 10 + S > 10 {
+        full_window_sum[i] = if window_overflows::<S>(i, vertical) {
             // permute case
-            trace.push(trace[i - 1]);
+            full_window_sum[i - 1]
         } else {
             // bit window check case
-            trace.push(trace[i - 1] + increment(i))
+            full_window_sum[i - 1] + increment(i)
+        };
+    }
+    [bit_sum, full_window_sum]
+}
+
+/**
+ * Given a ship placement, construct the same running sum traces as compute_placement_trace
+ * @dev tests bits and bit windows on the 100 bit board as a u128, so field elements are only touched
+ *      to copy a running sum or increment it by one
+ *
+ * @param ship - serialized ship placement
+ * @return - bit_sum and full_bit_window cell values for assignment
+ */
+pub fn compute_placement_trace_fast<F: FieldExt, const S: usize>(
+    ship: &BinaryValue,
+) -> PlacementTrace<F> {
+    let bits = ship.lower_u128();
+    let window = (1u128 << S) - 1;
+    let mut bit_sum = [F::zero(); BOARD_SIZE];
+    let mut full_window_sum = [F::zero(); BOARD_SIZE];
+    let (mut bit_count, mut window_count) = (F::zero(), F::zero());
+    for i in 0..BOARD_SIZE {
+        // increment the bit sum if bit i is flipped
+        if bits >> i & 1 == 1 {
+            bit_count += F::one();
         }
+        bit_sum[i] = bit_count;
+        // increment the window sum if the window at i is full; overflowing windows are permuted
+        if !window_overflows::<S>(i, false) && (bits >> i) & window == window {
+            window_count += F::one();
+        }
+        full_window_sum[i] = window_count;
     }
-    let full_window_sum: [F; BOARD_SIZE] = trace.try_into().unwrap();
     [bit_sum, full_window_sum]
 }
 
@@ -431,7 +578,7 @@ impl<F: FieldExt> PlacementState<F> {
      *
      * @param bit_sum - reference to assigned bit_sum cell
      * @param full_window_sum - reference to assigned full_bit_window cell
-     * @return - BoardState object
+     * @return - PlacementState object
      */
     pub fn new(bit_sum: AssignedCell<F, F>, full_window_sum: AssignedCell<F, F>) -> Self {
         PlacementState {
@@ -494,14 +641,28 @@ impl<F: FieldExt> PlacementState<F> {
                 i + 1, // offset + 1 for padded row
             )?);
         }
-        Ok(AssignedBits::from(
-            permuted
-                .iter()
-                .map(|bit| bit.clone())
-                .collect::<Vec<AssignedCell<F, F>>>()
-                .try_into()
-                .unwrap(),
-        ))
+        Ok(AssignedBits::from(to_fixed_array(permuted)?))
+    }
+
+    /**
+     * Explicitly constrain sampled collapsed bits to equal their copies in the running sum region
+     * @dev redundant with the copy constraints of permute_bits2num, as assurance that the bits
+     *      entering the running sum are the H + V collapse even if a copy is dropped by the layouter
+     *
+     * @param region - the "placement running sum trace" region the bits were permuted into
+     * @param bits - assigned cells output by load_bits()
+     * @param permuted - assigned cells output by permute_bits2num()
+     * @return - Ok if the equality constraints were added
+     */
+    pub fn constrain_sampled_bits(
+        region: &mut Region<F>,
+        bits: &AssignedBits<F>,
+        permuted: &AssignedBits<F>,
+    ) -> Result<(), Error> {
+        for i in SAMPLED_BIT_INDICES {
+            region.constrain_equal(bits[i].cell(), permuted[i].cell())?;
+        }
+        Ok(())
     }
 
     /**
@@ -555,7 +716,7 @@ impl<F: FieldExt> PlacementState<F> {
 
             // toggle selectors
             config.s_sum_bits.enable(region, offset)?;
-            if adjusted_offset % 10 + S > 10 {
+            if window_overflows::<S>(adjusted_offset, config.vertical) {
                 config.s_permute.enable(region, offset)?;
             } else {
                 config.s_adjacency.enable(region, offset)?;
@@ -567,3 +728,545 @@ impl<F: FieldExt> PlacementState<F> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{
+            fixtures::pattern_1,
+            ship::{Ship, ShipType, DEFAULT_WITNESS_OPTIONS},
+        },
+        bitvec::prelude::*,
+        halo2_proofs::{
+            circuit::SimpleFloorPlanner,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            pasta::Fp,
+            plonk::{Any, Circuit},
+        },
+    };
+
+    // destroyer placement chip under test
+    const S: usize = 2;
+
+    /**
+     * Witness the horizontal placement of a ship and an empty vertical placement
+     *
+     * @param ship - bits of the horizontal placement
+     * @return - assigned [horizontal, vertical] bit decompositions to pass to the chip
+     */
+    fn witness_placement<const S: usize>(
+        layouter: &mut impl Layouter<Fp>,
+        config: &PlacementConfig<Fp, S>,
+        ship: &BinaryValue,
+    ) -> Result<[AssignedBits<Fp>; 2], Error> {
+        let bits = ship.bitfield::<Fp, BOARD_SIZE>();
+        layouter.assign_region(
+            || "witness placement",
+            |mut region: Region<Fp>| {
+                let mut horizontal = Vec::<AssignedCell<Fp, Fp>>::new();
+                let mut vertical = Vec::<AssignedCell<Fp, Fp>>::new();
+                for (i, bit) in bits.iter().enumerate() {
+                    horizontal.push(region.assign_advice(
+                        || format!("h bit {}", i),
+                        config.bit_sum,
+                        i,
+                        || Value::known(*bit),
+                    )?);
+                    vertical.push(region.assign_advice(
+                        || format!("v bit {}", i),
+                        config.full_window_sum,
+                        i,
+                        || Value::known(Fp::zero()),
+                    )?);
+                }
+                Ok([to_fixed_array(horizontal)?, to_fixed_array(vertical)?])
+            },
+        )
+    }
+
+    #[derive(Clone, Debug)]
+    struct PlacementCircuit<const S: usize, const VERTICAL: bool> {
+        ship: BinaryValue,
+        trace: PlacementTrace<Fp>,
+        // re-witness bit 0 flipped in the running sum region instead of copying it
+        tamper_bit_0: bool,
+    }
+
+    impl<const S: usize, const VERTICAL: bool> PlacementCircuit<S, VERTICAL> {
+        // construct a circuit that witnesses an honestly computed running sum trace
+        fn new(ship: BinaryValue) -> Self {
+            PlacementCircuit {
+                ship,
+                trace: compute_oriented_placement_trace::<Fp, S>(&ship, VERTICAL),
+                tamper_bit_0: false,
+            }
+        }
+    }
+
+    impl<const S: usize, const VERTICAL: bool> Circuit<Fp> for PlacementCircuit<S, VERTICAL> {
+        type Config = PlacementConfig<Fp, S>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> PlacementConfig<Fp, S> {
+            let bits = meta.advice_column();
+            let bit_sum = meta.advice_column();
+            let full_window_sum = meta.advice_column();
+            let fixed = meta.fixed_column();
+            meta.enable_equality(bits);
+            meta.enable_equality(bit_sum);
+            meta.enable_equality(full_window_sum);
+            meta.enable_constant(fixed);
+            if VERTICAL {
+                PlacementChip::<Fp, S>::configure_vertical(
+                    meta,
+                    bits,
+                    bit_sum,
+                    full_window_sum,
+                    fixed,
+                )
+            } else {
+                PlacementChip::<Fp, S>::configure(meta, bits, bit_sum, full_window_sum, fixed)
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: PlacementConfig<Fp, S>,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let bits = self.ship.bitfield::<Fp, BOARD_SIZE>();
+            let [horizontal, vertical] = witness_placement(&mut layouter, &config, &self.ship)?;
+            let chip = PlacementChip::<Fp, S>::new(config);
+            let assigned = chip.load_bits(&mut layouter, &bits, &horizontal, &vertical)?;
+            if !self.tamper_bit_0 {
+                let state = chip.placement_sums(&mut layouter, &assigned, &self.trace)?;
+                return chip.assign_constraint(&mut layouter, &state);
+            }
+            // simulate a layouter that drops the copy constraint of bit 0
+            let state = layouter.assign_region(
+                || "placement running sum trace",
+                |mut region: Region<Fp>| {
+                    let mut state = PlacementState::<Fp>::assign_padding_row(&mut region, &config)?;
+                    let mut permuted = Vec::<AssignedCell<Fp, Fp>>::new();
+                    for (i, bit) in assigned.iter().enumerate() {
+                        permuted.push(match i {
+                            0 => region.assign_advice(
+                                || "tampered bit 0",
+                                config.bits,
+                                1,
+                                || bit.value().map(|bit| Fp::one() - bit),
+                            )?,
+                            _ => bit.copy_advice(
+                                || format!("permute bit {}", i),
+                                &mut region,
+                                config.bits,
+                                i + 1,
+                            )?,
+                        });
+                    }
+                    PlacementState::<Fp>::constrain_sampled_bits(
+                        &mut region,
+                        &assigned,
+                        &to_fixed_array(permuted)?,
+                    )?;
+                    state.assign_running_sum_trace(&mut region, &config, &self.trace)
+                },
+            )?;
+            chip.assign_constraint(&mut layouter, &state)
+        }
+    }
+
+    // proves several placements through PlacementChip::synthesize with a single shared config
+    #[derive(Clone, Debug)]
+    struct SharedConfigCircuit<const S: usize> {
+        ships: Vec<BinaryValue>,
+    }
+
+    impl<const S: usize> Circuit<Fp> for SharedConfigCircuit<S> {
+        type Config = PlacementConfig<Fp, S>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> PlacementConfig<Fp, S> {
+            PlacementCircuit::<S, false>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: PlacementConfig<Fp, S>,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let chip = PlacementChip::<Fp, S>::new(config);
+            for ship in self.ships.iter() {
+                let [horizontal, vertical] = witness_placement(&mut layouter, &config, ship)?;
+                chip.synthesize(&mut layouter, ship, &horizontal, &vertical)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn overlapping_bit_windows() {
+        // the carrier of pattern 1 runs down column 3 from row 3 to row 7
+        let bits = pattern_1()
+            .state(DEFAULT_WITNESS_OPTIONS)
+            .bitfield::<Fp, BOARD_SIZE>();
+        let count = |window: [Fp; 5]| window.iter().filter(|bit| **bit == Fp::one()).count();
+        // three overlapping windows read from the same bits without moving them
+        let windows = [23, 33, 43].map(|offset| bit_window::<Fp, 5>(&bits, offset, true).unwrap());
+        assert_eq!(windows.map(count), [4, 5, 4]);
+        // windows share the cells they overlap on
+        assert_eq!(windows[1][1..], windows[2][..4]);
+        // a window running off the bottom of the board is not read
+        assert_eq!(bit_window::<Fp, 5>(&bits, 63, true), None);
+        assert_eq!(bit_window::<Fp, 5>(&bits, 33, false).map(count), Some(1));
+    }
+
+    #[test]
+    fn valid_placement() {
+        let ship = Ship::new(ShipType::Destroyer, 3, 4, false).bits(true);
+        let circuit = PlacementCircuit::<S, false>::new(ship);
+        let prover = MockProver::run(9, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_tampered_bit_0() {
+        let ship = Ship::new(ShipType::Destroyer, 3, 4, false).bits(true);
+        let mut circuit = PlacementCircuit::<S, false>::new(ship);
+        circuit.tamper_bit_0 = true;
+        let prover = MockProver::run(9, &circuit, vec![]).unwrap();
+        // the sampled equality constraint catches the flipped bit entering the running sum
+        assert!(prover
+            .verify()
+            .unwrap_err()
+            .contains(&VerifyFailure::Permutation {
+                column: (Any::Advice, 0).into(),
+                location: FailureLocation::InRegion {
+                    region: (2, "placement running sum trace").into(),
+                    offset: 1,
+                },
+            }));
+    }
+
+    #[test]
+    fn valid_vertical_placement() {
+        // cruiser placed vertically at (4, 7) fills the last 3 rows of the column without transposing
+        let ship = Ship::new(ShipType::Cruiser, 4, 7, true).bits(false);
+        let circuit = PlacementCircuit::<3, true>::new(ship);
+        let prover = MockProver::run(9, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_vertical_overflow() {
+        // cruiser placed vertically at (4, 8) overflows the column by 1 bit
+        let ship = Ship::new(ShipType::Cruiser, 4, 8, true).bits(false);
+        let circuit = PlacementCircuit::<3, true>::new(ship);
+        let prover = MockProver::run(9, &circuit, vec![]).unwrap();
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                // bit 104 falls off the board so only 2 bits are counted
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (4, GATE_RUNNING_SUM_CONSTRAINTS).into(),
+                        0,
+                        "Placed ship of correct length",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (3, "constrain running sum output").into(),
+                        offset: 0,
+                    },
+                    cell_values: vec![(((Any::Advice, 1).into(), 0).into(), String::from("0x2"))]
+                },
+                // no window starting in row 8 or 9 is checked for a ship of length 3
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (4, GATE_RUNNING_SUM_CONSTRAINTS).into(),
+                        1,
+                        "One full bit window"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (3, "constrain running sum output").into(),
+                        offset: 0,
+                    },
+                    cell_values: vec![(((Any::Advice, 2).into(), 0).into(), String::from("0"))]
+                }
+            ])
+        );
+    }
+
+    /**
+     * Prove a horizontal placement with a placement chip for ships of length S
+     *
+     * @param ship - bits of the placement to prove
+     * @param expected - names of the constraints the placement fails, in order
+     */
+    fn assert_placement<const S: usize>(ship: BinaryValue, expected: &[&str]) {
+        let circuit = PlacementCircuit::<S, false>::new(ship);
+        let prover = MockProver::run(9, &circuit, vec![]).unwrap();
+        let failures = prover.verify().err().unwrap_or_default();
+        assert_eq!(failures.len(), expected.len(), "{:?}", failures);
+        for (failure, name) in failures.iter().zip(expected) {
+            match failure {
+                VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                    assert!(format!("{}", constraint).contains(name), "{:?}", failure);
+                }
+                failure => panic!("Unexpected failure {:?}", failure),
+            }
+        }
+    }
+
+    /**
+     * Check valid and malformed placements of a ship against the placement chip for its length
+     * @dev the ship is placed horizontally from (0, 4), covering bits 40..40 + S
+     *
+     * @param ship_type - the ship whose length is S
+     */
+    fn check_placement_lengths<const S: usize>(ship_type: ShipType) {
+        assert_eq!(ship_type.length(), S);
+        let ship = Ship::new(ship_type, 0, 4, false).bits(true);
+        assert_placement::<S>(ship, &[]);
+        // a stray bit in the last row adds to the bit count but not to the full windows
+        let mut stray = ship;
+        stray.value.set(90, true);
+        assert_placement::<S>(stray, &["Placed ship of correct length"]);
+        // moving the last bit one cell right keeps S bits but leaves no full window
+        let mut split = ship;
+        split.value.set(40 + S - 1, false);
+        split.value.set(40 + S, true);
+        assert_placement::<S>(split, &["One full bit window"]);
+        // extending the ship by one cell fills two overlapping windows
+        let mut extended = ship;
+        extended.value.set(40 + S, true);
+        assert_placement::<S>(
+            extended,
+            &["Placed ship of correct length", "One full bit window"],
+        );
+    }
+
+    #[test]
+    fn placement_lengths() {
+        check_placement_lengths::<2>(ShipType::Destroyer);
+        check_placement_lengths::<3>(ShipType::Cruiser);
+        check_placement_lengths::<4>(ShipType::Battleship);
+        check_placement_lengths::<5>(ShipType::Carrier);
+    }
+
+    /**
+     * Check the last horizontal window a ship of length S can start in and the first it cannot
+     * @dev places the ship in row 4, so the invalid placement wraps onto the first cell of row 5
+     */
+    fn check_rightmost_window<const S: usize>() {
+        let column = 10 - S;
+        let (valid, wrapped) = (40 + column, 40 + column + 1);
+        assert!(!window_overflows::<S>(valid, false));
+        assert!(window_overflows::<S>(wrapped, false));
+        for (start, full_windows, expected) in [
+            (valid, Fp::one(), &[][..]),
+            (wrapped, Fp::zero(), &["One full bit window"][..]),
+        ] {
+            let mut ship = BinaryValue::empty();
+            for bit in start..start + S {
+                ship.value.set(bit, true);
+            }
+            let trace = compute_placement_trace::<Fp, S>(&ship);
+            assert_eq!(trace[0][BOARD_SIZE - 1], Fp::from(S as u64));
+            assert_eq!(trace[1][BOARD_SIZE - 1], full_windows);
+            assert_placement::<S>(ship, expected);
+        }
+    }
+
+    #[test]
+    fn rightmost_window_boundary() {
+        check_rightmost_window::<2>();
+        check_rightmost_window::<3>();
+        check_rightmost_window::<4>();
+        check_rightmost_window::<5>();
+    }
+
+    #[test]
+    fn max_full_windows_bound() {
+        assert_eq!(max_full_windows(5), 60);
+        assert_eq!(max_full_windows(2), 90);
+        // every row of the trace where the full window sum can increment is counted
+        let full = &[u32::MAX; 4].view_bits::<Lsb0>()[0..BOARD_SIZE];
+        let ship = BinaryValue::from_bitslice(full).unwrap();
+        let trace = compute_placement_trace::<Fp, S>(&ship);
+        assert_eq!(
+            trace[1][BOARD_SIZE - 1],
+            Fp::from(max_full_windows(S) as u64)
+        );
+    }
+
+    #[test]
+    fn fast_trace_matches() {
+        // every legal placement, the fixture board, and the empty/ full boards
+        let mut ships: Vec<BinaryValue> = [ShipType::Carrier, ShipType::Destroyer]
+            .into_iter()
+            .flat_map(Ship::placements)
+            .map(|ship| ship.bits(true))
+            .collect();
+        ships.push(pattern_1().state(DEFAULT_WITNESS_OPTIONS));
+        ships.push(BinaryValue::empty());
+        ships.push(
+            BinaryValue::from_bitslice(&[u32::MAX; 4].view_bits::<Lsb0>()[0..BOARD_SIZE]).unwrap(),
+        );
+        for ship in ships.iter() {
+            assert_eq!(
+                compute_placement_trace_fast::<Fp, 2>(ship),
+                compute_placement_trace::<Fp, 2>(ship)
+            );
+            assert_eq!(
+                compute_placement_trace_fast::<Fp, 5>(ship),
+                compute_placement_trace::<Fp, 5>(ship)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_wrapped_full_window_sum() {
+        // a nonconsecutive destroyer at (3, 4), (5, 4) honestly produces 0 full bit windows
+        let mut ship = Ship::new(ShipType::Destroyer, 3, 4, false).bits(true);
+        ship.value.set(44, false);
+        ship.value.set(45, true);
+        let mut circuit = PlacementCircuit::<S, false>::new(ship);
+        // increment the full window sum by 2 at row 2 and by -1 (p - 1) at row 5 so the
+        // running sum wraps the field and ends at exactly one full window
+        for i in 2..BOARD_SIZE {
+            circuit.trace[1][i] += Fp::from(2);
+        }
+        for i in 5..BOARD_SIZE {
+            circuit.trace[1][i] -= Fp::one();
+        }
+        let prover = MockProver::run(9, &circuit, vec![]).unwrap();
+        // expect the incrementor and the boolean increment range check to fail on both rows
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (2, GATE_ADJACENCY_BIT_COUNT).into(),
+                        0,
+                        "Full Window Running Sum"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (2, "placement running sum trace").into(),
+                        offset: 3,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0")),
+                        (((Any::Advice, 0).into(), 1).into(), String::from("0")),
+                        (((Any::Advice, 2).into(), -1).into(), String::from("0")),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0x2")),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (2, GATE_ADJACENCY_BIT_COUNT).into(),
+                        1,
+                        "Full Window Increment is boolean"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (2, "placement running sum trace").into(),
+                        offset: 3,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 2).into(), -1).into(), String::from("0")),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0x2")),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (2, GATE_ADJACENCY_BIT_COUNT).into(),
+                        0,
+                        "Full Window Running Sum"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (2, "placement running sum trace").into(),
+                        offset: 6,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0")),
+                        (((Any::Advice, 0).into(), 1).into(), String::from("0")),
+                        (((Any::Advice, 2).into(), -1).into(), String::from("0x2")),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("1")),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (2, GATE_ADJACENCY_BIT_COUNT).into(),
+                        1,
+                        "Full Window Increment is boolean"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (2, "placement running sum trace").into(),
+                        offset: 6,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 2).into(), -1).into(), String::from("0x2")),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("1")),
+                    ]
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn shared_config_placements() {
+        // the horizontal cases of placement_lengths, proven back to back with one config
+        let ship = Ship::new(ShipType::Destroyer, 0, 4, false).bits(true);
+        let mut stray = ship;
+        stray.value.set(90, true);
+        let mut split = ship;
+        split.value.set(41, false);
+        split.value.set(42, true);
+        let valid = vec![ship, Ship::new(ShipType::Destroyer, 3, 4, false).bits(true)];
+        let circuit = SharedConfigCircuit::<S> { ships: valid };
+        let prover = MockProver::run(11, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // only the malformed placements fail, so no witness leaks between placements
+        let circuit = SharedConfigCircuit::<S> {
+            ships: vec![ship, stray, ship, split],
+        };
+        let prover = MockProver::run(11, &circuit, vec![]).unwrap();
+        let failures: Vec<String> = prover
+            .verify()
+            .unwrap_err()
+            .iter()
+            .map(|failure| match failure {
+                VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                    format!("{}", constraint)
+                }
+                failure => panic!("Unexpected failure {:?}", failure),
+            })
+            .collect();
+        assert_eq!(failures.len(), 2, "{:?}", failures);
+        assert!(failures[0].contains("Placed ship of correct length"));
+        assert!(failures[1].contains("One full bit window"));
+    }
+
+    #[test]
+    #[should_panic(expected = "PlacementChip columns must be distinct")]
+    fn aliased_columns() {
+        let mut meta = ConstraintSystem::<Fp>::default();
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let fixed = meta.fixed_column();
+        PlacementChip::<Fp, S>::configure(&mut meta, advice[0], advice[1], advice[0], fixed);
+    }
+}
@@ -0,0 +1,225 @@
+use {
+    crate::{
+        chips::{
+            bitify::{BitifyConfig, Num2BitsChip},
+            gate_names::GATE_REVEAL_CONSISTENCY,
+            pedersen::{PedersenCommitmentChip, PedersenCommitmentConfig},
+        },
+        utils::{binary::BinaryValue, board::BOARD_SIZE},
+    },
+    halo2_proofs::{
+        circuit::{AssignedCell, Chip, Layouter, Value},
+        pasta::pallas,
+        plonk::{
+            Advice, Column, ConstraintSystem, Constraints, Error, Expression, Fixed, Instance,
+            Selector, TableColumn,
+        },
+        poly::Rotation,
+    },
+};
+
+/**
+ * Storage for a proof that a revealed board opens the board commitment and agrees with every
+ * hit/ miss claimed during the game
+ *
+ * @param num2bits - num2bits config shared by the board, claimed hits and claimed misses
+ * @param pedersen - pedersen commitment config used to re-derive the board commitment
+ * @param advice - advice columns shared throughout the regions of RevealChip
+ * @param fixed - fixed columns for constant values in RevealChip
+ * @param instance - public [commitment x, commitment y, board, claimed hits, claimed misses]
+ * @param selector - selector toggling the reveal consistency gate
+ */
+#[derive(Clone, Debug)]
+pub struct RevealConfig {
+    // chip configs
+    pub num2bits: BitifyConfig,
+    pub pedersen: PedersenCommitmentConfig,
+    // columns
+    pub advice: [Column<Advice>; 10],
+    pub fixed: [Column<Fixed>; 8],
+    pub table_idx: TableColumn,
+    pub instance: Column<Instance>,
+    // selectors
+    pub selector: Selector,
+}
+
+pub struct RevealChip {
+    config: RevealConfig,
+}
+
+impl Chip<pallas::Base> for RevealChip {
+    type Config = RevealConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl RevealChip {
+    pub fn new(config: RevealConfig) -> Self {
+        RevealChip { config }
+    }
+
+    /**
+     * Configure the computation space of the circuit & return RevealConfig
+     */
+    pub fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> RevealConfig {
+        // define advice
+        let advice = [(); 10].map(|_| {
+            let col = meta.advice_column();
+            meta.enable_equality(col);
+            col
+        });
+
+        // define fixed; fixed[0] has constant enabled
+        let fixed = [(); 8].map(|_| meta.fixed_column());
+        meta.enable_constant(fixed[0]);
+
+        // define table column
+        let table_idx = meta.lookup_table_column();
+
+        // define instance
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        // define selector
+        let selector = meta.selector();
+
+        // define num2bits chip
+        let num2bits = Num2BitsChip::<_, BOARD_SIZE>::configure(
+            meta, advice[5], advice[6], advice[7], fixed[0],
+        );
+
+        // define pedersen chip
+        let pedersen = PedersenCommitmentChip::configure(meta, advice, fixed, table_idx);
+
+        // define gates
+        meta.create_gate(GATE_REVEAL_CONSISTENCY, |meta| {
+            // query cells used in gate
+            let board_bit = meta.query_advice(advice[5], Rotation::cur());
+            let hit_bit = meta.query_advice(advice[6], Rotation::cur());
+            let miss_bit = meta.query_advice(advice[7], Rotation::cur());
+            let one = Expression::Constant(pallas::Base::one());
+            // constrain using selector
+            // - board AND claimed misses == 0
+            // - claimed hits AND NOT board == 0
+            let selector = meta.query_selector(selector);
+            Constraints::with_selector(
+                selector,
+                [
+                    (
+                        "claimed miss is not on the board",
+                        board_bit.clone() * miss_bit,
+                    ),
+                    ("claimed hit is on the board", hit_bit * (one - board_bit)),
+                ],
+            )
+        });
+
+        // return config
+        RevealConfig {
+            num2bits,
+            pedersen,
+            advice,
+            fixed,
+            table_idx,
+            instance,
+            selector,
+        }
+    }
+
+    /**
+     * Synthesize a proof that a revealed board is consistent with its commitment and all claims
+     *
+     * @param board - the revealed board state
+     * @param board_commitment_trapdoor - the trapdoor for the board commitment
+     * @param hits - union of all shots answered as hits during the game
+     * @param misses - union of all shots answered as misses during the game
+     * @return - Ok if synthesis executes successfully
+     */
+    pub fn synthesize(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        hits: BinaryValue,
+        misses: BinaryValue,
+    ) -> Result<(), Error> {
+        let values = [board, hits, misses];
+        // load the revealed board and claims as advice
+        let inputs = layouter.assign_region(
+            || "load reveal advice values",
+            |mut region| {
+                let mut assigned = Vec::<AssignedCell<pallas::Base, pallas::Base>>::new();
+                for (i, value) in values.iter().enumerate() {
+                    assigned.push(region.assign_advice(
+                        || format!("assign reveal input {}", i),
+                        self.config.advice[4],
+                        i,
+                        || Value::known(value.to_fp::<pallas::Base>()),
+                    )?);
+                }
+                Ok(assigned)
+            },
+        )?;
+        // decompose board, claimed hits and claimed misses into constrained bits
+        let bits = layouter.assign_region(
+            || "reveal num2bits",
+            |mut region| {
+                let mut decomposed =
+                    Vec::<[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]>::new();
+                for (i, value) in values.iter().enumerate() {
+                    let chip = Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(
+                        inputs[i].clone(),
+                        value.bitfield::<pallas::Base, BOARD_SIZE>(),
+                    );
+                    decomposed.push(chip.assign(
+                        self.config.num2bits,
+                        &mut region,
+                        i * (BOARD_SIZE + 1),
+                    )?);
+                }
+                Ok(decomposed)
+            },
+        )?;
+        // check every claim against the revealed board
+        layouter.assign_region(
+            || "reveal consistency",
+            |mut region| {
+                let labels = ["board", "hit", "miss"];
+                for (j, (bits, label)) in bits.iter().zip(labels).enumerate() {
+                    for (i, bit) in bits.iter().enumerate() {
+                        bit.copy_advice(
+                            || format!("copy {} bit {}", label, i),
+                            &mut region,
+                            self.config.advice[5 + j],
+                            i,
+                        )?;
+                    }
+                }
+                for i in 0..BOARD_SIZE {
+                    self.config.selector.enable(&mut region, i)?;
+                }
+                Ok(())
+            },
+        )?;
+        // re-derive the board commitment
+        let commitment = PedersenCommitmentChip::new(self.config.pedersen.clone()).synthesize(
+            layouter.namespace(|| "pedersen"),
+            &inputs[0],
+            Value::known(board_commitment_trapdoor),
+        )?;
+        // export public values
+        layouter.constrain_instance(commitment.x.cell(), self.config.instance, 0)?;
+        layouter.constrain_instance(commitment.y.cell(), self.config.instance, 1)?;
+        for (i, input) in inputs.iter().enumerate() {
+            layouter.constrain_instance(input.cell(), self.config.instance, i + 2)?;
+        }
+        Ok(())
+    }
+}
@@ -2,20 +2,36 @@ use {
     crate::{
         chips::{
             bitify::{BitifyConfig, Num2BitsChip},
+            boolean::{BoolCheckChip, BoolCheckConfig},
+            columns::ColumnSet,
+            gate_names::{
+                GATE_BOOLEAN_HIT_ASSERTION, GATE_SHOT_PACKING, GATE_SHOT_RUNNING_SUM_OUTPUT,
+                GATE_SHOT_RUNNING_SUM_ROW,
+            },
             pedersen::{PedersenCommitmentChip, PedersenCommitmentConfig},
+            placement::AssignedBits,
+            shot_index::{ShotIndexChip, ShotIndexConfig},
+            trace::traced,
+        },
+        utils::{
+            binary::BinaryValue, board::BOARD_SIZE, error::to_fixed_array,
+            pedersen::pedersen_coordinates,
         },
-        utils::{binary::BinaryValue, board::BOARD_SIZE, pedersen::pedersen_commit},
+    },
+    halo2_gadgets::poseidon::{
+        primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+        Hash, Pow5Chip, Pow5Config,
     },
     halo2_proofs::{
-        arithmetic::{CurveAffine, FieldExt},
         circuit::{AssignedCell, Chip, Layouter, Value},
-        pasta::{group::Curve, pallas},
+        pasta::pallas,
         plonk::{
             Advice, Column, ConstraintSystem, Constraints, Error, Expression, Fixed, Instance,
             Selector, TableColumn,
         },
         poly::Rotation,
     },
+    std::collections::HashMap,
 };
 
 /**
@@ -29,25 +45,85 @@ pub fn compute_shot_trace(
     board: BinaryValue,
     shot: BinaryValue,
 ) -> [[pallas::Base; BOARD_SIZE]; 2] {
-    let mut hit_trace = Vec::<pallas::Base>::new();
-    let mut shot_trace = Vec::<pallas::Base>::new();
+    let mut hit_trace = [pallas::Base::zero(); BOARD_SIZE];
+    let mut shot_trace = [pallas::Base::zero(); BOARD_SIZE];
 
     // assign first round manually
-    hit_trace.push(pallas::Base::from(board.value[0] && shot.value[0]));
-    shot_trace.push(pallas::Base::from(shot.value[0]));
+    hit_trace[0] = pallas::Base::from(board.value[0] && shot.value[0]);
+    shot_trace[0] = pallas::Base::from(shot.value[0]);
     for i in 1..BOARD_SIZE {
         // hit_trace: if board and shot have flipped bit, prev hit_trace + 1 else prev hit trace
         let condition = board.value[i] && shot.value[i];
-        let new_hit_trace = hit_trace[hit_trace.len() - 1] + pallas::Base::from(condition);
-        hit_trace.push(new_hit_trace);
+        hit_trace[i] = hit_trace[i - 1] + pallas::Base::from(condition);
         // shot_trace: prev shot_trace + shot_trace
-        let new_shot_trace = shot_trace[shot_trace.len() - 1] + pallas::Base::from(shot.value[i]);
-        shot_trace.push(new_shot_trace);
+        shot_trace[i] = shot_trace[i - 1] + pallas::Base::from(shot.value[i]);
     }
-    [
-        shot_trace.try_into().unwrap(),
-        hit_trace.try_into().unwrap(),
-    ]
+    [shot_trace, hit_trace]
+}
+
+/**
+ * How the public hit assertion of a ShotChip is interpreted
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HitMode {
+    // a single shot whose hit assertion is constrained to 0 or 1
+    Boolean,
+    // a salvo of any number of shots whose hit assertion is the number of hits
+    // @dev the number of shots is not constrained; it is public as the set bits of the shot commitment
+    Count,
+}
+
+/**
+ * Storage for packing the shot index and hit assertion into a single public output
+ * @dev the index is the one bounded by ShotConfig::shot_index
+ *
+ * @param selector - selector toggling the packing gate
+ */
+#[derive(Clone, Debug)]
+pub struct ShotPackingConfig {
+    pub selector: Selector,
+}
+
+/**
+ * Storage for committing to the hit assertion instead of exporting it
+ *
+ * @param poseidon - Pow5 config over the first 4 shared advice columns and 6 shared fixed columns
+ */
+#[derive(Clone, Debug)]
+pub struct ShotBlindingConfig {
+    pub poseidon: Pow5Config<pallas::Base, 3, 2>,
+}
+
+/**
+ * Storage for committing to the board state with Poseidon instead of Pedersen
+ *
+ * @param poseidon - Pow5 config over the first 4 shared advice columns and 6 shared fixed columns
+ */
+#[derive(Clone, Debug)]
+pub struct ShotPoseidonConfig {
+    pub poseidon: Pow5Config<pallas::Base, 3, 2>,
+}
+
+/**
+ * Commit to a board state with Poseidon outside of the circuit
+ * @dev the commitment is not blinded, matching ShotChip::synthesize_poseidon()
+ *
+ * @param board_state - the board state as a field element
+ * @return - Poseidon(board state)
+ */
+pub fn poseidon_board_commitment(board_state: pallas::Base) -> pallas::Base {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([board_state])
+}
+
+/**
+ * Commit to the hit assertion of a blind shot outside of the circuit
+ *
+ * @param hit - the hit assertion (0 or 1)
+ * @param nonce - randomly sampled blinding factor hiding the hit
+ * @return - Poseidon(hit, nonce)
+ */
+pub fn blind_hit_commitment(hit: pallas::Base, nonce: pallas::Base) -> pallas::Base {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([hit, nonce])
 }
 
 /**
@@ -57,6 +133,13 @@ pub fn compute_shot_trace(
  * @param advice - advice columns shared throughout instructions/ chips/ regions of ShotChip
  * @param selectors - selectors used to toggle gates in ShotChip
  * @param fixed - fixed columns for constant values in ShotChip
+ * @param hit_mode - whether the hit assertion is a boolean or a hit count
+ * @param hit_check - boolean check of the hit assertion, set in HitMode::Boolean only
+ * @param shot_index - derives y * 10 + x from the shot bits and bounds it to the board, set in
+ *        HitMode::Boolean only
+ * @param packing - if set, the shot and hit are exported as one packed public output
+ * @param blinding - if set, synthesize_blind() exports a commitment to the hit instead of the hit
+ * @param poseidon - if set, synthesize_poseidon() commits to the board with Poseidon
  */
 #[derive(Clone, Debug)]
 pub struct ShotConfig {
@@ -70,6 +153,61 @@ pub struct ShotConfig {
     pub instance: Column<Instance>,
     // selectors
     pub selectors: [Selector; 3],
+    pub hit_mode: HitMode,
+    pub hit_check: Option<BoolCheckConfig>,
+    pub shot_index: Option<ShotIndexConfig>,
+    pub packing: Option<ShotPackingConfig>,
+    pub blinding: Option<ShotBlindingConfig>,
+    pub poseidon: Option<ShotPoseidonConfig>,
+}
+
+/**
+ * Names of the roles the ShotConfig advice columns play, in column order
+ * @dev columns 0-3 are only used by the ecc chip; board_bits, shot_bits and shot_sum also hold the
+ *      hit assertion, shot count and hit count in the running sum output row, and shot_sum, hit_sum
+ *      and range_check hold the index, hit and packed output when packing is configured
+ */
+const ADVICE_COLUMN_NAMES: [&str; 10] = [
+    "ecc_0",
+    "ecc_1",
+    "ecc_2",
+    "ecc_3",
+    "board_state",
+    "board_bits",
+    "shot_bits",
+    "shot_sum",
+    "hit_sum",
+    "range_check",
+];
+
+impl ShotConfig {
+    /**
+     * Name each advice column by the role it plays in ShotChip
+     *
+     * @return - map of column name to advice column
+     */
+    pub fn column_map(&self) -> HashMap<&'static str, Column<Advice>> {
+        ShotConfig::name_columns(self.advice)
+    }
+
+    /**
+     * Names of the advice columns in column order, for labelling columns in error messages
+     *
+     * @return - the name of each advice column
+     */
+    pub fn debug_column_names() -> [&'static str; 10] {
+        ADVICE_COLUMN_NAMES
+    }
+
+    /**
+     * Name advice columns before a ShotConfig exists to hold them
+     *
+     * @param advice - the advice columns in column order
+     * @return - map of column name to advice column
+     */
+    fn name_columns(advice: [Column<Advice>; 10]) -> HashMap<&'static str, Column<Advice>> {
+        ADVICE_COLUMN_NAMES.into_iter().zip(advice).collect()
+    }
 }
 
 pub struct ShotChip {
@@ -96,7 +234,7 @@ pub trait ShotInstructions {
      * @param board_state - advice 100 bit number to decompose to serialized board state
      * @param board_commitment - instance poseidon hash of board_state
      * @param shot_commitment - instance 100 bit number (1 bit flipped) representing shot
-     * @param hit - instance (constrained to be boolean) value conveying shot hit status
+     * @param hit - instance value conveying shot hit status (boolean in HitMode::Boolean, else hit count)
      * @return reference to assigned cells of each input in order above
      */
     fn load_advice(
@@ -111,6 +249,7 @@ pub trait ShotInstructions {
     /**
      * Decompose board_state, shot_commitment into 100 bits each
      * @dev order in arrays: [board_state, shot_commitment]
+     * @dev both values share num2bits[0] in a single region (see decompose_n)
      *
      * @param num - assignements to state/ shot commitment values
      * @param bits - unassigned binary decomposition of assigned values
@@ -123,6 +262,21 @@ pub trait ShotInstructions {
         bits: [[pallas::Base; BOARD_SIZE]; 2],
     ) -> Result<[[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]; 2], Error>;
 
+    /**
+     * Decompose any number of values into 100 bits each in a single region
+     * @dev every value reuses num2bits[0], laid out in consecutive blocks of BOARD_SIZE + 1 rows
+     *
+     * @param nums - assignments to the values to decompose
+     * @param bits - unassigned binary decomposition of each assigned value
+     * @return - assignments to the decomposed bits of each value in the same order
+     */
+    fn decompose_n<const N: usize>(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        nums: [AssignedCell<pallas::Base, pallas::Base>; N],
+        bits: [[pallas::Base; BOARD_SIZE]; N],
+    ) -> Result<[[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]; N], Error>;
+
     /**
      * Perform the running sum constrains comparing the shot commitment and board state bits
      *
@@ -175,75 +329,75 @@ impl ShotChip {
 
     /**
      * Configure the computation space of the circuit & return ShotConfig
+     * @dev HitMode::Count omits the boolean hit assertion gate and the single shot constraint, so the
+     *      two modes produce different verifying keys
+     *
+     * @param hit_mode - whether the hit assertion is a boolean or a hit count
      */
-    pub fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> ShotConfig {
-        // define advice
-        let mut advice = Vec::<Column<Advice>>::new();
-        for _ in 0..10 {
-            let col = meta.advice_column();
-            meta.enable_equality(col);
-            advice.push(col);
-        }
-        let advice: [Column<Advice>; 10] = advice.try_into().unwrap();
-        let input = meta.advice_column();
-        meta.enable_equality(input);
-
-        // define fixed
-        let mut fixed = Vec::<Column<Fixed>>::new();
-        for _ in 0..8 {
-            let col = meta.fixed_column();
-            fixed.push(col);
-        }
-
-        // fixed[0] has constant enabled
-        let fixed: [Column<Fixed>; 8] = fixed.try_into().unwrap();
-        meta.enable_constant(fixed[0]);
-
-        // define table column
-        let table_idx = meta.lookup_table_column();
+    pub fn configure(meta: &mut ConstraintSystem<pallas::Base>, hit_mode: HitMode) -> ShotConfig {
+        // the extra advice column is unused but kept so the verifying key does not change
+        let (columns, _) = ColumnSet::configure(meta, 1);
+        ShotChip::configure_with_columns(meta, columns, hit_mode)
+    }
 
-        // define instance
-        let instance = meta.instance_column();
-        meta.enable_equality(instance);
+    /**
+     * Configure the chip over columns owned by the circuit & return ShotConfig
+     * @dev lets several chips share columns; chips that each commit to a board must not share the
+     *      table column, as every commitment loads the lookup table
+     *
+     * @param columns - the columns to configure the chip over
+     * @param hit_mode - whether the hit assertion is a boolean or a hit count
+     */
+    pub fn configure_with_columns(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        columns: ColumnSet,
+        hit_mode: HitMode,
+    ) -> ShotConfig {
+        let ColumnSet {
+            advice,
+            fixed,
+            instance,
+            table_idx,
+        } = columns;
+        let column_map = ShotConfig::name_columns(advice);
 
         // define selectors
-        let mut selectors = Vec::<Selector>::new();
-        for _ in 0..3 {
-            selectors.push(meta.selector());
-        }
-        let selectors: [Selector; 3] = selectors.try_into().unwrap();
+        let selectors = [(); 3].map(|_| meta.selector());
 
         // define bits2num chips
-        let mut num2bits = Vec::<BitifyConfig>::new();
-        for _ in 0..2 {
-            num2bits.push(Num2BitsChip::<_, BOARD_SIZE>::configure(
-                meta, advice[5], advice[6], advice[7], fixed[0],
-            ));
-        }
-        let num2bits: [BitifyConfig; 2] = num2bits.try_into().unwrap();
+        let num2bits = [(); 2].map(|_| {
+            Num2BitsChip::<_, BOARD_SIZE>::configure(
+                meta,
+                column_map["board_bits"],
+                column_map["shot_bits"],
+                column_map["shot_sum"],
+                fixed[0],
+            )
+        });
 
         // define pedersen chip
         let pedersen = PedersenCommitmentChip::configure(meta, advice, fixed, table_idx);
 
         // define gates
-        meta.create_gate("boolean hit assertion", |meta| {
-            let assertion = meta.query_advice(advice[4], Rotation::cur());
-            let one = Expression::Constant(pallas::Base::one());
-            let constraint = (one - assertion.clone()) * assertion.clone();
-            // constrain using selector[0]
-            // - the asserted hit/miss value is a boolean (0 or 1)
-            let selector = meta.query_selector(selectors[0]);
-            Constraints::with_selector(selector, [("asserted hit value is boolean", constraint)])
-        });
+        // - the asserted hit/miss value is a boolean (0 or 1), toggled by selector[0]
+        let hit_check = match hit_mode {
+            HitMode::Boolean => Some(BoolCheckChip::configure(
+                meta,
+                GATE_BOOLEAN_HIT_ASSERTION,
+                column_map["board_state"],
+                selectors[0],
+            )),
+            HitMode::Count => None,
+        };
 
-        meta.create_gate("shot running sum row", |meta| {
+        meta.create_gate(GATE_SHOT_RUNNING_SUM_ROW, |meta| {
             // query cells used in gate
-            let hit_bit = meta.query_advice(advice[5], Rotation::cur());
-            let shot_bit = meta.query_advice(advice[6], Rotation::cur());
-            let shot_sum = meta.query_advice(advice[7], Rotation::cur());
-            let hit_sum = meta.query_advice(advice[8], Rotation::cur());
-            let prev_shot_sum = meta.query_advice(advice[7], Rotation::prev());
-            let prev_hit_sum = meta.query_advice(advice[8], Rotation::prev());
+            let hit_bit = meta.query_advice(column_map["board_bits"], Rotation::cur());
+            let shot_bit = meta.query_advice(column_map["shot_bits"], Rotation::cur());
+            let shot_sum = meta.query_advice(column_map["shot_sum"], Rotation::cur());
+            let hit_sum = meta.query_advice(column_map["hit_sum"], Rotation::cur());
+            let prev_shot_sum = meta.query_advice(column_map["shot_sum"], Rotation::prev());
+            let prev_hit_sum = meta.query_advice(column_map["hit_sum"], Rotation::prev());
             // constraint expressions
             let shot_constraint = shot_bit.clone() + prev_shot_sum - shot_sum;
             let hit_constraint = hit_bit * shot_bit + prev_hit_sum - hit_sum;
@@ -260,30 +414,42 @@ impl ShotChip {
             )
         });
 
-        meta.create_gate("constrain shot running sum output", |meta| {
+        meta.create_gate(GATE_SHOT_RUNNING_SUM_OUTPUT, |meta| {
             // query cells used in gate
-            let hit_assertion = meta.query_advice(advice[5], Rotation::cur());
-            let shot_count = meta.query_advice(advice[6], Rotation::cur());
-            let hit_count = meta.query_advice(advice[7], Rotation::cur());
+            let hit_assertion = meta.query_advice(column_map["board_bits"], Rotation::cur());
+            let shot_count = meta.query_advice(column_map["shot_bits"], Rotation::cur());
+            let hit_count = meta.query_advice(column_map["shot_sum"], Rotation::cur());
             // constraint expressions
             let shot_constraint = Expression::Constant(pallas::Base::one()) - shot_count;
             let hit_constraint = hit_assertion - hit_count;
             // constrain using selector[2]
-            // - shot_sum = 1
+            // - shot_sum = 1 (HitMode::Boolean only)
             // - hit_sum = hit_assertion
             let selector = meta.query_selector(selectors[2]);
-            Constraints::with_selector(
-                selector,
-                [
-                    ("Shot only fires at one board cell", shot_constraint),
-                    (
-                        "Public hit assertion matches private witness",
-                        hit_constraint,
-                    ),
-                ],
-            )
+            let mut constraints = Vec::new();
+            if hit_mode == HitMode::Boolean {
+                constraints.push(("Shot only fires at one board cell", shot_constraint));
+            }
+            constraints.push((
+                "Public hit assertion matches private witness",
+                hit_constraint,
+            ));
+            Constraints::with_selector(selector, constraints)
         });
 
+        // - the index of the single shot bit is looked up below the board size (HitMode::Boolean
+        //   only, as the weighted sum of a salvo is not an index), reusing the pedersen range check
+        let shot_index = match hit_mode {
+            HitMode::Boolean => Some(ShotIndexChip::configure(
+                meta,
+                column_map["board_bits"],
+                column_map["shot_bits"],
+                fixed[7],
+                pedersen.range_check,
+            )),
+            HitMode::Count => None,
+        };
+
         // return config
         ShotConfig {
             num2bits,
@@ -293,9 +459,96 @@ impl ShotChip {
             table_idx,
             instance,
             selectors,
+            hit_mode,
+            hit_check,
+            shot_index,
+            packing: None,
+            blinding: None,
+            poseidon: None,
         }
     }
 
+    /**
+     * Configure a boolean shot circuit that exports y * 10 + x + hit * 100 as its only public output
+     * besides the board commitment
+     * @dev adds the packing gate over the bounded shot index, so the verifying key differs from
+     *      configure()
+     *
+     * @return - configured ShotConfig with packing set
+     */
+    pub fn configure_packed(meta: &mut ConstraintSystem<pallas::Base>) -> ShotConfig {
+        let mut config = ShotChip::configure(meta, HitMode::Boolean);
+        let column_map = config.column_map();
+
+        // define gates
+        let selector = meta.selector();
+        meta.create_gate(GATE_SHOT_PACKING, |meta| {
+            let index = meta.query_advice(column_map["shot_sum"], Rotation::cur());
+            let hit = meta.query_advice(column_map["hit_sum"], Rotation::cur());
+            let packed = meta.query_advice(column_map["range_check"], Rotation::cur());
+            let offset = Expression::Constant(pallas::Base::from(BOARD_SIZE as u64));
+            // constrain using selector
+            // - packed = index + hit * 100
+            let selector = meta.query_selector(selector);
+            Constraints::with_selector(
+                selector,
+                [("packed shot output", index + hit * offset - packed)],
+            )
+        });
+
+        config.packing = Some(ShotPackingConfig { selector });
+        config
+    }
+
+    /**
+     * Configure a boolean shot circuit whose public hit output is Poseidon(hit, nonce), so the
+     * shooter does not learn the result until the defender opens the commitment
+     * @dev adds Poseidon gates, so the verifying key differs from configure(); prove with
+     *      synthesize_blind()
+     *
+     * @return - configured ShotConfig with blinding set
+     */
+    pub fn configure_blind(meta: &mut ConstraintSystem<pallas::Base>) -> ShotConfig {
+        let mut config = ShotChip::configure(meta, HitMode::Boolean);
+        let poseidon = ShotChip::configure_pow5(meta, &config);
+        config.blinding = Some(ShotBlindingConfig { poseidon });
+        config
+    }
+
+    /**
+     * Configure a boolean shot circuit that commits to the board with either Pedersen or Poseidon
+     * @dev adds Poseidon gates, so the verifying key differs from configure(); prove a Pedersen
+     *      commitment with synthesize() and a Poseidon commitment with synthesize_poseidon()
+     *
+     * @return - configured ShotConfig with poseidon set
+     */
+    pub fn configure_poseidon(meta: &mut ConstraintSystem<pallas::Base>) -> ShotConfig {
+        let mut config = ShotChip::configure(meta, HitMode::Boolean);
+        let poseidon = ShotChip::configure_pow5(meta, &config);
+        config.poseidon = Some(ShotPoseidonConfig { poseidon });
+        config
+    }
+
+    /**
+     * Configure a Pow5 Poseidon chip over the shared columns of a ShotConfig
+     *
+     * @param config - the config whose first 4 advice and first 6 fixed columns the chip uses
+     * @return - Pow5 config with width 3 and rate 2
+     */
+    fn configure_pow5(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        config: &ShotConfig,
+    ) -> Pow5Config<pallas::Base, 3, 2> {
+        let (advice, fixed) = (config.advice, config.fixed);
+        Pow5Chip::configure::<P128Pow5T3>(
+            meta,
+            [advice[0], advice[1], advice[2]],
+            advice[3],
+            [fixed[0], fixed[1], fixed[2]],
+            [fixed[3], fixed[4], fixed[5]],
+        )
+    }
+
     /**
      * Synthesize a proof of a valid board
      *
@@ -313,44 +566,444 @@ impl ShotChip {
         shot: BinaryValue,
         hit: BinaryValue,
     ) -> Result<(), Error> {
+        let (commitment, inputs, shot_bits) =
+            self.synthesize_shot(&mut layouter, board, board_commitment_trapdoor, shot, hit)?;
+        // export public values
+        traced("shot", "export", 4 + shot_bits.len(), || {
+            self.export(
+                &mut layouter,
+                &commitment,
+                &inputs[3],
+                &inputs[4],
+                &shot_bits,
+            )
+        })
+    }
+
+    /**
+     * Synthesize a proof of a shot whose hit assertion stays private behind Poseidon(hit, nonce)
+     * @dev requires a config from configure_blind(); the public values are
+     *      [commitment x, commitment y, shot, Poseidon(hit, nonce)]
+     *
+     * @param board - the board state in BinaryValue form for bits-> integer functions
+     * @param board_commitment_trapdoor - the trapdoor for the board commitment
+     * @param shot - the shot commitment in BinaryValue form for bits-> integer functions
+     * @param hit - true/ false assertion if shot produces hit on board
+     * @param nonce - randomly sampled blinding factor hiding the hit
+     * @return - Ok if synthesis executes successfully, or Error::Synthesis without blinding configured
+     */
+    pub fn synthesize_blind(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+        nonce: pallas::Base,
+    ) -> Result<(), Error> {
+        let blinding = self.config.blinding.as_ref().ok_or(Error::Synthesis)?;
+        let column_map = self.config.column_map();
+        let (commitment, inputs, shot_bits) =
+            self.synthesize_shot(&mut layouter, board, board_commitment_trapdoor, shot, hit)?;
+        // commit to the constrained hit assertion
+        let nonce = layouter.assign_region(
+            || "load hit nonce",
+            |mut region| {
+                region.assign_advice(
+                    || "assign hit nonce",
+                    column_map["board_state"],
+                    0,
+                    || Value::known(nonce),
+                )
+            },
+        )?;
+        let chip = Pow5Chip::construct(blinding.poseidon.clone());
+        let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<2>, 3, 2>::init(
+            chip,
+            layouter.namespace(|| "init hit commitment"),
+        )?;
+        let hit_commitment = hasher.hash(
+            layouter.namespace(|| "hit commitment"),
+            [inputs[4].clone(), nonce],
+        )?;
+        // bound the shot to the board
+        self.bound_shot_index(&mut layouter, &shot_bits)?;
+        // export public values
+        layouter.constrain_instance(commitment[0].cell(), self.config.instance, 0)?;
+        layouter.constrain_instance(commitment[1].cell(), self.config.instance, 1)?;
+        layouter.constrain_instance(inputs[3].cell(), self.config.instance, 2)?;
+        layouter.constrain_instance(hit_commitment.cell(), self.config.instance, 3)
+    }
+
+    /**
+     * Synthesize a proof of a shot against a Poseidon commitment to the board
+     * @dev requires a config from configure_poseidon(); the public values are
+     *      [Poseidon(board state), 0, shot, hit], the layout synthesize() exports with the hash and
+     *      a zero in place of the commitment coordinates
+     *
+     * @param board - the board state in BinaryValue form for bits-> integer functions
+     * @param shot - the shot commitment in BinaryValue form for bits-> integer functions
+     * @param hit - true/ false assertion if shot produces hit on board
+     * @return - Ok if synthesis executes successfully, or Error::Synthesis without poseidon set
+     */
+    pub fn synthesize_poseidon(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        shot: BinaryValue,
+        hit: BinaryValue,
+    ) -> Result<(), Error> {
+        let poseidon = self.config.poseidon.as_ref().ok_or(Error::Synthesis)?;
+        let column_map = self.config.column_map();
+        let board_commitment = [
+            poseidon_board_commitment(board.to_fp()),
+            pallas::Base::zero(),
+        ];
+        let (inputs, shot_bits) =
+            self.synthesize_hit(&mut layouter, board, board_commitment, shot, hit)?;
+        // commit to the constrained board state
+        let chip = Pow5Chip::construct(poseidon.poseidon.clone());
+        let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<1>, 3, 2>::init(
+            chip,
+            layouter.namespace(|| "init board commitment"),
+        )?;
+        let commitment = hasher.hash(
+            layouter.namespace(|| "board commitment"),
+            [inputs[0].clone()],
+        )?;
+        // pad the hash to the two coordinates a pedersen commitment exports
+        let padding = layouter.assign_region(
+            || "pad board commitment",
+            |mut region| {
+                region.assign_advice_from_constant(
+                    || "board commitment padding",
+                    column_map["board_state"],
+                    0,
+                    pallas::Base::zero(),
+                )
+            },
+        )?;
+        // the shot index looks up the table that a pedersen commitment would otherwise load
+        PedersenCommitmentChip::new(self.config.pedersen.clone()).load_table(&mut layouter)?;
+        // export public values
+        self.export(
+            &mut layouter,
+            &[commitment, padding],
+            &inputs[3],
+            &inputs[4],
+            &shot_bits,
+        )
+    }
+
+    /**
+     * Synthesize the hit/ miss computation shared by every shot proof, without exporting anything
+     *
+     * @param board - the board state in BinaryValue form for bits-> integer functions
+     * @param board_commitment_trapdoor - the trapdoor for the board commitment
+     * @param shot - the shot commitment in BinaryValue form for bits-> integer functions
+     * @param hit - true/ false assertion if shot produces hit on board
+     * @return - assigned board commitment, loaded inputs and shot bits
+     */
+    #[allow(clippy::type_complexity)]
+    fn synthesize_shot(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+    ) -> Result<
+        (
+            [AssignedCell<pallas::Base, pallas::Base>; 2],
+            [AssignedCell<pallas::Base, pallas::Base>; 5],
+            AssignedBits<pallas::Base>,
+        ),
+        Error,
+    > {
+        let board_commitment = pedersen_coordinates(&board.to_fp(), &board_commitment_trapdoor)?;
+        let (inputs, shot_bits) =
+            self.synthesize_hit(layouter, board, board_commitment, shot, hit)?;
+        // commit to board state
+        let commitment = traced("shot", "commit_board", 1, || {
+            self.commit_board(layouter, inputs[0].clone(), board_commitment_trapdoor)
+        })?;
+        Ok((commitment, inputs, shot_bits))
+    }
+
+    /**
+     * Synthesize the hit/ miss computation over a board without committing to it
+     *
+     * @param board - the board state in BinaryValue form for bits-> integer functions
+     * @param board_commitment - board commitment computed outside of the circuit, witnessed as is
+     * @param shot - the shot commitment in BinaryValue form for bits-> integer functions
+     * @param hit - true/ false assertion if shot produces hit on board
+     * @return - loaded inputs and shot bits
+     */
+    #[allow(clippy::type_complexity)]
+    fn synthesize_hit(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        board_commitment: [pallas::Base; 2],
+        shot: BinaryValue,
+        hit: BinaryValue,
+    ) -> Result<
+        (
+            [AssignedCell<pallas::Base, pallas::Base>; 5],
+            AssignedBits<pallas::Base>,
+        ),
+        Error,
+    > {
         // compute values to witness
-        let board_state = pallas::Base::from_u128(board.lower_u128());
-        let board_commitment = {
-            let commitment = pedersen_commit(&board_state, &board_commitment_trapdoor).to_affine();
-            let x = commitment.clone().coordinates().unwrap().x().to_owned();
-            let y = commitment.clone().coordinates().unwrap().y().to_owned();
-            [x, y]
-        };
-        let shot_commitment = pallas::Base::from_u128(shot.lower_u128());
+        let board_state = board.to_fp();
+        let shot_commitment = shot.to_fp();
         let bits = [
             board.bitfield::<pallas::Base, BOARD_SIZE>(),
             shot.bitfield::<pallas::Base, BOARD_SIZE>(),
         ];
         let trace = compute_shot_trace(board, shot);
         // load inputs as advice
+        let inputs = traced("shot", "load_advice", 5, || {
+            self.load_advice(
+                layouter,
+                board_state,
+                board_commitment,
+                shot_commitment,
+                hit.to_fp(),
+            )
+        })?;
+        // decompose board_state and ship_commitment into constrained bits
+        let assigned_bits = traced("shot", "decompose", 2, || {
+            self.decompose(layouter, [inputs[0].clone(), inputs[3].clone()], bits)
+        })?;
+        let shot_bits = assigned_bits[1].clone();
+        // synthesize running sum
+        let running_sum_results = traced("shot", "running_sums", 2 * BOARD_SIZE, || {
+            self.running_sums(layouter, assigned_bits, trace)
+        })?;
+        // constrain results of running sum
+        traced("shot", "running_sum_output", 3, || {
+            self.running_sum_output(layouter, inputs[4].clone(), running_sum_results)
+        })?;
+        Ok((inputs, shot_bits))
+    }
+
+    /**
+     * Synthesize a proof of a shot against a board that was already decomposed in this circuit
+     * @dev skips the board num2bits; board_bits must be a constrained decomposition of board_state
+     *      (e.g. from BoardChip) as this method only permutes them into the running sum
+     *
+     * @param board - the board state in BinaryValue form for computing the running sum trace
+     * @param board_state - assigned board state the board commitment is computed over
+     * @param board_bits - assigned LE decomposition of board_state
+     * @param board_commitment_trapdoor - the trapdoor for the board commitment
+     * @param shot - the shot commitment in BinaryValue form for bits-> integer functions
+     * @param hit - true/ false assertion if shot produces hit on board
+     * @return - Ok if synthesis executes successfully
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn synthesize_with_board_bits(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        board_state: &AssignedCell<pallas::Base, pallas::Base>,
+        board_bits: &AssignedBits<pallas::Base>,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+    ) -> Result<(), Error> {
+        let column_map = self.config.column_map();
+        // compute values to witness
+        let board_commitment = pedersen_coordinates(&board.to_fp(), &board_commitment_trapdoor)?;
+        let shot_commitment = shot.to_fp();
+        let trace = compute_shot_trace(board, shot);
+        // load inputs as advice
         let inputs = self.load_advice(
             &mut layouter,
-            board_state,
+            board.to_fp(),
             board_commitment,
             shot_commitment,
-            pallas::Base::from_u128(hit.lower_u128()),
+            hit.to_fp(),
+        )?;
+        // tie the loaded board state to the already decomposed board state
+        layouter.assign_region(
+            || "permute decomposed board state",
+            |mut region| {
+                let copied = board_state.copy_advice(
+                    || "copy decomposed board state",
+                    &mut region,
+                    column_map["board_state"],
+                    0,
+                )?;
+                region.constrain_equal(copied.cell(), inputs[0].cell())
+            },
+        )?;
+        // decompose only the shot commitment into constrained bits
+        let chip = Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(
+            inputs[3].clone(),
+            shot.bitfield::<pallas::Base, BOARD_SIZE>(),
+        );
+        let shot_bits = chip.synthesize(
+            self.config.num2bits[1],
+            layouter.namespace(|| "shot_commitment bits2num"),
+        )?;
+        // synthesize running sum over the existing board bits
+        let running_sum_results = self.running_sums(
+            &mut layouter,
+            [board_bits.clone(), shot_bits.clone()],
+            trace,
         )?;
-        // decompose board_state and ship_commitment into constrained bits
-        let assigned_bits =
-            self.decompose(&mut layouter, [inputs[0].clone(), inputs[3].clone()], bits)?;
-        // synthesize running sum
-        let running_sum_results = self.running_sums(&mut layouter, assigned_bits, trace)?;
         // constrain results of running sum
         self.running_sum_output(&mut layouter, inputs[4].clone(), running_sum_results)?;
         // commit to board state
         let commitment =
             self.commit_board(&mut layouter, inputs[0].clone(), board_commitment_trapdoor)?;
         // export public values
+        self.export(
+            &mut layouter,
+            &commitment,
+            &inputs[3],
+            &inputs[4],
+            &shot_bits,
+        )
+    }
+
+    /**
+     * Synthesize a shot fired by the prover at an opponent board that is not part of the circuit
+     * @dev the running sum is evaluated over the prover's own board bits so the single shot
+     *      constraint of HitMode::Boolean applies; the hit count against the prover's own board is
+     *      witnessed but not exported, and nothing is committed or exported by this method
+     * @dev the shot index lookup relies on the circuit loading the table on table_idx, e.g. through
+     *      a board commitment over shared columns
+     *
+     * @param board - the prover's board state in BinaryValue form for computing the running sum trace
+     * @param board_bits - assigned LE decomposition of the prover's board state
+     * @param shot - the shot commitment in BinaryValue form for bits-> integer functions
+     * @return - assigned shot commitment for the circuit to export
+     */
+    pub fn synthesize_fired_shot(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        board: BinaryValue,
+        board_bits: &AssignedBits<pallas::Base>,
+        shot: BinaryValue,
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let column_map = self.config.column_map();
+        let trace = compute_shot_trace(board, shot);
+        // load the shot and its hit count against the prover's own board as advice
+        let (shot_commitment, hit) = layouter.assign_region(
+            || "load fired shot advice",
+            |mut region| {
+                let shot_commitment = region.assign_advice(
+                    || "assign shot commitment",
+                    column_map["board_state"],
+                    0,
+                    || Value::known(shot.to_fp()),
+                )?;
+                let hit = region.assign_advice(
+                    || "assign own board hit count",
+                    column_map["board_state"],
+                    1,
+                    || Value::known(trace[1][BOARD_SIZE - 1]),
+                )?;
+                Ok((shot_commitment, hit))
+            },
+        )?;
+        // decompose only the shot commitment into constrained bits
+        let chip = Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(
+            shot_commitment.clone(),
+            shot.bitfield::<pallas::Base, BOARD_SIZE>(),
+        );
+        let shot_bits = chip.synthesize(
+            self.config.num2bits[1],
+            layouter.namespace(|| "shot_commitment bits2num"),
+        )?;
+        // synthesize running sum over the prover's board bits and constrain a single shot
+        let running_sum_results = self.running_sums(
+            &mut layouter,
+            [board_bits.clone(), shot_bits.clone()],
+            trace,
+        )?;
+        self.running_sum_output(&mut layouter, hit, running_sum_results)?;
+        // bound the shot to the board
+        self.bound_shot_index(&mut layouter, &shot_bits)?;
+        Ok(shot_commitment)
+    }
+
+    /**
+     * Derive y * 10 + x from the single set shot bit and lookup constrain it below the board size
+     * @dev the lookup table on table_idx must be loaded by the circuit (e.g. by commit_board)
+     *
+     * @param shot_bits - assigned LE decomposition of the shot commitment
+     * @return - assigned shot index, or None in HitMode::Count where no shot index is configured
+     */
+    fn bound_shot_index(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        shot_bits: &[AssignedCell<pallas::Base, pallas::Base>],
+    ) -> Result<Option<AssignedCell<pallas::Base, pallas::Base>>, Error> {
+        match &self.config.shot_index {
+            Some(shot_index) => Ok(Some(
+                ShotIndexChip::new(shot_index.clone())
+                    .synthesize(layouter.namespace(|| "shot index"), shot_bits)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /**
+     * Export the public values of a shot proof
+     * @dev the shot index is bounded to the board before the shot is exported
+     * @dev with packing configured, the shot and hit are replaced by y * 10 + x + hit * 100 at row 2
+     *
+     * @param commitment - assigned board commitment coordinates
+     * @param shot - assigned shot commitment
+     * @param hit - assigned hit assertion
+     * @param shot_bits - assigned LE decomposition of the shot commitment
+     * @return - Ok if the public values were constrained
+     */
+    fn export(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        commitment: &[AssignedCell<pallas::Base, pallas::Base>; 2],
+        shot: &AssignedCell<pallas::Base, pallas::Base>,
+        hit: &AssignedCell<pallas::Base, pallas::Base>,
+        shot_bits: &[AssignedCell<pallas::Base, pallas::Base>],
+    ) -> Result<(), Error> {
+        let column_map = self.config.column_map();
+        let index = self.bound_shot_index(layouter, shot_bits)?;
         layouter.constrain_instance(commitment[0].cell(), self.config.instance, 0)?;
         layouter.constrain_instance(commitment[1].cell(), self.config.instance, 1)?;
-        layouter.constrain_instance(inputs[3].cell(), self.config.instance, 2)?;
-        layouter.constrain_instance(inputs[4].cell(), self.config.instance, 3)?;
-        Ok(())
+        let packing = match &self.config.packing {
+            Some(packing) => packing,
+            None => {
+                layouter.constrain_instance(shot.cell(), self.config.instance, 2)?;
+                return layouter.constrain_instance(hit.cell(), self.config.instance, 3);
+            }
+        };
+        // pack the bounded y * 10 + x, configured alongside packing in HitMode::Boolean
+        let index = index.ok_or(Error::Synthesis)?;
+        let packed = layouter.assign_region(
+            || "pack shot output",
+            |mut region| {
+                index.copy_advice(|| "copy shot index", &mut region, column_map["shot_sum"], 0)?;
+                hit.copy_advice(
+                    || "copy hit assertion",
+                    &mut region,
+                    column_map["hit_sum"],
+                    0,
+                )?;
+                packing.selector.enable(&mut region, 0)?;
+                let offset = Value::known(pallas::Base::from(BOARD_SIZE as u64));
+                region.assign_advice(
+                    || "packed shot output",
+                    column_map["range_check"],
+                    0,
+                    || index.value().cloned() + hit.value().cloned() * offset,
+                )
+            },
+        )?;
+        layouter.constrain_instance(packed.cell(), self.config.instance, 2)
     }
 }
 
@@ -363,41 +1016,49 @@ impl ShotInstructions for ShotChip {
         shot_commitment: pallas::Base,
         hit: pallas::Base,
     ) -> Result<[AssignedCell<pallas::Base, pallas::Base>; 5], Error> {
+        let column_map = self.config.column_map();
         Ok(layouter.assign_region(
             || "load private ShotChip advice values",
             |mut region| {
                 let board_state = region.assign_advice(
                     || "assign board state",
-                    self.config.advice[4],
+                    column_map["board_state"],
                     0,
                     || Value::known(board_state),
                 )?;
                 let x = region.assign_advice(
                     || "assign board state",
-                    self.config.advice[4],
+                    column_map["board_state"],
                     1,
                     || Value::known(board_commitment[0]),
                 )?;
                 let y = region.assign_advice(
                     || "assign board state",
-                    self.config.advice[4],
+                    column_map["board_state"],
                     2,
                     || Value::known(board_commitment[1]),
                 )?;
                 let shot_commitment = region.assign_advice(
                     || "assign shot commitment",
-                    self.config.advice[4],
+                    column_map["board_state"],
                     3,
                     || Value::known(shot_commitment),
                 )?;
-                let hit = region.assign_advice(
-                    || "assign hit assertion",
-                    self.config.advice[4],
-                    4,
-                    || Value::known(hit),
-                )?;
-                // enable selector to check hit is binary
-                self.config.selectors[0].enable(&mut region, 4)?;
+                // check hit is binary in HitMode::Boolean
+                let hit = match self.config.hit_check {
+                    Some(hit_check) => BoolCheckChip::new(hit_check).assign_bool(
+                        &mut region,
+                        "assign hit assertion",
+                        4,
+                        Value::known(hit),
+                    )?,
+                    None => region.assign_advice(
+                        || "assign hit assertion",
+                        column_map["board_state"],
+                        4,
+                        || Value::known(hit),
+                    )?,
+                };
                 Ok([board_state, x, y, shot_commitment, hit])
             },
         )?)
@@ -409,19 +1070,33 @@ impl ShotInstructions for ShotChip {
         num: [AssignedCell<pallas::Base, pallas::Base>; 2],
         bits: [[pallas::Base; BOARD_SIZE]; 2],
     ) -> Result<[[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]; 2], Error> {
-        // decompose board state
-        let chip = Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(num[0].clone(), bits[0]);
-        let board_state = chip.synthesize(
-            self.config.num2bits[0],
-            layouter.namespace(|| "board_state num2bits"),
-        )?;
-        // decompose shot commitment
-        let chip = Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(num[1].clone(), bits[1]);
-        let shot_commitment = chip.synthesize(
-            self.config.num2bits[1],
-            layouter.namespace(|| "shot_commitment bits2num"),
-        )?;
-        Ok([board_state, shot_commitment])
+        // decompose board state and shot commitment in one region over num2bits[0]
+        self.decompose_n(layouter, num, bits)
+    }
+
+    fn decompose_n<const N: usize>(
+        &self,
+        layouter: &mut impl Layouter<pallas::Base>,
+        nums: [AssignedCell<pallas::Base, pallas::Base>; N],
+        bits: [[pallas::Base; BOARD_SIZE]; N],
+    ) -> Result<[[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]; N], Error> {
+        layouter.assign_region(
+            || format!("num2bits x {}", N),
+            |mut region| {
+                let mut decomposed =
+                    Vec::<[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]>::new();
+                for i in 0..N {
+                    let chip =
+                        Num2BitsChip::<pallas::Base, BOARD_SIZE>::new(nums[i].clone(), bits[i]);
+                    decomposed.push(chip.assign(
+                        self.config.num2bits[0],
+                        &mut region,
+                        i * (BOARD_SIZE + 1),
+                    )?);
+                }
+                Ok(to_fixed_array(decomposed)?)
+            },
+        )
     }
 
     fn running_sums(
@@ -430,47 +1105,48 @@ impl ShotInstructions for ShotChip {
         bits: [[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE]; 2],
         trace: [[pallas::Base; BOARD_SIZE]; 2],
     ) -> Result<[AssignedCell<pallas::Base, pallas::Base>; 2], Error> {
+        let column_map = self.config.column_map();
         Ok(layouter.assign_region(
             || "shot running sum",
             |mut region| {
                 // pad first row
                 let mut shot_sum = region.assign_advice_from_constant(
                     || "pad bit sum column",
-                    self.config.advice[7],
+                    column_map["shot_sum"],
                     0,
                     pallas::Base::zero(),
                 )?;
                 let mut hit_sum = region.assign_advice_from_constant(
                     || "pad shot hit sum column",
-                    self.config.advice[8],
+                    column_map["hit_sum"],
                     0,
                     pallas::Base::zero(),
                 )?;
                 // assign rows
                 for i in 0..BOARD_SIZE {
                     // permute bits for row
-                    _ = bits[0][i].copy_advice(
+                    bits[0][i].copy_advice(
                         || format!("copy board bit {}", i),
                         &mut region,
-                        self.config.advice[5],
+                        column_map["board_bits"],
                         i + 1,
                     )?;
-                    _ = bits[1][i].copy_advice(
+                    bits[1][i].copy_advice(
                         || format!("copy shot bit {}", i),
                         &mut region,
-                        self.config.advice[6],
+                        column_map["shot_bits"],
                         i + 1,
                     )?;
                     // assign trace for row
                     shot_sum = region.assign_advice(
                         || format!("shot bit count sum {}", i),
-                        self.config.advice[7],
+                        column_map["shot_sum"],
                         i + 1,
                         || Value::known(trace[0][i]),
                     )?;
                     hit_sum = region.assign_advice(
                         || format!("board hit count sum {}", i),
-                        self.config.advice[8],
+                        column_map["hit_sum"],
                         i + 1,
                         || Value::known(trace[1][i]),
                     )?;
@@ -487,6 +1163,7 @@ impl ShotInstructions for ShotChip {
         hit: AssignedCell<pallas::Base, pallas::Base>,
         output: [AssignedCell<pallas::Base, pallas::Base>; 2],
     ) -> Result<(), Error> {
+        let column_map = self.config.column_map();
         Ok(layouter.assign_region(
             || "shot running sum output checks",
             |mut region| {
@@ -494,19 +1171,19 @@ impl ShotInstructions for ShotChip {
                 hit.copy_advice(
                     || "permute hit assertion",
                     &mut region,
-                    self.config.advice[5],
+                    column_map["board_bits"],
                     0,
                 )?;
                 output[0].copy_advice(
                     || "permute shot bit count",
                     &mut region,
-                    self.config.advice[6],
+                    column_map["shot_bits"],
                     0,
                 )?;
                 output[1].copy_advice(
                     || "permute board hits by shot count",
                     &mut region,
-                    self.config.advice[7],
+                    column_map["shot_sum"],
                     0,
                 )?;
                 self.config.selectors[2].enable(&mut region, 0)?;
@@ -528,9 +1205,6 @@ impl ShotInstructions for ShotChip {
             Value::known(board_commitment_trapdoor),
         )?;
         // return pedersen commitment points
-        Ok([
-            commitment.clone().inner().x(),
-            commitment.clone().inner().y(),
-        ])
+        Ok(commitment.coordinates())
     }
 }
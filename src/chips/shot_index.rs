@@ -0,0 +1,324 @@
+use {
+    crate::{
+        chips::gate_names::{GATE_SHOT_INDEX_BOUND, GATE_SHOT_INDEX_SUM},
+        utils::{board::BOARD_SIZE, constants::LOOKUP_SIZE},
+    },
+    halo2_gadgets::utilities::lookup_range_check::LookupRangeCheckConfig,
+    halo2_proofs::{
+        circuit::{AssignedCell, Chip, Layouter, Value},
+        pasta::pallas,
+        plonk::{
+            Advice, Column, ConstraintSystem, Constraints, Error, Expression, Fixed, Selector,
+        },
+        poly::Rotation,
+    },
+};
+
+/**
+ * Storage for a proof that the flipped bit of a shot commitment lies on the board
+ *
+ * @param bits - advice column the decomposed shot bits are copied into
+ * @param index - advice column holding the running sum of bit index * bit
+ * @param weight - fixed column holding the index of the bit in each row
+ * @param selectors - [weighted sum row, bound shift] selectors
+ * @param range_check - 10 bit lookup range check used to bound the index
+ */
+#[derive(Clone, Debug)]
+pub struct ShotIndexConfig {
+    pub bits: Column<Advice>,
+    pub index: Column<Advice>,
+    pub weight: Column<Fixed>,
+    pub selectors: [Selector; 2],
+    pub range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>,
+}
+
+pub struct ShotIndexChip {
+    config: ShotIndexConfig,
+}
+
+impl Chip<pallas::Base> for ShotIndexChip {
+    type Config = ShotIndexConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl ShotIndexChip {
+    pub fn new(config: ShotIndexConfig) -> Self {
+        ShotIndexChip { config }
+    }
+
+    /**
+     * Configure the computation space of the chip & return ShotIndexConfig
+     * @dev the lookup table of range_check must be loaded by the circuit
+     *
+     * @param bits - equality enabled advice column to copy shot bits into
+     * @param index - equality enabled advice column for the index running sum
+     * @param weight - fixed column for bit indices
+     * @param range_check - lookup range check config over the 10 bit table
+     * @return - configured ShotIndexConfig
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        bits: Column<Advice>,
+        index: Column<Advice>,
+        weight: Column<Fixed>,
+        range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>,
+    ) -> ShotIndexConfig {
+        let selectors = [meta.selector(), meta.selector()];
+
+        meta.create_gate(GATE_SHOT_INDEX_SUM, |meta| {
+            let bit = meta.query_advice(bits, Rotation::cur());
+            let weight = meta.query_fixed(weight, Rotation::cur());
+            let sum = meta.query_advice(index, Rotation::cur());
+            let prev_sum = meta.query_advice(index, Rotation::prev());
+            // constrain using selector[0]
+            // - index sum = prev index sum + bit index * bit
+            let selector = meta.query_selector(selectors[0]);
+            Constraints::with_selector(
+                selector,
+                [("weighted sum of shot bits", prev_sum + weight * bit - sum)],
+            )
+        });
+
+        meta.create_gate(GATE_SHOT_INDEX_BOUND, |meta| {
+            let shifted = meta.query_advice(bits, Rotation::cur());
+            let sum = meta.query_advice(index, Rotation::prev());
+            let shift = pallas::Base::from(((1 << LOOKUP_SIZE) - BOARD_SIZE) as u64);
+            // constrain using selector[1]
+            // - shifted = index + 2^10 - 100 so that shifted < 2^10 iff index < 100
+            let selector = meta.query_selector(selectors[1]);
+            Constraints::with_selector(
+                selector,
+                [(
+                    "shift index by 2^10 - board size",
+                    sum + Expression::Constant(shift) - shifted,
+                )],
+            )
+        });
+
+        ShotIndexConfig {
+            bits,
+            index,
+            weight,
+            selectors,
+            range_check,
+        }
+    }
+
+    /**
+     * Constrain the index of the flipped shot bit to lie within the board
+     * @dev assumes at most one bit is set (constrained by ShotChip); accepts decompositions wider than the board
+     *
+     * @param bits - assigned little endian decomposition of the shot commitment
+     * @return - assigned index of the flipped bit, lookup constrained to [0, BOARD_SIZE)
+     */
+    pub fn synthesize(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        bits: &[AssignedCell<pallas::Base, pallas::Base>],
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let [index, shifted] = layouter.assign_region(
+            || "shot index",
+            |mut region| {
+                let mut sum = region.assign_advice_from_constant(
+                    || "pad shot index sum",
+                    self.config.index,
+                    0,
+                    pallas::Base::zero(),
+                )?;
+                for (i, bit) in bits.iter().enumerate() {
+                    let row = i + 1;
+                    let weight = pallas::Base::from(i as u64);
+                    bit.copy_advice(
+                        || format!("copy shot bit {}", i),
+                        &mut region,
+                        self.config.bits,
+                        row,
+                    )?;
+                    region.assign_fixed(
+                        || format!("bit index {}", i),
+                        self.config.weight,
+                        row,
+                        || Value::known(weight),
+                    )?;
+                    let next = sum.value().cloned() + bit.value().cloned() * Value::known(weight);
+                    sum = region.assign_advice(
+                        || format!("shot index sum {}", i),
+                        self.config.index,
+                        row,
+                        || next,
+                    )?;
+                    self.config.selectors[0].enable(&mut region, row)?;
+                }
+                // shift the index so that the upper bound can be checked by the same lookup
+                let shift = pallas::Base::from(((1 << LOOKUP_SIZE) - BOARD_SIZE) as u64);
+                let shifted = region.assign_advice(
+                    || "shifted shot index",
+                    self.config.bits,
+                    bits.len() + 1,
+                    || sum.value().cloned() + Value::known(shift),
+                )?;
+                self.config.selectors[1].enable(&mut region, bits.len() + 1)?;
+                Ok([sum, shifted])
+            },
+        )?;
+        // index < 2^10 and index + 2^10 - 100 < 2^10 together constrain index < 100
+        self.config.range_check.copy_check(
+            layouter.namespace(|| "shot index range check"),
+            index.clone(),
+            1,
+            true,
+        )?;
+        self.config.range_check.copy_check(
+            layouter.namespace(|| "shifted shot index range check"),
+            shifted,
+            1,
+            true,
+        )?;
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::bitify::{BitifyConfig, Num2BitsChip},
+            utils::{binary::BinaryValue, shot::serialize},
+        },
+        halo2_proofs::{
+            circuit::SimpleFloorPlanner,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            plonk::{Any, Circuit, TableColumn},
+        },
+    };
+
+    // decompose shots wider than the board so that bits above index 99 can be witnessed
+    const WIDE_BITS: usize = 128;
+
+    #[derive(Clone, Debug)]
+    struct TestShotIndexConfig {
+        input: Column<Advice>,
+        num2bits: BitifyConfig,
+        shot_index: ShotIndexConfig,
+        table_idx: TableColumn,
+    }
+
+    #[derive(Clone, Debug)]
+    struct ShotIndexCircuit {
+        shot: BinaryValue,
+    }
+
+    impl Circuit<pallas::Base> for ShotIndexCircuit {
+        type Config = TestShotIndexConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let advice = [(); 5].map(|_| {
+                let column = meta.advice_column();
+                meta.enable_equality(column);
+                column
+            });
+            let fixed = [meta.fixed_column(), meta.fixed_column()];
+            meta.enable_constant(fixed[0]);
+            let table_idx = meta.lookup_table_column();
+            let num2bits = Num2BitsChip::<_, WIDE_BITS>::configure(
+                meta, advice[1], advice[2], advice[3], fixed[0],
+            );
+            let range_check = LookupRangeCheckConfig::configure(meta, advice[4], table_idx);
+            let shot_index =
+                ShotIndexChip::configure(meta, advice[1], advice[2], fixed[1], range_check);
+            TestShotIndexConfig {
+                input: advice[0],
+                num2bits,
+                shot_index,
+                table_idx,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            layouter.assign_table(
+                || "table_idx",
+                |mut table| {
+                    for index in 0..(1 << LOOKUP_SIZE) {
+                        table.assign_cell(
+                            || "table_idx",
+                            config.table_idx,
+                            index,
+                            || Value::known(pallas::Base::from(index as u64)),
+                        )?;
+                    }
+                    Ok(())
+                },
+            )?;
+            let shot = layouter.assign_region(
+                || "load shot",
+                |mut region| {
+                    region.assign_advice(
+                        || "shot",
+                        config.input,
+                        0,
+                        || Value::known(self.shot.to_fp::<pallas::Base>()),
+                    )
+                },
+            )?;
+            let bits = Num2BitsChip::<_, WIDE_BITS>::new(shot, self.shot.bitfield())
+                .synthesize(config.num2bits, layouter.namespace(|| "num2bits"))?;
+            ShotIndexChip::new(config.shot_index)
+                .synthesize(layouter.namespace(|| "shot index"), &bits)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn valid_last_cell() {
+        // shot at (9, 9) sets bit 99
+        let circuit = ShotIndexCircuit {
+            shot: serialize::<1>([9], [9]),
+        };
+        let prover = MockProver::run(11, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_off_board() {
+        // shot at (0, 10) sets bit 100, just above the board
+        let mut shot = BinaryValue::empty();
+        shot.value.set(BOARD_SIZE, true);
+        let circuit = ShotIndexCircuit { shot };
+        let prover = MockProver::run(11, &circuit, vec![]).unwrap();
+        // expect the shifted index 1024 to leave a nonzero running sum after one 10 bit lookup word
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 4).into(),
+                    location: FailureLocation::InRegion {
+                        region: (5, "1 words range check").into(),
+                        offset: 1
+                    }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Fixed, 0).into(),
+                    location: FailureLocation::OutsideRegion { row: 4 }
+                }
+            ])
+        );
+    }
+}
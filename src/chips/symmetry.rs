@@ -0,0 +1,392 @@
+use {
+    crate::{
+        chips::gate_names::{GATE_SYMMETRY_DIFF, GATE_SYMMETRY_INDEX},
+        utils::{board::BOARD_SIZE, symmetry::SYMMETRIES},
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        circuit::{AssignedCell, Chip, Layouter, Value},
+        pasta::pallas,
+        plonk::{
+            Advice, Column, ConstraintSystem, Constraints, Error, Expression, Fixed, Selector,
+        },
+        poly::Rotation,
+    },
+};
+
+/**
+ * Storage for a proof of which rotation or reflection (if any) maps one board state onto another
+ * @dev every symmetry is a fixed permutation of the 100 board bits, wired with copy constraints
+ *
+ * @param bits - advice column the bits of the compared board are copied into
+ * @param permuted - advice column per symmetry the permuted bits of the original board are copied into
+ * @param diffs - advice column per symmetry holding the running count of mismatched bits; reused by
+ *                the index region as [mismatches, inverse, is match, none matched yet, index]
+ * @param weight - fixed column holding the public index of the symmetry in each index row
+ * @param selectors - [mismatch count row, index row] selectors
+ */
+#[derive(Clone, Debug)]
+pub struct SymmetryConfig {
+    pub bits: Column<Advice>,
+    pub permuted: [Column<Advice>; 5],
+    pub diffs: [Column<Advice>; 5],
+    pub weight: Column<Fixed>,
+    pub selectors: [Selector; 2],
+}
+
+pub struct SymmetryChip {
+    config: SymmetryConfig,
+}
+
+impl Chip<pallas::Base> for SymmetryChip {
+    type Config = SymmetryConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl SymmetryChip {
+    pub fn new(config: SymmetryConfig) -> Self {
+        SymmetryChip { config }
+    }
+
+    /**
+     * Configure the computation space of the chip & return SymmetryConfig
+     * @dev the circuit must enable a constant column for the running sum seeds
+     *
+     * @param bits - equality enabled advice column to copy the compared board bits into
+     * @param permuted - equality enabled advice columns to copy the permuted original board bits into
+     * @param diffs - equality enabled advice columns for the mismatch counts and index
+     * @param weight - fixed column for symmetry indices
+     * @return - configured SymmetryConfig
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        bits: Column<Advice>,
+        permuted: [Column<Advice>; 5],
+        diffs: [Column<Advice>; 5],
+        weight: Column<Fixed>,
+    ) -> SymmetryConfig {
+        let selectors = [meta.selector(), meta.selector()];
+
+        meta.create_gate(GATE_SYMMETRY_DIFF, |meta| {
+            // bits are boolean, so (permuted - bit)^2 is 1 exactly when they differ
+            let bit = meta.query_advice(bits, Rotation::cur());
+            let selector = meta.query_selector(selectors[0]);
+            let constraints = (0..5)
+                .map(|i| {
+                    let permuted_bit = meta.query_advice(permuted[i], Rotation::cur());
+                    let diff = meta.query_advice(diffs[i], Rotation::cur());
+                    let prev_diff = meta.query_advice(diffs[i], Rotation::prev());
+                    let mismatch = permuted_bit - bit.clone();
+                    diff - prev_diff - mismatch.clone() * mismatch
+                })
+                .collect::<Vec<_>>();
+            Constraints::with_selector(selector, constraints)
+        });
+
+        meta.create_gate(GATE_SYMMETRY_INDEX, |meta| {
+            let mismatches = meta.query_advice(diffs[0], Rotation::cur());
+            let inverse = meta.query_advice(diffs[1], Rotation::cur());
+            let is_match = meta.query_advice(diffs[2], Rotation::cur());
+            let none = meta.query_advice(diffs[3], Rotation::cur());
+            let next_none = meta.query_advice(diffs[3], Rotation::next());
+            let index = meta.query_advice(diffs[4], Rotation::cur());
+            let next_index = meta.query_advice(diffs[4], Rotation::next());
+            let weight = meta.query_fixed(weight, Rotation::cur());
+            let one = Expression::Constant(pallas::Base::one());
+            // first match is the symmetry that matches while no earlier symmetry has
+            let first = is_match.clone() * none.clone();
+            let selector = meta.query_selector(selectors[1]);
+            Constraints::with_selector(
+                selector,
+                [
+                    (
+                        "is match when mismatches has no inverse",
+                        is_match.clone() - (one - mismatches.clone() * inverse),
+                    ),
+                    ("no match when any bit mismatches", mismatches * is_match),
+                    ("none matched yet", next_none - (none - first.clone())),
+                    (
+                        "accumulate index of first match",
+                        next_index - (index + weight * first),
+                    ),
+                ],
+            )
+        });
+
+        SymmetryConfig {
+            bits,
+            permuted,
+            diffs,
+            weight,
+            selectors,
+        }
+    }
+
+    /**
+     * Synthesize the index of the first symmetry mapping one board onto the other
+     *
+     * @param board - decomposed bits of the original board
+     * @param other - decomposed bits of the board compared against
+     * @return - assigned cell holding the public index (0 if no symmetry matches)
+     */
+    pub fn synthesize(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        board: &[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE],
+        other: &[AssignedCell<pallas::Base, pallas::Base>; BOARD_SIZE],
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        // count the bits each symmetry of the board disagrees with the other board on
+        let mismatches = layouter.assign_region(
+            || "symmetry mismatch counts",
+            |mut region| {
+                let mut counts = Vec::<AssignedCell<pallas::Base, pallas::Base>>::new();
+                for (i, symmetry) in SYMMETRIES.iter().enumerate() {
+                    let mut count = region.assign_advice_from_constant(
+                        || format!("symmetry {} mismatch seed", i),
+                        self.config.diffs[i],
+                        0,
+                        pallas::Base::zero(),
+                    )?;
+                    for (row, bit) in other.iter().enumerate() {
+                        let permuted = board[symmetry.source(row)].copy_advice(
+                            || format!("permute symmetry {} bit {}", i, row),
+                            &mut region,
+                            self.config.permuted[i],
+                            row + 1,
+                        )?;
+                        let mismatch = permuted.value().zip(bit.value()).map(|(a, b)| {
+                            let difference = *a - *b;
+                            difference * difference
+                        });
+                        count = region.assign_advice(
+                            || format!("symmetry {} mismatches to bit {}", i, row),
+                            self.config.diffs[i],
+                            row + 1,
+                            || count.value().copied() + mismatch,
+                        )?;
+                    }
+                    counts.push(count);
+                }
+                for (row, bit) in other.iter().enumerate() {
+                    bit.copy_advice(
+                        || format!("copy compared bit {}", row),
+                        &mut region,
+                        self.config.bits,
+                        row + 1,
+                    )?;
+                    self.config.selectors[0].enable(&mut region, row + 1)?;
+                }
+                Ok(counts)
+            },
+        )?;
+        // select the index of the first symmetry with no mismatches
+        layouter.assign_region(
+            || "symmetry index",
+            |mut region| {
+                let mut none = region.assign_advice_from_constant(
+                    || "none matched seed",
+                    self.config.diffs[3],
+                    0,
+                    pallas::Base::one(),
+                )?;
+                let mut index = region.assign_advice_from_constant(
+                    || "symmetry index seed",
+                    self.config.diffs[4],
+                    0,
+                    pallas::Base::zero(),
+                )?;
+                for (row, count) in mismatches.iter().enumerate() {
+                    let count = count.copy_advice(
+                        || format!("copy symmetry {} mismatches", row),
+                        &mut region,
+                        self.config.diffs[0],
+                        row,
+                    )?;
+                    let inverse = count
+                        .value()
+                        .map(|count| count.invert().unwrap_or(pallas::Base::zero()));
+                    region.assign_advice(
+                        || format!("symmetry {} mismatches inverse", row),
+                        self.config.diffs[1],
+                        row,
+                        || inverse,
+                    )?;
+                    let is_match = count
+                        .value()
+                        .map(|count| pallas::Base::from(count.is_zero_vartime() as u64));
+                    region.assign_advice(
+                        || format!("symmetry {} is match", row),
+                        self.config.diffs[2],
+                        row,
+                        || is_match,
+                    )?;
+                    let weight = pallas::Base::from(SYMMETRIES[row].index());
+                    region.assign_fixed(
+                        || format!("symmetry {} weight", row),
+                        self.config.weight,
+                        row,
+                        || Value::known(weight),
+                    )?;
+                    let first = is_match * none.value().copied();
+                    index = region.assign_advice(
+                        || format!("symmetry index after {}", row),
+                        self.config.diffs[4],
+                        row + 1,
+                        || index.value().copied() + first * Value::known(weight),
+                    )?;
+                    none = region.assign_advice(
+                        || format!("none matched after {}", row),
+                        self.config.diffs[3],
+                        row + 1,
+                        || none.value().copied() - first,
+                    )?;
+                    self.config.selectors[1].enable(&mut region, row)?;
+                }
+                Ok(index)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::bitify::{BitifyConfig, Num2BitsChip},
+            utils::{
+                binary::BinaryValue,
+                fixtures::{pattern_1, pattern_2},
+                ship::DEFAULT_WITNESS_OPTIONS,
+                symmetry::Symmetry,
+            },
+        },
+        halo2_proofs::{
+            circuit::SimpleFloorPlanner,
+            dev::MockProver,
+            plonk::{Circuit, Instance},
+        },
+    };
+
+    #[derive(Clone, Debug)]
+    struct TestSymmetryConfig {
+        input: Column<Advice>,
+        num2bits: BitifyConfig,
+        symmetry: SymmetryConfig,
+        instance: Column<Instance>,
+    }
+
+    #[derive(Clone, Debug)]
+    struct SymmetryCircuit {
+        board: BinaryValue,
+        other: BinaryValue,
+    }
+
+    impl Circuit<pallas::Base> for SymmetryCircuit {
+        type Config = TestSymmetryConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let advice = [(); 11].map(|_| {
+                let column = meta.advice_column();
+                meta.enable_equality(column);
+                column
+            });
+            let fixed = [meta.fixed_column(), meta.fixed_column()];
+            meta.enable_constant(fixed[0]);
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            let num2bits = Num2BitsChip::<_, BOARD_SIZE>::configure(
+                meta, advice[1], advice[2], advice[3], fixed[0],
+            );
+            let symmetry = SymmetryChip::configure(
+                meta,
+                advice[0],
+                [advice[1], advice[2], advice[3], advice[4], advice[5]],
+                [advice[6], advice[7], advice[8], advice[9], advice[10]],
+                fixed[1],
+            );
+            TestSymmetryConfig {
+                input: advice[0],
+                num2bits,
+                symmetry,
+                instance,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let mut bits = Vec::new();
+            for (i, board) in [self.board, self.other].iter().enumerate() {
+                let input = layouter.assign_region(
+                    || format!("load board {}", i),
+                    |mut region| {
+                        region.assign_advice(
+                            || "board",
+                            config.input,
+                            0,
+                            || Value::known(board.to_fp::<pallas::Base>()),
+                        )
+                    },
+                )?;
+                bits.push(
+                    Num2BitsChip::<_, BOARD_SIZE>::new(input, board.bitfield())
+                        .synthesize(config.num2bits, layouter.namespace(|| "num2bits"))?,
+                );
+            }
+            let index = SymmetryChip::new(config.symmetry).synthesize(
+                layouter.namespace(|| "symmetry"),
+                &bits[0],
+                &bits[1],
+            )?;
+            layouter.constrain_instance(index.cell(), config.instance, 0)
+        }
+    }
+
+    #[test]
+    fn valid_rotate_180() {
+        // a board rotated by 180 degrees exports the index of Rotate180
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let circuit = SymmetryCircuit {
+            board,
+            other: Symmetry::Rotate180.apply(&board),
+        };
+        let index = pallas::Base::from(Symmetry::Rotate180.index());
+        let prover = MockProver::run(9, &circuit, vec![vec![index]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // the rotated board cannot be passed off as unrelated
+        let prover = MockProver::run(9, &circuit, vec![vec![pallas::Base::zero()]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn valid_unrelated() {
+        // two unrelated boards export index 0
+        let circuit = SymmetryCircuit {
+            board: pattern_1().state(DEFAULT_WITNESS_OPTIONS),
+            other: pattern_2().state(DEFAULT_WITNESS_OPTIONS),
+        };
+        assert_eq!(Symmetry::find(&circuit.board, &circuit.other), None);
+        let prover = MockProver::run(9, &circuit, vec![vec![pallas::Base::zero()]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // and cannot claim to be a rotation of each other
+        let index = pallas::Base::from(Symmetry::Rotate180.index());
+        let prover = MockProver::run(9, &circuit, vec![vec![index]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,23 @@
+/**
+ * Run one step of a chip's synthesis inside a debug level tracing span
+ * @dev without the trace feature this is a plain call, so untraced builds pay nothing for it
+ *
+ * @param chip - name of the chip being synthesized, e.g. "board"
+ * @param step - name of the synthesis step, e.g. "decompose_commitments"
+ * @param cells - number of cells the step takes in, logged to size the step
+ * @param synthesize - the step to run
+ * @return - the result of the step
+ */
+#[cfg_attr(not(feature = "trace"), allow(unused_variables))]
+pub fn traced<T>(
+    chip: &'static str,
+    step: &'static str,
+    cells: usize,
+    synthesize: impl FnOnce() -> T,
+) -> T {
+    #[cfg(feature = "trace")]
+    let _span = tracing::debug_span!("synthesize", chip, step, cells).entered();
+    #[cfg(feature = "trace")]
+    tracing::debug!(cells, "assigning {} {}", chip, step);
+    synthesize()
+}
@@ -1,5 +1,8 @@
 use {
-    crate::utils::board::BOARD_SIZE,
+    crate::{
+        chips::gate_names::GATE_TRANSPOSE_ROW,
+        utils::{board::BOARD_SIZE, error::to_fixed_array},
+    },
     halo2_proofs::{
         arithmetic::FieldExt,
         circuit::{AssignedCell, Chip, Layouter, Region, Value},
@@ -13,9 +16,9 @@ use {
  * Takes an input of
  * @dev ex: if coordinate = 19 and z = 1, then coordinate = 91
  */
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct TransposeConfig<F: FieldExt> {
-    pub permuted_bits: [Column<Advice>; 10],
+    pub permuted_bits: Vec<Column<Advice>>, // one column per H/V ship commitment
     pub transposed_bits: Column<Advice>,
     pub selector: Selector, // constrains the transposition of row of all ship bits into a single board bit
     _marker: PhantomData<F>,
@@ -45,22 +48,22 @@ impl<F: FieldExt> TransposeChip<F> {
 
     pub fn configure(
         meta: &mut ConstraintSystem<F>,
-        permuted_bits: [Column<Advice>; 10],
+        permuted_bits: Vec<Column<Advice>>,
         transposed_bits: Column<Advice>,
     ) -> TransposeConfig<F> {
         // define selectors
         let selector = meta.selector();
 
-        meta.create_gate("transpose row constraint", |meta| {
+        meta.create_gate(GATE_TRANSPOSE_ROW, |meta| {
             // constrain a transpose row
             // sum(permuted_bits[i]) == transposed_bits[i]
-            // advice[10] == 0 or 1
+            // transposed_bits == 0 or 1
             let zero = Expression::Constant(F::zero());
             let one = Expression::Constant(F::one());
             let mut transposed_bit = zero;
-            for i in 0..10 {
+            for column in permuted_bits.iter() {
                 transposed_bit =
-                    transposed_bit.clone() + meta.query_advice(permuted_bits[i], Rotation::cur());
+                    transposed_bit.clone() + meta.query_advice(*column, Rotation::cur());
             }
             let transposed_trace = meta.query_advice(transposed_bits, Rotation::cur());
             let selector = meta.query_selector(selector);
@@ -100,48 +103,207 @@ impl<F: FieldExt> TransposeChip<F> {
         &self,
         layouter: &mut impl Layouter<F>,
         bits: &[F; BOARD_SIZE],
-        placements: &[[AssignedCell<F, F>; BOARD_SIZE]; 10],
+        placements: &[[AssignedCell<F, F>; BOARD_SIZE]],
     ) -> Result<[AssignedCell<F, F>; BOARD_SIZE], Error> {
-        Ok(layouter
-            .assign_region(
-                || "Transpose ship commitments",
-                |mut region: Region<F>| {
-                    // permute from bits2num chips
-                    for col in 0..10 {
-                        for row in 0..BOARD_SIZE {
-                            let transposed_index = if col % 2 == 1 {
-                                row % 10 * 10 + row / 10
-                            } else {
-                                row
-                            };
-                            let orientation = if col % 2 == 1 {
-                                "vertical"
-                            } else {
-                                "horizontal"
-                            };
-                            placements[col][transposed_index].clone().copy_advice(
-                                || format!("permute {} ship {} bit {}", orientation, col / 2, row),
-                                &mut region,
-                                self.config.permuted_bits[col],
-                                row,
-                            )?;
-                        }
-                    }
-                    // assign transposed commitment
-                    let mut assigned = Vec::<AssignedCell<F, F>>::new();
+        layouter.assign_region(
+            || "Transpose ship commitments",
+            |mut region: Region<F>| {
+                // permute from bits2num chips
+                for col in 0..placements.len() {
                     for row in 0..BOARD_SIZE {
-                        assigned.push(region.assign_advice(
-                            || format!("assign tranposed bit {}", row),
-                            self.config.transposed_bits,
+                        let transposed_index = if col % 2 == 1 {
+                            row % 10 * 10 + row / 10
+                        } else {
+                            row
+                        };
+                        let orientation = if col % 2 == 1 {
+                            "vertical"
+                        } else {
+                            "horizontal"
+                        };
+                        placements[col][transposed_index].clone().copy_advice(
+                            || format!("permute {} ship {} bit {}", orientation, col / 2, row),
+                            &mut region,
+                            self.config.permuted_bits[col],
                             row,
-                            || Value::known(bits[row].clone()),
-                        )?);
-                        // toggle transposed row constraint
-                        self.config.selector.enable(&mut region, row)?;
+                        )?;
                     }
-                    Ok(assigned.try_into().unwrap())
+                }
+                // assign transposed commitment
+                let mut assigned = Vec::<AssignedCell<F, F>>::new();
+                for row in 0..BOARD_SIZE {
+                    assigned.push(region.assign_advice(
+                        || format!("assign tranposed bit {}", row),
+                        self.config.transposed_bits,
+                        row,
+                        || Value::known(bits[row].clone()),
+                    )?);
+                    // toggle transposed row constraint
+                    self.config.selector.enable(&mut region, row)?;
+                }
+                Ok(to_fixed_array(assigned)?)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{binary::BinaryValue, fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS},
+        halo2_proofs::{
+            circuit::SimpleFloorPlanner,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            pasta::Fp,
+            plonk::Circuit,
+        },
+    };
+
+    #[derive(Clone, Debug)]
+    struct TransposeCircuit {
+        // H/V ship commitments in the order of chips::board::commitment_label
+        commitments: [BinaryValue; 10],
+        // claimed board state the commitments transpose onto
+        board: BinaryValue,
+    }
+
+    impl Circuit<Fp> for TransposeCircuit {
+        type Config = TransposeConfig<Fp>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> TransposeConfig<Fp> {
+            let permuted_bits = (0..10)
+                .map(|_| {
+                    let column = meta.advice_column();
+                    meta.enable_equality(column);
+                    column
+                })
+                .collect::<Vec<_>>();
+            let transposed_bits = meta.advice_column();
+            TransposeChip::configure(meta, permuted_bits, transposed_bits)
+        }
+
+        fn synthesize(
+            &self,
+            config: TransposeConfig<Fp>,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            // witness the commitment bits in place of the board chip's num2bits decompositions
+            let placements = layouter.assign_region(
+                || "witness commitments",
+                |mut region: Region<Fp>| {
+                    let mut placements = Vec::<[AssignedCell<Fp, Fp>; BOARD_SIZE]>::new();
+                    for (i, commitment) in self.commitments.iter().enumerate() {
+                        let bits = commitment.bitfield::<Fp, BOARD_SIZE>();
+                        let mut assigned = Vec::<AssignedCell<Fp, Fp>>::new();
+                        for (row, bit) in bits.iter().enumerate() {
+                            assigned.push(region.assign_advice(
+                                || format!("commitment {} bit {}", i, row),
+                                config.permuted_bits[i],
+                                row,
+                                || Value::known(*bit),
+                            )?);
+                        }
+                        placements.push(to_fixed_array(assigned)?);
+                    }
+                    Ok(placements)
                 },
-            )
-            .unwrap())
+            )?;
+            let chip = TransposeChip::new(config);
+            let board = self.board.bitfield::<Fp, BOARD_SIZE>();
+            chip.synthesize(&mut layouter, &board, &placements)?;
+            Ok(())
+        }
+    }
+
+    /**
+     * Transpose the commitments of board pattern 1 onto a claimed board
+     *
+     * @param commitments - the H/V ship commitments to transpose
+     * @param board - the claimed board state
+     * @return - the (constraint name, row) of every failed transpose row constraint
+     */
+    fn transpose_failures(
+        commitments: [BinaryValue; 10],
+        board: BinaryValue,
+    ) -> Vec<(String, usize)> {
+        let circuit = TransposeCircuit { commitments, board };
+        let prover = MockProver::run(8, &circuit, vec![]).unwrap();
+        prover
+            .verify()
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|failure| match failure {
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint,
+                    location: FailureLocation::InRegion { region, offset },
+                    ..
+                } => {
+                    assert_eq!(region, (1, "Transpose ship commitments").into());
+                    let constraint = format!("{}", constraint);
+                    assert!(constraint.contains(GATE_TRANSPOSE_ROW));
+                    let name = [
+                        "Constrain trace value integrity",
+                        "Constrain transposition of bit",
+                    ]
+                    .into_iter()
+                    .find(|name| constraint.contains(name))
+                    .unwrap();
+                    (String::from(name), offset)
+                }
+                failure => panic!("Unexpected failure {:?}", failure),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_transpose() {
+        // pattern 1 places its carrier and submarine vertically, so both orientations are transposed
+        let board = pattern_1();
+        let failures = transpose_failures(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+        );
+        assert_eq!(failures, vec![]);
+    }
+
+    #[test]
+    fn invalid_collision() {
+        // place an extra horizontal carrier bit over the cruiser at (0, 1)
+        let board = pattern_1();
+        let mut commitments = board.witness(DEFAULT_WITNESS_OPTIONS);
+        commitments[0].value.set(10, true);
+        let failures = transpose_failures(commitments, board.state(DEFAULT_WITNESS_OPTIONS));
+        // the row sums to 2, which is neither the claimed bit nor boolean
+        assert_eq!(
+            failures,
+            vec![
+                (String::from("Constrain trace value integrity"), 10),
+                (String::from("Constrain transposition of bit"), 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_claimed_board() {
+        // claim the empty cell (0, 0) and drop the cruiser's cell (0, 1)
+        let board = pattern_1();
+        let mut state = board.state(DEFAULT_WITNESS_OPTIONS);
+        state.value.set(0, true);
+        state.value.set(10, false);
+        let failures = transpose_failures(board.witness(DEFAULT_WITNESS_OPTIONS), state);
+        assert_eq!(
+            failures,
+            vec![
+                (String::from("Constrain trace value integrity"), 0),
+                (String::from("Constrain trace value integrity"), 10),
+            ]
+        );
     }
 }
@@ -0,0 +1,311 @@
+use {
+    crate::{
+        chips::shot::{blind_hit_commitment, ShotChip, ShotConfig},
+        utils::{binary::BinaryValue, commitment::BoardCommitment, error::BattleshipError},
+    },
+    halo2_gadgets::poseidon::{
+        primitives::{ConstantLength, P128Pow5T3},
+        Hash, Pow5Chip, Pow5Config,
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance},
+    },
+};
+
+/**
+ * Shot circuit variant for fog of war games where the shooter commits to a shot and only learns
+ * whether it hit once the defender opens the hit commitment with a HitRevealCircuit
+ */
+#[derive(Debug, Clone)]
+pub struct BlindShotCircuit {
+    pub board: BinaryValue,
+    pub board_commitment_trapdoor: pallas::Scalar,
+    pub shot: BinaryValue,
+    pub hit: BinaryValue,
+    pub nonce: pallas::Base,
+}
+
+impl Circuit<pallas::Base> for BlindShotCircuit {
+    type Config = ShotConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        self.clone()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        ShotChip::configure_blind(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        ShotChip::new(config).synthesize_blind(
+            layouter,
+            self.board,
+            self.board_commitment_trapdoor,
+            self.shot,
+            self.hit,
+            self.nonce,
+        )
+    }
+}
+
+impl BlindShotCircuit {
+    /**
+     * Construct a new shot circuit that keeps the hit assertion private
+     *
+     * @param board - private board placement
+     * @param board_commitment_trapdoor - trapdoor to the board commitment
+     * @param shot - x, y coordinates serialized into a shot commitment
+     * @param hit - assertion that the shot either hits or misses the decomposed board (constrained 0 or 1)
+     * @param nonce - randomly sampled blinding factor hiding the hit
+     * @return - instantiated BlindShotCircuit object
+     */
+    pub fn new(
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+        nonce: pallas::Base,
+    ) -> BlindShotCircuit {
+        BlindShotCircuit {
+            board,
+            board_commitment_trapdoor,
+            shot,
+            hit,
+            nonce,
+        }
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [commitment x, commitment y, shot, Poseidon(hit, nonce)], or IdentityCommitment
+     */
+    pub fn try_public_inputs(&self) -> Result<[pallas::Base; 4], BattleshipError> {
+        let commitment = BoardCommitment::try_commit(&self.board, &self.board_commitment_trapdoor)?;
+        Ok([
+            commitment.0[0],
+            commitment.0[1],
+            self.shot.to_fp(),
+            blind_hit_commitment(self.hit.to_fp(), self.nonce),
+        ])
+    }
+}
+
+/**
+ * Storage for a proof opening a blind shot's hit commitment
+ *
+ * @param advice - equality enabled advice columns; the first loads the hit and nonce
+ * @param poseidon - Pow5 config over the advice columns
+ * @param instance - instance column exporting [Poseidon(hit, nonce), hit]
+ */
+#[derive(Clone, Debug)]
+pub struct HitRevealConfig {
+    pub advice: [Column<Advice>; 4],
+    pub poseidon: Pow5Config<pallas::Base, 3, 2>,
+    pub instance: Column<Instance>,
+}
+
+/**
+ * Proof by the defender that the hit commitment of a BlindShotCircuit opens to a public hit
+ * @dev the nonce stays private, so the commitment cannot be linked to other commitments it blinds
+ */
+#[derive(Debug, Clone)]
+pub struct HitRevealCircuit {
+    pub hit: BinaryValue,
+    pub nonce: pallas::Base,
+}
+
+impl Circuit<pallas::Base> for HitRevealCircuit {
+    type Config = HitRevealConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        self.clone()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        let advice = [(); 4].map(|_| {
+            let column = meta.advice_column();
+            meta.enable_equality(column);
+            column
+        });
+        let fixed = [(); 6].map(|_| meta.fixed_column());
+        // the Poseidon capacity element is assigned from a constant
+        meta.enable_constant(fixed[0]);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+        let poseidon = Pow5Chip::configure::<P128Pow5T3>(
+            meta,
+            [advice[0], advice[1], advice[2]],
+            advice[3],
+            [fixed[0], fixed[1], fixed[2]],
+            [fixed[3], fixed[4], fixed[5]],
+        );
+        HitRevealConfig {
+            advice,
+            poseidon,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let (hit, nonce) = layouter.assign_region(
+            || "load hit opening",
+            |mut region| {
+                let hit = region.assign_advice(
+                    || "assign hit",
+                    config.advice[0],
+                    0,
+                    || Value::known(self.hit.to_fp()),
+                )?;
+                let nonce = region.assign_advice(
+                    || "assign hit nonce",
+                    config.advice[0],
+                    1,
+                    || Value::known(self.nonce),
+                )?;
+                Ok((hit, nonce))
+            },
+        )?;
+        let chip = Pow5Chip::construct(config.poseidon);
+        let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<2>, 3, 2>::init(
+            chip,
+            layouter.namespace(|| "init hit commitment"),
+        )?;
+        let commitment = hasher.hash(
+            layouter.namespace(|| "hit commitment"),
+            [hit.clone(), nonce],
+        )?;
+        // export public values
+        layouter.constrain_instance(commitment.cell(), config.instance, 0)?;
+        layouter.constrain_instance(hit.cell(), config.instance, 1)
+    }
+}
+
+impl HitRevealCircuit {
+    /**
+     * Construct a new proof opening a hit commitment
+     *
+     * @param hit - the hit assertion committed to by the blind shot
+     * @param nonce - the nonce the blind shot committed to the hit with
+     * @return - instantiated HitRevealCircuit object
+     */
+    pub fn new(hit: BinaryValue, nonce: pallas::Base) -> HitRevealCircuit {
+        HitRevealCircuit { hit, nonce }
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [Poseidon(hit, nonce), hit]
+     */
+    pub fn public_inputs(&self) -> [pallas::Base; 2] {
+        let hit = self.hit.to_fp();
+        [blind_hit_commitment(hit, self.nonce), hit]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::gate_names::GATE_SHOT_RUNNING_SUM_OUTPUT,
+            circuits::layout::recommended_k,
+            utils::{fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS, shot::serialize},
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            plonk::Any,
+        },
+        rand::rngs::OsRng,
+    };
+
+    /**
+     * Construct a blind shot against pattern 1
+     *
+     * @param x - horizontal coordinate of the shot
+     * @param y - vertical coordinate of the shot
+     * @param hit - the hit assertion
+     * @return - the blind shot circuit
+     */
+    fn blind_shot(x: u8, y: u8, hit: u8) -> BlindShotCircuit {
+        BlindShotCircuit::new(
+            pattern_1().state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+            serialize::<1>([x], [y]),
+            BinaryValue::from_u8(hit),
+            pallas::Base::random(&mut OsRng),
+        )
+    }
+
+    #[test]
+    fn valid_blind_shot_and_reveal() {
+        // (3, 5) hits the carrier of pattern 1 and (0, 0) is open water
+        for (x, y, hit) in [(3, 5, 1), (0, 0, 0)] {
+            let circuit = blind_shot(x, y, hit);
+            let public_inputs = circuit.try_public_inputs().unwrap();
+            // the shooter only sees a commitment to the hit
+            assert_ne!(public_inputs[3], pallas::Base::from(hit as u64));
+            let k = recommended_k(&circuit);
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.to_vec()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+            // the defender later opens the commitment without revealing the nonce
+            let reveal = HitRevealCircuit::new(circuit.hit, circuit.nonce);
+            assert_eq!(reveal.public_inputs()[0], public_inputs[3]);
+            let k = recommended_k(&reveal);
+            let prover =
+                MockProver::run(k, &reveal, vec![reveal.public_inputs().to_vec()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn invalid_blind_hit() {
+        // commit to a miss for a shot that hits the carrier
+        let circuit = blind_shot(3, 5, 0);
+        let k = recommended_k(&circuit);
+        let public_inputs = circuit.try_public_inputs().unwrap().to_vec();
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        let failures = prover.verify().unwrap_err();
+        assert_eq!(failures.len(), 1, "{:?}", failures);
+        match &failures[0] {
+            VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                let constraint = format!("{}", constraint);
+                assert!(constraint.contains(GATE_SHOT_RUNNING_SUM_OUTPUT));
+                assert!(constraint.contains("Public hit assertion matches private witness"));
+            }
+            failure => panic!("Unexpected failure {:?}", failure),
+        }
+    }
+
+    #[test]
+    fn invalid_reveal() {
+        let circuit = blind_shot(3, 5, 1);
+        let commitment = circuit.try_public_inputs().unwrap()[3];
+        // open the hit commitment as a miss with the same nonce
+        let reveal = HitRevealCircuit::new(BinaryValue::from_u8(0), circuit.nonce);
+        let k = recommended_k(&reveal);
+        let public_inputs = vec![commitment, pallas::Base::zero()];
+        let prover = MockProver::run(k, &reveal, vec![public_inputs]).unwrap();
+        assert!(prover
+            .verify()
+            .unwrap_err()
+            .contains(&VerifyFailure::Permutation {
+                column: (Any::Instance, 0).into(),
+                location: FailureLocation::OutsideRegion { row: 0 }
+            }));
+    }
+}
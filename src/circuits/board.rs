@@ -1,16 +1,33 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use {
     crate::{
         chips::board::{BoardChip, BoardConfig},
-        utils::binary::BinaryValue,
+        circuits::layout::recommended_k,
+        utils::{
+            binary::BinaryValue,
+            board::BOARD_SIZE,
+            commitment::{BoardCommitment, CompressedCommitment},
+            error::BattleshipError,
+            ship::{validate_ship_commitments, STANDARD_FLEET},
+        },
     },
     halo2_proofs::{
-        arithmetic::FieldExt,
         circuit::{Layouter, SimpleFloorPlanner},
-        pasta::{group::Curve, pallas},
-        plonk::{Circuit, ConstraintSystem, Error},
+        pasta::{pallas, vesta},
+        plonk::{
+            create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ConstraintSystem, Error,
+            ProvingKey, SingleVerifier, VerifyingKey,
+        },
+        poly::commitment::Params,
+        transcript::{Blake2bRead, Blake2bWrite, Challenge255},
     },
+    rand::{rngs::OsRng, CryptoRng, RngCore},
 };
 
+// 2^K rows used by the board circuit
+pub const BOARD_CIRCUIT_K: u32 = 12;
+
 #[derive(Debug, Clone)]
 pub struct BoardCircuit {
     pub ship_commitments: [BinaryValue; 10],
@@ -32,7 +49,7 @@ impl Circuit<pallas::Base> for BoardCircuit {
     }
 
     fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
-        BoardChip::configure(meta)
+        BoardChip::configure(meta, &STANDARD_FLEET)
     }
 
     fn synthesize(
@@ -43,7 +60,7 @@ impl Circuit<pallas::Base> for BoardCircuit {
         let chip = BoardChip::new(config);
         chip.synthesize(
             layouter,
-            self.ship_commitments,
+            &self.ship_commitments,
             self.board,
             self.board_commitment_trapdoor,
         )
@@ -51,6 +68,9 @@ impl Circuit<pallas::Base> for BoardCircuit {
 }
 
 impl BoardCircuit {
+    // number of public inputs exported by the circuit: [commitment x, commitment y]
+    pub const EXPECTED_INSTANCE_LEN: usize = 2;
+
     /**
      * Construct a new board circuit given a commitment to ship placements
      * @dev handles all trace/ gadget construction given deck input
@@ -71,6 +91,204 @@ impl BoardCircuit {
             board_commitment_trapdoor,
         }
     }
+
+    /**
+     * Compute the public board commitment this circuit exports
+     * @dev panics on an identity commitment like BoardCommitment::commit(); provers use
+     *      try_commitment()
+     *
+     * @return - the (x, y) coordinates of the pedersen commitment to the board state
+     */
+    pub fn commitment(&self) -> BoardCommitment {
+        self.try_commitment().unwrap()
+    }
+
+    /**
+     * Compute the public board commitment this circuit exports, rejecting the identity point
+     *
+     * @return - the (x, y) coordinates of the pedersen commitment, or IdentityCommitment
+     */
+    pub fn try_commitment(&self) -> Result<BoardCommitment, BattleshipError> {
+        BoardCommitment::try_commit(&self.board, &self.board_commitment_trapdoor)
+    }
+
+    /**
+     * Compute the board commitment in its compressed 32 byte form for on-chain verification
+     *
+     * @return - the compressed pedersen commitment to the board state
+     */
+    pub fn compressed_commitment(&self) -> CompressedCommitment {
+        self.commitment().compress()
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [commitment x, commitment y] in the order they are constrained to the instance column
+     */
+    pub fn public_inputs(&self) -> [pallas::Base; 2] {
+        self.commitment().0
+    }
+
+    /**
+     * Count the board cells occupied by the witnessed board state
+     * @dev a standard fleet occupies STANDARD_FLEET.cells() = 17 cells; any other count (e.g. from
+     *      overlapping or missing ships) always fails the circuit, so check it before proving
+     *
+     * @return - number of flipped bits in the 100 bit board state
+     */
+    pub fn cell_count(&self) -> usize {
+        self.board.value[..BOARD_SIZE].count_ones()
+    }
+
+    /**
+     * Check that every ship commitment pair sets the ship's length in bits before proving
+     * @dev like cell_count(), a failure here always fails the circuit with a less helpful error;
+     *      prove_with_rng() runs it before creating a proof
+     *
+     * @return - Ok if each ship sets its length in bits, or the first ship that does not
+     */
+    pub fn validate_ship_commitments(&self) -> Result<(), BattleshipError> {
+        validate_ship_commitments(&STANDARD_FLEET, &self.ship_commitments)
+    }
+
+    /**
+     * Compute the smallest k such that the board circuit fits in 2^k rows
+     * @dev lays out the keygen circuit with the mock prover, so the result follows any change to the
+     *      placement, bits2num, transpose or commitment regions without a hand maintained row count
+     *
+     * @return - the minimal k to pass to Params::new() for any BoardCircuit
+     */
+    pub fn min_k() -> u32 {
+        recommended_k(&BoardCircuit::new(
+            [BinaryValue::empty(); 10],
+            BinaryValue::empty(),
+            pallas::Scalar::zero(),
+        ))
+    }
+
+    /**
+     * Generate the proving key for the board circuit
+     * @dev the verifying key can be taken from the proving key with pk.get_vk()
+     *
+     * @param params - polynomial commitment parameters with k = BOARD_CIRCUIT_K
+     * @return - the proving key for any BoardCircuit
+     */
+    pub fn keygen(params: &Params<vesta::Affine>) -> Result<ProvingKey<vesta::Affine>, Error> {
+        let empty_circuit = BoardCircuit::new(
+            [BinaryValue::empty(); 10],
+            BinaryValue::empty(),
+            pallas::Scalar::zero(),
+        );
+        let vk = keygen_vk(params, &empty_circuit)?;
+        keygen_pk(params, vk, &empty_circuit)
+    }
+
+    /**
+     * Prove knowledge of a valid board opening for the commitment returned by commitment()
+     *
+     * @param params - polynomial commitment parameters with k = BOARD_CIRCUIT_K
+     * @param pk - proving key generated by keygen()
+     * @return - the serialized proof
+     */
+    pub fn prove(
+        &self,
+        params: &Params<vesta::Affine>,
+        pk: &ProvingKey<vesta::Affine>,
+    ) -> Result<Vec<u8>, Error> {
+        self.prove_with_rng(params, pk, OsRng)
+    }
+
+    /**
+     * Prove a valid board opening drawing the proof's blinding factors from a given rng
+     * @dev the same rng state always yields the same proof bytes; only seed it for tests
+     *
+     * @param params - polynomial commitment parameters with k = BOARD_CIRCUIT_K
+     * @param pk - proving key generated by keygen()
+     * @param rng - source of the proof's blinding factors
+     * @return - the serialized proof
+     */
+    pub fn prove_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        params: &Params<vesta::Affine>,
+        pk: &ProvingKey<vesta::Affine>,
+        rng: R,
+    ) -> Result<Vec<u8>, Error> {
+        self.validate_ship_commitments()?;
+        let commitment = self.try_commitment()?;
+        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+        create_proof(
+            params,
+            pk,
+            &[self.clone()],
+            &[&[&commitment.0]],
+            rng,
+            &mut transcript,
+        )?;
+        Ok(transcript.finalize())
+    }
+
+    /**
+     * Prove many board circuits in parallel on the rayon thread pool
+     * @dev params and pk are shared by every proof; proofs are returned in the order of circuits
+     *
+     * @param circuits - board circuits to prove, one per player
+     * @param params - polynomial commitment parameters with k = BOARD_CIRCUIT_K
+     * @param pk - proving key generated by keygen()
+     * @return - the serialized proof for each circuit, or the first error encountered
+     */
+    #[cfg(feature = "parallel")]
+    pub fn prove_batch(
+        circuits: &[BoardCircuit],
+        params: &Params<vesta::Affine>,
+        pk: &ProvingKey<vesta::Affine>,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        circuits
+            .par_iter()
+            .map(|circuit| circuit.prove(params, pk))
+            .collect()
+    }
+
+    /**
+     * Verify a proof that a valid board was committed to
+     *
+     * @param params - polynomial commitment parameters with k = BOARD_CIRCUIT_K
+     * @param vk - verifying key for the board circuit
+     * @param commitment - the public (x, y) board commitment
+     * @param proof - the serialized proof generated by prove()
+     * @return - Ok if the proof is valid for the commitment
+     */
+    pub fn verify(
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+        commitment: &BoardCommitment,
+        proof: &[u8],
+    ) -> Result<(), Error> {
+        let strategy = SingleVerifier::new(params);
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof);
+        verify_proof(params, vk, strategy, &[&[&commitment.0]], &mut transcript)
+    }
+
+    /**
+     * Verify a proof that a valid board was committed to against a compressed commitment
+     * @dev the circuit constrains both coordinates, so the commitment is decompressed before verifying
+     *
+     * @param params - polynomial commitment parameters with k = BOARD_CIRCUIT_K
+     * @param vk - verifying key for the board circuit
+     * @param commitment - the compressed board commitment
+     * @param proof - the serialized proof generated by prove()
+     * @return - Ok if the proof is valid for the commitment, or Error::Synthesis if the commitment
+     *           does not decompress
+     */
+    pub fn verify_compressed(
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+        commitment: &CompressedCommitment,
+        proof: &[u8],
+    ) -> Result<(), Error> {
+        let commitment = commitment.decompress()?;
+        BoardCircuit::verify(params, vk, &commitment, proof)
+    }
 }
 
 #[cfg(test)]
@@ -78,14 +296,18 @@ mod test {
 
     use {
         super::*,
+        crate::chips::gate_names::{
+            GATE_ORIENTATION_ZERO, GATE_RUNNING_SUM_CONSTRAINTS, GATE_TRANSPOSE_ROW,
+        },
         crate::utils::{
             board::Board,
             deck::Deck,
+            fixtures::{pattern_1, pattern_2, PATTERN_1},
             pedersen::pedersen_commit,
-            ship::{WitnessOption, DEFAULT_WITNESS_OPTIONS},
+            ship::{FleetConfig, WitnessOption, DEFAULT_WITNESS_OPTIONS},
         },
         halo2_proofs::{
-            arithmetic::{CurveAffine, Field},
+            arithmetic::{CurveAffine, Field, FieldExt},
             dev::{FailureLocation, MockProver, VerifyFailure},
             pasta::{group::Curve, pallas, vesta},
             plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Any, SingleVerifier},
@@ -98,17 +320,11 @@ mod test {
     #[test]
     fn valid_0() {
         // construct battleship board pattern #1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = board.state(DEFAULT_WITNESS_OPTIONS).to_fp();
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -122,6 +338,8 @@ mod test {
             board.state(DEFAULT_WITNESS_OPTIONS),
             trapdoor,
         );
+        // the circuit computes the same public values
+        assert_eq!(circuit.public_inputs().to_vec(), commitment);
         // prove board pattern 1 is a valid configuration, and constrain the output of the board commitment
         let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
         // expect success
@@ -131,17 +349,11 @@ mod test {
     #[test]
     fn valid_1() {
         // construct battleship board pattern #2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -177,7 +389,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -200,7 +412,7 @@ mod test {
                 // expect 5 bits, counts 0 bits
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (15, "running sum constraints").into(),
+                        (15, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                         0,
                         "Placed ship of correct length",
                     )
@@ -214,7 +426,7 @@ mod test {
                 // expects one full (true, true, true, true, true) 5-bit window, counts none
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (15, "running sum constraints").into(),
+                        (15, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                         1,
                         "One full bit window",
                     )
@@ -229,16 +441,218 @@ mod test {
         );
     }
 
+    // (gate index, region index) of the running sum constraints of each ship's placement chip
+    // @dev placement chips are configured and laid out in fleet order: carrier, battleship, cruiser,
+    //      submarine, destroyer; each chip adds 5 gates and 3 regions
+    const PLACEMENT_GATES: [(usize, usize); 5] = [(15, 13), (20, 16), (25, 19), (30, 22), (35, 25)];
+
+    /**
+     * Build the running sum failures reported by a single ship's placement chip
+     *
+     * @param ship - index of the ship in the fleet
+     * @param bits - number of placement bits counted
+     * @param windows - number of full bit windows counted
+     * @param length - length of the ship
+     * @return - the failures expected from MockProver::verify()
+     */
+    fn placement_failures(ship: usize, bits: u64, windows: u64, length: u64) -> Vec<VerifyFailure> {
+        // MockProver prints 0 and 1 in decimal and every other small value in hex
+        let format = |value: u64| match value {
+            0 | 1 => value.to_string(),
+            _ => format!("{:#x}", value),
+        };
+        let (gate, region) = PLACEMENT_GATES[ship];
+        let failure = |index: usize, name: &'static str, column: usize, value: u64| {
+            VerifyFailure::ConstraintNotSatisfied {
+                constraint: ((gate, GATE_RUNNING_SUM_CONSTRAINTS).into(), index, name).into(),
+                location: FailureLocation::InRegion {
+                    region: (region, "constrain running sum output").into(),
+                    offset: 0,
+                },
+                cell_values: vec![(((Any::Advice, column).into(), 0).into(), format(value))],
+            }
+        };
+        let mut failures = Vec::new();
+        if bits != length {
+            failures.push(failure(0, "Placed ship of correct length", 1, bits));
+        }
+        if windows != 1 {
+            failures.push(failure(1, "One full bit window", 2, windows));
+        }
+        failures
+    }
+
+    /**
+     * Prove board pattern 1 with one ship removed, oversized and undersized
+     * @dev each case must fail only the running sum gate of that ship's placement chip
+     *
+     * @param ship - index of the ship in the fleet
+     */
+    fn assert_single_ship_failures(ship: usize) {
+        let length = STANDARD_FLEET.ships[ship] as u64;
+        let prove = |board: Board, options: [WitnessOption; 5]| {
+            let circuit = BoardCircuit::new(
+                board.witness(options),
+                board.state(options),
+                pallas::Scalar::random(&mut OsRng),
+            );
+            let commitment = circuit.commitment().0.to_vec();
+            MockProver::run(12, &circuit, vec![commitment])
+                .unwrap()
+                .verify()
+        };
+        // ship left unplaced: no bits and no full window
+        let mut deck = PATTERN_1;
+        deck[ship] = None;
+        assert_eq!(
+            prove(Board::from(&Deck::from(deck)), DEFAULT_WITNESS_OPTIONS),
+            Err(placement_failures(ship, 0, 0, length))
+        );
+        // pattern 1 leaves the cell after each ship empty and in the same row, so an extra bit
+        // completes a second full window without colliding with another ship
+        let mut options = DEFAULT_WITNESS_OPTIONS;
+        options[ship] = WitnessOption::Oversized;
+        assert_eq!(
+            prove(pattern_1(), options),
+            Err(placement_failures(ship, length + 1, 2, length))
+        );
+        // ship missing its last bit: one bit short and no full window
+        options[ship] = WitnessOption::Undersized;
+        assert_eq!(
+            prove(pattern_1(), options),
+            Err(placement_failures(ship, length - 1, 0, length))
+        );
+    }
+
+    #[test]
+    fn invalid_single_carrier() {
+        assert_single_ship_failures(0);
+    }
+
+    #[test]
+    fn invalid_single_battleship() {
+        assert_single_ship_failures(1);
+    }
+
+    #[test]
+    fn invalid_single_cruiser() {
+        assert_single_ship_failures(2);
+    }
+
+    #[test]
+    fn invalid_single_submarine() {
+        assert_single_ship_failures(3);
+    }
+
+    #[test]
+    fn invalid_single_destroyer() {
+        assert_single_ship_failures(4);
+    }
+
+    #[test]
+    fn cell_count() {
+        let count = |board: Board| {
+            BoardCircuit::new(
+                board.witness(DEFAULT_WITNESS_OPTIONS),
+                board.state(DEFAULT_WITNESS_OPTIONS),
+                pallas::Scalar::random(&mut OsRng),
+            )
+            .cell_count()
+        };
+        // a full standard fleet occupies 17 cells
+        assert_eq!(count(pattern_1()), STANDARD_FLEET.cells());
+        assert_eq!(count(pattern_2()), 17);
+        // a missing carrier leaves 12 cells
+        let mut deck = PATTERN_1;
+        deck[0] = None;
+        assert_eq!(count(Board::from(&Deck::from(deck))), 12);
+        // the carrier crossing the battleship at (5, 4) shares one cell
+        deck[0] = Some((5, 2, true));
+        assert_eq!(count(Board::from(&Deck::from(deck))), 16);
+    }
+
+    #[test]
+    fn ship_commitment_lengths() {
+        let validate = |board: Board, options: [WitnessOption; 5]| {
+            BoardCircuit::new(
+                board.witness(options),
+                board.state(options),
+                pallas::Scalar::random(&mut OsRng),
+            )
+            .validate_ship_commitments()
+        };
+        assert_eq!(validate(pattern_1(), DEFAULT_WITNESS_OPTIONS), Ok(()));
+        // an oversized battleship sets 5 bits
+        let mut options = DEFAULT_WITNESS_OPTIONS;
+        options[1] = WitnessOption::Oversized;
+        assert_eq!(
+            validate(pattern_1(), options),
+            Err(BattleshipError::ShipLengthMismatch {
+                ship: 1,
+                expected: 4,
+                found: 5
+            })
+        );
+        // a missing carrier sets no bits
+        let mut deck = PATTERN_1;
+        deck[0] = None;
+        assert_eq!(
+            validate(Board::from(&Deck::from(deck)), DEFAULT_WITNESS_OPTIONS),
+            Err(BattleshipError::ShipLengthMismatch {
+                ship: 0,
+                expected: 5,
+                found: 0
+            })
+        );
+        // the count spans both orientations, so a dual placement is left to the circuit
+        options[1] = WitnessOption::DualPlacement;
+        assert!(validate(pattern_1(), options).is_ok());
+        // a commitment pair for every ship of the fleet is required
+        assert_eq!(
+            validate_ship_commitments(&STANDARD_FLEET, &pattern_1().witness(options)[..8]),
+            Err(BattleshipError::LengthMismatch {
+                expected: 10,
+                found: 8
+            })
+        );
+    }
+
+    #[test]
+    fn prove_rejects_ship_length_mismatch() {
+        // an oversized battleship is caught before the prover synthesizes the circuit
+        let mut options = DEFAULT_WITNESS_OPTIONS;
+        options[1] = WitnessOption::Oversized;
+        let board = pattern_1();
+        let circuit = BoardCircuit::new(
+            board.witness(options),
+            board.state(options),
+            pallas::Scalar::random(&mut OsRng),
+        );
+        let params: Params<vesta::Affine> = Params::new(BoardCircuit::min_k());
+        let pk = BoardCircuit::keygen(&params).unwrap();
+        assert!(matches!(circuit.prove(&params, &pk), Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn insufficient_rows_error() {
+        let board = pattern_1();
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+        );
+        // layout errors (e.g. enabling a selector past the usable rows) surface from synthesis
+        let result = MockProver::run(4, &circuit, vec![vec![]]);
+        assert!(matches!(
+            result,
+            Err(Error::NotEnoughRowsAvailable { current_k: 4 })
+        ));
+    }
+
     #[test]
     fn invalid_placement_dual() {
         // construct battleship board pattern #1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // modify the shot_commitment for H5, V5 by setting horizontal as expected and vertical = 1 (not allowed)
         let witness_options = [
             WitnessOption::DualPlacement,
@@ -251,7 +665,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(witness_options).lower_u128());
+        let message = board.commitment_preimage(witness_options);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -268,7 +682,7 @@ mod test {
             prover.verify(),
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 constraint: (
-                    (56, "Commitment orientation H OR V == 0 constraint").into(),
+                    (56, GATE_ORIENTATION_ZERO).into(),
                     0,
                     "Aircraft Carrier H OR V == 0",
                 )
@@ -291,16 +705,54 @@ mod test {
         );
     }
 
+    #[test]
+    fn invalid_orientation_field_boundary() {
+        // a product of nonzero field elements is never zero, so values near the modulus cannot
+        // satisfy the orientation gate with both the H and V commitment set
+        let half = pallas::Base::from(2).invert().unwrap();
+        let cases = [
+            (-pallas::Base::one(), -pallas::Base::one()),
+            (-pallas::Base::one(), pallas::Base::one()),
+            // (p + 1) / 2 * 2 wraps around the modulus to 1
+            (half, pallas::Base::from(2)),
+            (pallas::Base::from_u128(1 << 99), pallas::Base::from_u128(1 << 99)),
+        ];
+        let board = pattern_1();
+        for (horizontal, vertical) in cases {
+            assert_ne!(horizontal * vertical, pallas::Base::zero());
+            let mut ship_commitments = board.witness(DEFAULT_WITNESS_OPTIONS);
+            ship_commitments[0] = BinaryValue::from_fp(horizontal);
+            ship_commitments[1] = BinaryValue::from_fp(vertical);
+            let circuit = BoardCircuit::new(
+                ship_commitments,
+                board.state(DEFAULT_WITNESS_OPTIONS),
+                pallas::Scalar::random(&mut OsRng),
+            );
+            let public_inputs = circuit.public_inputs().to_vec();
+            let prover = MockProver::run(12, &circuit, vec![public_inputs]).unwrap();
+            // the malformed commitments also fail their running sums; only the carrier fails the
+            // orientation gate
+            let orientation_failures = prover
+                .verify()
+                .unwrap_err()
+                .into_iter()
+                .filter_map(|failure| match failure {
+                    VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                        Some(format!("{}", constraint))
+                    }
+                    _ => None,
+                })
+                .filter(|constraint| constraint.contains(GATE_ORIENTATION_ZERO))
+                .collect::<Vec<_>>();
+            assert_eq!(orientation_failures.len(), 1, "{:?}", orientation_failures);
+            assert!(orientation_failures[0].contains("Aircraft Carrier H OR V == 0"));
+        }
+    }
+
     #[test]
     fn invalid_placement_nonconsecutive() {
         // construct battleship board pattern #1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // modify the shot_commitment for H5, V5 by setting horizontal as expected and vertical = 1 (not allowed)
         let witness_options = [
             WitnessOption::Nonconsecutive,
@@ -313,7 +765,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(witness_options).lower_u128());
+        let message = board.commitment_preimage(witness_options);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -330,7 +782,7 @@ mod test {
             prover.verify(),
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 constraint: (
-                    (15, "running sum constraints").into(),
+                    (15, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                     1,
                     "One full bit window",
                 )
@@ -347,13 +799,7 @@ mod test {
     #[test]
     fn invalid_placement_extra_bit() {
         // construct battleship board pattern #1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // modify the shot_commitment for H5, V5 by setting horizontal as expected and vertical = 1 (not allowed)
         let witness_options = [
             WitnessOption::ExtraBit,
@@ -366,7 +812,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(witness_options).lower_u128());
+        let message = board.commitment_preimage(witness_options);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -383,7 +829,7 @@ mod test {
             prover.verify(),
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 constraint: (
-                    (15, "running sum constraints").into(),
+                    (15, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                     0,
                     "Placed ship of correct length",
                 )
@@ -400,13 +846,7 @@ mod test {
     #[test]
     fn invalid_placement_oversized() {
         // construct battleship board pattern #1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // modify the shot_commitment for H4 by adding an extra bit making ship length 5 when it should be 4
         let witness_options = [
             WitnessOption::Default,
@@ -419,7 +859,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(witness_options).lower_u128());
+        let message = board.commitment_preimage(witness_options);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -438,7 +878,7 @@ mod test {
                 // counted 5 bits for battleship placement chip expecting 4 bits
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (20, "running sum constraints").into(),
+                        (20, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                         0,
                         "Placed ship of correct length",
                     )
@@ -453,7 +893,7 @@ mod test {
                 // full window at 54, 64, 74, 84 expected, full window at 64, 74, 84, 94 not expected
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (20, "running sum constraints").into(),
+                        (20, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                         1,
                         "One full bit window",
                     )
@@ -471,13 +911,7 @@ mod test {
     #[test]
     fn invalid_placement_undersized() {
         // construct battleship board pattern #2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // modify the shot_commitment for V5 by removing last bit making ship length 1 when it should be 2
         let witness_options = [
             WitnessOption::Default,
@@ -490,7 +924,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(witness_options).lower_u128());
+        let message = board.commitment_preimage(witness_options);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -509,7 +943,7 @@ mod test {
                 // counted 1 bits for destroyer placement, expecting 2
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (35, "running sum constraints").into(),
+                        (35, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                         0,
                         "Placed ship of correct length",
                     )
@@ -523,7 +957,7 @@ mod test {
                 // counted 0 full bit windows, expecting 1
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (35, "running sum constraints").into(),
+                        (35, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                         1,
                         "One full bit window",
                     )
@@ -539,21 +973,22 @@ mod test {
     }
 
     #[test]
-    fn invalid_horizontal_row_overflow() {
-        // construct battleship board pattern #2 with modification
-        // set cruiser ship to be placed at (9, 0) which is out of bounds by 2 bits
-        // 9 valid, wraps around to 10, 11
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((9, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+    fn invalid_placement_shifted_window() {
+        // construct battleship board pattern #1
+        let board = pattern_1();
+        // modify the shot_commitment for H3a by shifting the cruiser from 10, 11, 12 to 18, 19, 20 (wraps a row)
+        let witness_options = [
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::ShiftedWindow,
+            WitnessOption::Default,
+            WitnessOption::Default,
+        ];
+        let ship_commitments = board.witness(witness_options);
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(witness_options);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -562,38 +997,390 @@ mod test {
             vec![x, y]
         };
         // construct Board circuit
-        let circuit = BoardCircuit::new(
-            board.witness(DEFAULT_WITNESS_OPTIONS),
-            board.state(DEFAULT_WITNESS_OPTIONS),
-            trapdoor,
-        );
-        // prove that attempting to place ships that overflow 10 units horizontally fails verification
+        let circuit = BoardCircuit::new(ship_commitments, board.state(witness_options), trapdoor);
+        // prove that a ship of correct length crossing a row boundary fails verification
         let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
         // expect failure
         assert_eq!(
             prover.verify(),
-            Err(vec![VerifyFailure::ConstraintNotSatisfied {
-                constraint: (
-                    (25, "running sum constraints").into(),
-                    1,
-                    "One full bit window",
-                )
-                    .into(),
-                location: FailureLocation::InRegion {
-                    region: (19, "constrain running sum output").into(),
-                    offset: 0,
-                },
-                cell_values: vec![(((Any::Advice, 2).into(), 0).into(), String::from("0"),),]
-            }])
+            Err(vec![
+                // no full bit window found for cruiser placed at 18, 19, 20 (wraps from row 1 to row 2)
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (25, GATE_RUNNING_SUM_CONSTRAINTS).into(),
+                        1,
+                        "One full bit window",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (19, "constrain running sum output").into(),
+                        offset: 0,
+                    },
+                    cell_values: vec![(((Any::Advice, 2).into(), 0).into(), String::from("0"),),]
+                }
+            ])
         );
     }
 
     #[test]
-    fn invalid_vertical_row_overflow() {
-        // construct battleship board pattern #1 with modification
-        // set carrier ship to be placed vertically at (3, 6) which is out of bounds by 1 bit
-        // 63, 73, 83, 93 valid; wraps around to 4
-        let board = Board::from(&Deck::from([
+    fn invalid_placement_zero_placement() {
+        // construct battleship board pattern #1
+        let board = pattern_1();
+        // modify the shot_commitment for H2, V2 to both be empty while the board state still claims 16, 17
+        let witness_options = [
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::ZeroPlacement,
+        ];
+        let ship_commitments = board.witness(witness_options);
+        // sample a random trapdoor value for commitment
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // marshall the board state into a pallas base field element
+        let message = board.commitment_preimage(witness_options);
+        // commit to the board state
+        let commitment = {
+            let commitment = pedersen_commit(&message, &trapdoor).to_affine();
+            let x = commitment.clone().coordinates().unwrap().x().to_owned();
+            let y = commitment.clone().coordinates().unwrap().y().to_owned();
+            vec![x, y]
+        };
+        // construct Board circuit
+        let circuit = BoardCircuit::new(ship_commitments, board.state(witness_options), trapdoor);
+        // prove that claiming cells on the board without a ship placement fails verification
+        let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
+        // expect failure
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                // counted 0 bits for destroyer placement, expecting 2
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (35, GATE_RUNNING_SUM_CONSTRAINTS).into(),
+                        0,
+                        "Placed ship of correct length",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (25, "constrain running sum output").into(),
+                        offset: 0,
+                    },
+                    cell_values: vec![(((Any::Advice, 1).into(), 0).into(), String::from("0"),),]
+                },
+                // counted 0 full bit windows, expecting 1
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (35, GATE_RUNNING_SUM_CONSTRAINTS).into(),
+                        1,
+                        "One full bit window",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (25, "constrain running sum output").into(),
+                        offset: 0,
+                    },
+                    cell_values: vec![(((Any::Advice, 2).into(), 0).into(), String::from("0"),),]
+                },
+                // board state claims bits 16, 17 that are not set in any ship commitment
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (36, GATE_TRANSPOSE_ROW).into(),
+                        0,
+                        "Constrain trace value integrity",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (26, "Transpose ship commitments").into(),
+                        offset: 16,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 1).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 3).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 4).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 5).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 6).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 7).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 8).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 9).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 10).into(), 0).into(), String::from("1"),),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (36, GATE_TRANSPOSE_ROW).into(),
+                        0,
+                        "Constrain trace value integrity",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (26, "Transpose ship commitments").into(),
+                        offset: 17,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 1).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 3).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 4).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 5).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 6).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 7).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 8).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 9).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 10).into(), 0).into(), String::from("1"),),
+                    ]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_placement_high_bits() {
+        // construct battleship board pattern #1
+        let board = pattern_1();
+        // modify the shot_commitment for H4 by setting bit 100 above the board
+        let witness_options = [
+            WitnessOption::Default,
+            WitnessOption::HighBits,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+        ];
+        let ship_commitments = board.witness(witness_options);
+        // sample a random trapdoor value for commitment
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // marshall the board state into a pallas base field element
+        let message = board.commitment_preimage(witness_options);
+        // commit to the board state
+        let commitment = {
+            let commitment = pedersen_commit(&message, &trapdoor).to_affine();
+            let x = commitment.clone().coordinates().unwrap().x().to_owned();
+            let y = commitment.clone().coordinates().unwrap().y().to_owned();
+            vec![x, y]
+        };
+        // construct Board circuit
+        let circuit = BoardCircuit::new(ship_commitments, board.state(witness_options), trapdoor);
+        // prove that setting bits outside of the board fails verification
+        let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
+        // expect failure
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                // bit 100 of H4 is not decomposed, so the recomposed 100 bits do not match the witnessed commitment
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 1).into(),
+                    location: FailureLocation::InRegion {
+                        region: (3, "num2bits").into(),
+                        offset: 100
+                    }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 2).into(),
+                    location: FailureLocation::InRegion {
+                        region: (0, "load ship placements").into(),
+                        offset: 0
+                    }
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_placement_swapped_orientation() {
+        // construct battleship board pattern #1
+        let board = pattern_1();
+        // modify the shot_commitment for H3a, V3a by witnessing the horizontal cruiser in V3a
+        let witness_options = [
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::SwappedOrientation,
+            WitnessOption::Default,
+            WitnessOption::Default,
+        ];
+        let ship_commitments = board.witness(witness_options);
+        // sample a random trapdoor value for commitment
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // marshall the board state into a pallas base field element
+        let message = board.commitment_preimage(witness_options);
+        // commit to the board state
+        let commitment = {
+            let commitment = pedersen_commit(&message, &trapdoor).to_affine();
+            let x = commitment.clone().coordinates().unwrap().x().to_owned();
+            let y = commitment.clone().coordinates().unwrap().y().to_owned();
+            vec![x, y]
+        };
+        // construct Board circuit
+        let circuit = BoardCircuit::new(ship_commitments, board.state(witness_options), trapdoor);
+        // prove that witnessing a placement in the wrong orientation fails verification
+        let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
+        // expect failure
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                // cruiser witnessed in V3a transposes to 1, 11, 21 instead of 10, 11, 12 claimed by the board state
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (36, GATE_TRANSPOSE_ROW).into(),
+                        0,
+                        "Constrain trace value integrity",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (26, "Transpose ship commitments").into(),
+                        offset: 1,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 1).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 3).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 4).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 5).into(), 0).into(), String::from("1"),),
+                        (((Any::Advice, 6).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 7).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 8).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 9).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 10).into(), 0).into(), String::from("0"),),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (36, GATE_TRANSPOSE_ROW).into(),
+                        0,
+                        "Constrain trace value integrity",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (26, "Transpose ship commitments").into(),
+                        offset: 10,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 1).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 3).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 4).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 5).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 6).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 7).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 8).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 9).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 10).into(), 0).into(), String::from("1"),),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (36, GATE_TRANSPOSE_ROW).into(),
+                        0,
+                        "Constrain trace value integrity",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (26, "Transpose ship commitments").into(),
+                        offset: 12,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 1).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 3).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 4).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 5).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 6).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 7).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 8).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 9).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 10).into(), 0).into(), String::from("1"),),
+                    ]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (36, GATE_TRANSPOSE_ROW).into(),
+                        0,
+                        "Constrain trace value integrity",
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (26, "Transpose ship commitments").into(),
+                        offset: 21,
+                    },
+                    cell_values: vec![
+                        (((Any::Advice, 0).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 1).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 2).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 3).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 4).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 5).into(), 0).into(), String::from("1"),),
+                        (((Any::Advice, 6).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 7).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 8).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 9).into(), 0).into(), String::from("0"),),
+                        (((Any::Advice, 10).into(), 0).into(), String::from("0"),),
+                    ]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_horizontal_row_overflow() {
+        // construct battleship board pattern #2 with modification
+        // set cruiser ship to be placed at (9, 0) which is out of bounds by 2 bits
+        // 9 valid, wraps around to 10, 11
+        let board = Board::from(&Deck::from([
+            Some((3, 4, false)),
+            Some((9, 6, true)),
+            Some((9, 0, false)),
+            Some((0, 6, false)),
+            Some((6, 1, true)),
+        ]));
+        // sample a random trapdoor value for commitment
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // marshall the board state into a pallas base field element
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
+        // commit to the board state
+        let commitment = {
+            let commitment = pedersen_commit(&message, &trapdoor).to_affine();
+            let x = commitment.clone().coordinates().unwrap().x().to_owned();
+            let y = commitment.clone().coordinates().unwrap().y().to_owned();
+            vec![x, y]
+        };
+        // construct Board circuit
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            trapdoor,
+        );
+        // prove that attempting to place ships that overflow 10 units horizontally fails verification
+        let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
+        // expect failure
+        assert_eq!(
+            prover.verify(),
+            Err(vec![VerifyFailure::ConstraintNotSatisfied {
+                constraint: (
+                    (25, GATE_RUNNING_SUM_CONSTRAINTS).into(),
+                    1,
+                    "One full bit window",
+                )
+                    .into(),
+                location: FailureLocation::InRegion {
+                    region: (19, "constrain running sum output").into(),
+                    offset: 0,
+                },
+                cell_values: vec![(((Any::Advice, 2).into(), 0).into(), String::from("0"),),]
+            }])
+        );
+    }
+
+    #[test]
+    fn invalid_vertical_row_overflow() {
+        // construct battleship board pattern #1 with modification
+        // set carrier ship to be placed vertically at (3, 6) which is out of bounds by 1 bit
+        // 63, 73, 83, 93 valid; wraps around to 4
+        let board = Board::from(&Deck::from([
             Some((3, 6, true)),
             Some((5, 4, false)),
             Some((0, 1, false)),
@@ -603,7 +1390,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -624,7 +1411,7 @@ mod test {
             prover.verify(),
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 constraint: (
-                    (15, "running sum constraints").into(),
+                    (15, GATE_RUNNING_SUM_CONSTRAINTS).into(),
                     1,
                     "One full bit window",
                 )
@@ -653,7 +1440,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -679,7 +1466,7 @@ mod test {
                 //      fails when expects sum = 2 but gets sum = 1
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (36, "transpose row constraint").into(),
+                        (36, GATE_TRANSPOSE_ROW).into(),
                         0,
                         "Constrain trace value integrity",
                     )
@@ -705,7 +1492,7 @@ mod test {
                 // fail constraint: sum of all bits in commitment row != 0 or 1
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (36, "transpose row constraint").into(),
+                        (36, GATE_TRANSPOSE_ROW).into(),
                         1,
                         "Constrain transposition of bit",
                     )
@@ -749,7 +1536,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -775,7 +1562,7 @@ mod test {
                 //      fails when expects sum = 2 but gets sum = 1
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (36, "transpose row constraint").into(),
+                        (36, GATE_TRANSPOSE_ROW).into(),
                         0,
                         "Constrain trace value integrity",
                     )
@@ -801,7 +1588,7 @@ mod test {
                 // fail constraint: sum of all bits in commitment row != 0 or 1
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (36, "transpose row constraint").into(),
+                        (36, GATE_TRANSPOSE_ROW).into(),
                         1,
                         "Constrain transposition of bit",
                     )
@@ -831,17 +1618,11 @@ mod test {
     fn invalid_board_commitment() {
         // prove the circuit will throw an error if the board commitment advice is not equal to the computed commitment
         // construct battleship board pattern #2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state and add one to the x coordinate to invalidate commitment
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -876,11 +1657,50 @@ mod test {
         );
     }
 
+    // 4 ship fleet without a carrier
+    const KIDS_FLEET: FleetConfig = FleetConfig {
+        ships: &[4, 3, 3, 2],
+    };
+
+    // board circuit configured for KIDS_FLEET
+    #[derive(Debug, Clone)]
+    struct KidsBoardCircuit {
+        ship_commitments: [BinaryValue; 8],
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+    }
+
+    impl Circuit<pallas::Base> for KidsBoardCircuit {
+        type Config = BoardConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            BoardChip::configure(meta, &KIDS_FLEET)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            BoardChip::new(config).synthesize(
+                layouter,
+                &self.ship_commitments,
+                self.board,
+                self.board_commitment_trapdoor,
+            )
+        }
+    }
+
     #[test]
-    fn production() {
-        // construct valid battleship board pattern 1
+    fn valid_kids_fleet() {
+        // construct battleship board pattern #1 without the carrier
         let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
+            None,
             Some((5, 4, false)),
             Some((0, 1, false)),
             Some((0, 5, true)),
@@ -888,8 +1708,175 @@ mod test {
         ]));
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // commit to the board state
+        let commitment = {
+            let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
+            let commitment = pedersen_commit(&message, &trapdoor).to_affine();
+            let x = commitment.clone().coordinates().unwrap().x().to_owned();
+            let y = commitment.clone().coordinates().unwrap().y().to_owned();
+            vec![x, y]
+        };
+        // drop the empty carrier commitments from the witness
+        let circuit = KidsBoardCircuit {
+            ship_commitments: board.witness(DEFAULT_WITNESS_OPTIONS)[2..]
+                .try_into()
+                .unwrap(),
+            board: board.state(DEFAULT_WITNESS_OPTIONS),
+            board_commitment_trapdoor: trapdoor,
+        };
+        // prove the 4 ship board is a valid configuration
+        let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
+        // expect success
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn fleet_validation() {
+        assert_eq!(STANDARD_FLEET.cells(), 17);
+        assert_eq!(STANDARD_FLEET.validate(), Ok(()));
+        assert_eq!(KIDS_FLEET.validate(), Ok(()));
+        // no ships
+        assert!(FleetConfig { ships: &[] }.validate().is_err());
+        // ship length without a placement chip
+        assert!(FleetConfig { ships: &[6, 2] }.validate().is_err());
+        // 21 carriers need 105 cells
+        assert!(FleetConfig { ships: &[5; 21] }.validate().is_err());
+    }
+
+    #[test]
+    fn is_valid_matches_circuit() {
+        use WitnessOption::*;
+        // apply a witness option to a single ship and leave the rest default
+        let only = |ship: usize, option: WitnessOption| {
+            let mut options = DEFAULT_WITNESS_OPTIONS;
+            options[ship] = option;
+            options
+        };
+        let cases = [
+            (pattern_1(), DEFAULT_WITNESS_OPTIONS),
+            (pattern_2(), DEFAULT_WITNESS_OPTIONS),
+            (pattern_1(), only(0, DualPlacement)),
+            (pattern_1(), only(0, Nonconsecutive)),
+            (pattern_1(), only(0, ExtraBit)),
+            (pattern_1(), only(1, Oversized)),
+            (pattern_2(), only(4, Undersized)),
+            (pattern_1(), only(2, ShiftedWindow)),
+            (pattern_1(), only(4, ZeroPlacement)),
+            (pattern_1(), only(1, HighBits)),
+            (pattern_1(), only(2, SwappedOrientation)),
+            (
+                Board::from(&Deck::from([
+                    None,
+                    Some((5, 4, false)),
+                    Some((0, 1, false)),
+                    Some((0, 5, true)),
+                    Some((6, 1, true)),
+                ])),
+                DEFAULT_WITNESS_OPTIONS,
+            ),
+            (
+                Board::from(&Deck::from([
+                    Some((3, 4, false)),
+                    Some((9, 6, true)),
+                    Some((9, 0, false)),
+                    Some((0, 6, false)),
+                    Some((6, 1, true)),
+                ])),
+                DEFAULT_WITNESS_OPTIONS,
+            ),
+            (
+                Board::from(&Deck::from([
+                    Some((3, 6, true)),
+                    Some((5, 4, false)),
+                    Some((0, 1, false)),
+                    Some((0, 5, true)),
+                    Some((6, 1, false)),
+                ])),
+                DEFAULT_WITNESS_OPTIONS,
+            ),
+            (
+                Board::from(&Deck::from([
+                    Some((3, 3, true)),
+                    Some((5, 4, false)),
+                    Some((4, 1, false)),
+                    Some((0, 5, true)),
+                    Some((6, 1, false)),
+                ])),
+                DEFAULT_WITNESS_OPTIONS,
+            ),
+            (
+                Board::from(&Deck::from([
+                    Some((3, 4, false)),
+                    Some((9, 6, true)),
+                    Some((0, 0, false)),
+                    Some((0, 6, false)),
+                    Some((6, 3, true)),
+                ])),
+                DEFAULT_WITNESS_OPTIONS,
+            ),
+        ];
+        for (i, (board, options)) in cases.into_iter().enumerate() {
+            let circuit = BoardCircuit::new(
+                board.witness(options),
+                board.state(options),
+                pallas::Scalar::random(&mut OsRng),
+            );
+            let commitment = circuit.commitment().0.to_vec();
+            let prover = MockProver::run(12, &circuit, vec![commitment]).unwrap();
+            // the oracle and the circuit must agree on every case
+            assert_eq!(
+                board.is_valid_witness(options).is_ok(),
+                prover.verify().is_ok(),
+                "case {}: {:?}",
+                i,
+                board.is_valid_witness(options)
+            );
+        }
+    }
+
+    #[test]
+    fn config_column_handles() {
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        let config = BoardCircuit::configure(&mut meta);
+        // every bit decomposition of the board shares the bits column
+        for num2bits in config.num2bits.iter() {
+            assert_eq!(num2bits.bits, config.bits_column());
+        }
+        assert_eq!(config.bits2num.bits, config.bits_column());
+        assert_eq!(config.instance_column(), config.instance);
+        assert_eq!(config.shared_fixed(), config.fixed[0]);
+        // one placement config per ship in the fleet
+        assert_eq!(config.placement_configs().len(), STANDARD_FLEET.ships.len());
+    }
+
+    #[test]
+    fn constraint_system_shape() {
+        // any change to these counts changes the board verifying key; update tests/golden alongside
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        BoardChip::configure(&mut meta, &STANDARD_FLEET);
+        // 10 commitment columns + transposed bits
+        assert_eq!(meta.num_advice_columns(), 11);
+        // 8 shared fixed columns + range check table + ecc fixed_z
+        assert_eq!(meta.num_fixed_columns(), 10);
+        assert_eq!(meta.num_instance_columns(), 1);
+        // orientation 1 + num2bits 10 + bits2num 1 + placement 5 x 5 + transpose 1 + ecc 17
+        // + range check 3
+        assert_eq!(meta.num_selectors(), 58);
+        // num2bits 10 + bits2num 1 + placement 5 x 5 + transpose 1 + ecc and range check 19
+        // + orientation 1
+        assert_eq!(meta.gates().len(), 57);
+        // 10 bit range check table
+        assert_eq!(meta.lookups().len(), 1);
+    }
+
+    #[test]
+    fn production() {
+        // construct valid battleship board pattern 1
+        let board = pattern_1();
+        // sample a random trapdoor value for commitment
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -904,7 +1891,7 @@ mod test {
             trapdoor,
         );
         // Initialize the polynomial commitment parameters
-        let params: Params<vesta::Affine> = Params::new(12);
+        let params: Params<vesta::Affine> = Params::new(BoardCircuit::min_k());
         // Initialize the proving key
         let vk = keygen_vk(&params, &circuit).expect("keygen_vk should not fail");
         let pk = keygen_pk(&params, vk, &circuit).expect("keygen_pk should not fail");
@@ -932,6 +1919,48 @@ mod test {
         .is_ok());
     }
 
+    #[test]
+    fn compressed_commitment() {
+        let board = pattern_1();
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+        );
+        let params: Params<vesta::Affine> = Params::new(BoardCircuit::min_k());
+        let pk = BoardCircuit::keygen(&params).unwrap();
+        let proof = circuit.prove(&params, &pk).unwrap();
+        // the proof verifies against the compressed commitment
+        let compressed = circuit.compressed_commitment();
+        assert_eq!(compressed.decompress(), Ok(circuit.commitment()));
+        let vk = pk.get_vk();
+        assert!(BoardCircuit::verify_compressed(&params, vk, &compressed, &proof).is_ok());
+        // the identity point does not decompress
+        let identity = CompressedCommitment([0; 32]);
+        assert!(matches!(
+            BoardCircuit::verify_compressed(&params, vk, &identity, &proof),
+            Err(Error::Synthesis)
+        ));
+    }
+
+    #[test]
+    fn identity_commitment_prove_errors() {
+        // the keygen circuit commits an empty board with a zero trapdoor to the identity point
+        let circuit = BoardCircuit::new(
+            [BinaryValue::empty(); 10],
+            BinaryValue::empty(),
+            pallas::Scalar::zero(),
+        );
+        assert_eq!(
+            circuit.try_commitment(),
+            Err(BattleshipError::IdentityCommitment)
+        );
+        // proving reports the identity commitment instead of panicking
+        let params: Params<vesta::Affine> = Params::new(BoardCircuit::min_k());
+        let pk = BoardCircuit::keygen(&params).unwrap();
+        assert!(matches!(circuit.prove(&params, &pk), Err(Error::Synthesis)));
+    }
+
     // #[test]
     // fn print_circuit() {
     //     use plotters::prelude::*;
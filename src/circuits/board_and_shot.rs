@@ -0,0 +1,241 @@
+use {
+    crate::{
+        chips::{
+            board::{BoardChip, BoardConfig, BoardInstructions},
+            columns::ColumnSet,
+            shot::{HitMode, ShotChip, ShotConfig},
+        },
+        utils::{
+            binary::BinaryValue, commitment::BoardCommitment, error::BattleshipError,
+            ship::STANDARD_FLEET,
+        },
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/**
+ * Storage for a proof of a valid board and the first shot its owner fires
+ * @dev both chips are configured over one ColumnSet; only the board chip loads the lookup table
+ *
+ * @param board - BoardChip config proving and committing to the board
+ * @param shot - ShotChip config constraining the fired shot to a single cell
+ */
+#[derive(Clone, Debug)]
+pub struct BoardAndShotConfig {
+    pub board: BoardConfig,
+    pub shot: ShotConfig,
+}
+
+/**
+ * Opening move circuit proving a valid board and the serialized first shot fired by its owner, so
+ * the board proof and first shot need one round trip instead of two
+ */
+#[derive(Debug, Clone)]
+pub struct BoardAndShotCircuit {
+    pub ship_commitments: [BinaryValue; 10],
+    pub board: BinaryValue,
+    pub board_commitment_trapdoor: pallas::Scalar,
+    pub shot: BinaryValue,
+}
+
+impl Circuit<pallas::Base> for BoardAndShotCircuit {
+    type Config = BoardAndShotConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        self.clone()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        let (columns, extra_advice) =
+            ColumnSet::configure(meta, BoardChip::extra_advice_columns(&STANDARD_FLEET));
+        let board =
+            BoardChip::configure_with_columns(meta, &STANDARD_FLEET, columns, &extra_advice);
+        let shot = ShotChip::configure_with_columns(meta, columns, HitMode::Boolean);
+        BoardAndShotConfig { board, shot }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let instance = config.board.instance;
+        let chip = BoardChip::new(config.board);
+        // constrain a valid board and keep its decomposition
+        let (board_state, board_bits) =
+            chip.synthesize_board_state(&mut layouter, &self.ship_commitments, self.board)?;
+        let commitment =
+            chip.commit_board(&mut layouter, &board_state, &self.board_commitment_trapdoor)?;
+        // constrain the fired shot to a single cell
+        let shot = ShotChip::new(config.shot).synthesize_fired_shot(
+            layouter.namespace(|| "fired shot"),
+            self.board,
+            &board_bits,
+            self.shot,
+        )?;
+        // export public values
+        layouter.constrain_instance(commitment[0].cell(), instance, 0)?;
+        layouter.constrain_instance(commitment[1].cell(), instance, 1)?;
+        layouter.constrain_instance(shot.cell(), instance, 2)
+    }
+}
+
+impl BoardAndShotCircuit {
+    /**
+     * Construct a new circuit proving a valid board and the first shot fired by its owner
+     *
+     * @param ship_commitments - assignments for each of the ships according to order in chips::board::commitment_label
+     * @param board - the resulting board state when all ship commitments are transposed onto one bitfield
+     * @param board_commitment_trapdoor - randomly sampled blinding factor for board commitment
+     * @param shot - x, y coordinates of the first shot serialized into a shot commitment
+     * @return - instantiated BoardAndShotCircuit object
+     */
+    pub fn new(
+        ship_commitments: [BinaryValue; 10],
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+    ) -> BoardAndShotCircuit {
+        BoardAndShotCircuit {
+            ship_commitments,
+            board,
+            board_commitment_trapdoor,
+            shot,
+        }
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [commitment x, commitment y, shot], or IdentityCommitment
+     */
+    pub fn try_public_inputs(&self) -> Result<[pallas::Base; 3], BattleshipError> {
+        let commitment = BoardCommitment::try_commit(&self.board, &self.board_commitment_trapdoor)?;
+        Ok([commitment.0[0], commitment.0[1], self.shot.to_fp()])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::gate_names::{GATE_ORIENTATION_ZERO, GATE_SHOT_RUNNING_SUM_OUTPUT},
+            circuits::layout::recommended_k,
+            utils::{
+                board::Board,
+                fixtures::pattern_1,
+                ship::{WitnessOption, DEFAULT_WITNESS_OPTIONS},
+                shot::serialize,
+            },
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            dev::{MockProver, VerifyFailure},
+        },
+        rand::rngs::OsRng,
+    };
+
+    /**
+     * Construct a circuit proving a board and the first shot fired by its owner
+     *
+     * @param board - the board to prove
+     * @param options - witness options for each ship, used to build malicious boards
+     * @param shot - the serialized first shot
+     * @return - the board and shot circuit
+     */
+    fn opening(
+        board: Board,
+        options: [WitnessOption; 5],
+        shot: BinaryValue,
+    ) -> BoardAndShotCircuit {
+        BoardAndShotCircuit::new(
+            board.witness(options),
+            board.state(options),
+            pallas::Scalar::random(&mut OsRng),
+            shot,
+        )
+    }
+
+    /**
+     * Assert a circuit fails only the named constraint
+     *
+     * @param circuit - the circuit to verify
+     * @param gate - name of the gate the failing constraint belongs to
+     * @param name - name of the failing constraint
+     */
+    fn assert_fails(circuit: &BoardAndShotCircuit, gate: &str, name: &str) {
+        let k = recommended_k(circuit);
+        let public_inputs = circuit.try_public_inputs().unwrap().to_vec();
+        let prover = MockProver::run(k, circuit, vec![public_inputs]).unwrap();
+        let failures = prover.verify().unwrap_err();
+        assert_eq!(failures.len(), 1, "{:?}", failures);
+        match &failures[0] {
+            VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                let constraint = format!("{}", constraint);
+                assert!(constraint.contains(gate));
+                assert!(constraint.contains(name));
+            }
+            failure => panic!("Unexpected failure {:?}", failure),
+        }
+    }
+
+    #[test]
+    fn valid_board_and_shot() {
+        // the fired shot is accepted whether or not it lands on the prover's own board
+        for (x, y) in [(3, 5), (0, 0)] {
+            let circuit = opening(
+                pattern_1(),
+                DEFAULT_WITNESS_OPTIONS,
+                serialize::<1>([x], [y]),
+            );
+            let k = recommended_k(&circuit);
+            let public_inputs = circuit.try_public_inputs().unwrap().to_vec();
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+        // the board and shot share columns instead of doubling them
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        BoardAndShotCircuit::configure(&mut meta);
+        assert_eq!(meta.num_advice_columns(), 11);
+        assert_eq!(meta.num_instance_columns(), 1);
+    }
+
+    #[test]
+    fn invalid_board() {
+        // the carrier is placed both horizontally and vertically
+        let options = [
+            WitnessOption::DualPlacement,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+        ];
+        let circuit = opening(pattern_1(), options, serialize::<1>([3], [5]));
+        assert_fails(
+            &circuit,
+            GATE_ORIENTATION_ZERO,
+            "Aircraft Carrier H OR V == 0",
+        );
+    }
+
+    #[test]
+    fn invalid_multi_bit_shot() {
+        // a shot at two cells at once
+        let circuit = opening(
+            pattern_1(),
+            DEFAULT_WITNESS_OPTIONS,
+            serialize::<2>([3, 0], [5, 0]),
+        );
+        assert_fails(
+            &circuit,
+            GATE_SHOT_RUNNING_SUM_OUTPUT,
+            "Shot only fires at one board cell",
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use {
+    crate::{
+        circuits::game::ProverContext,
+        utils::{binary::BinaryValue, commitment::BoardCommitment, error::BattleshipError},
+    },
+    halo2_proofs::{
+        pasta::{pallas, vesta},
+        plonk::{verify_proof, Error, SingleVerifier, VerifyingKey},
+        poly::commitment::Params,
+        transcript::{Blake2bRead, Challenge255},
+    },
+};
+
+// version of the proof bundle byte encoding
+pub const PROOF_BUNDLE_VERSION: u8 = 2;
+
+/**
+ * Hash a verifying key; every verifying key digest of the crate is derived from this one
+ * @dev hashes the pinned verifying key, which covers k, the constraint system (every gate, lookup and
+ *      permutation argument) and the fixed and permutation commitments; halo2_proofs 0.2 cannot
+ *      serialize verifying keys, and the pinned key is what halo2 itself hashes into the transcript
+ *
+ * @param vk - verifying key to hash
+ * @return - blake2b digest of the pinned verifying key
+ */
+pub fn pinned_vk_digest(vk: &VerifyingKey<vesta::Affine>) -> [u8; 64] {
+    let pinned = format!("{:?}", vk.pinned());
+    *blake2b_simd::blake2b(pinned.as_bytes()).as_array()
+}
+
+/**
+ * Stable identifier of a verifying key for registering accepted circuits on-chain
+ *
+ * @param vk - verifying key to fingerprint
+ * @return - first 32 bytes of pinned_vk_digest()
+ */
+pub fn vk_fingerprint(vk: &VerifyingKey<vesta::Affine>) -> [u8; 32] {
+    pinned_vk_digest(vk)[..32].try_into().unwrap()
+}
+
+/**
+ * Verify a proof against public inputs passed as bytes across an FFI or contract boundary
+ * @dev encodings at or above the field modulus are rejected rather than reduced, so every public
+ *      input has exactly one accepted encoding
+ *
+ * @param params - polynomial commitment parameters the proof was generated with
+ * @param proof - the serialized proof
+ * @param instance_bytes - 32 byte little endian encoding of each public input, in order
+ * @param vk - verifying key of the circuit the proof was generated for
+ * @return - whether the proof verifies, or which public input could not be decoded
+ */
+pub fn verify_from_bytes(
+    params: &Params<vesta::Affine>,
+    proof: &[u8],
+    instance_bytes: &[&[u8]],
+    vk: &VerifyingKey<vesta::Affine>,
+) -> Result<bool, BattleshipError> {
+    let instance = instance_bytes
+        .iter()
+        .enumerate()
+        .map(|(index, bytes)| {
+            let repr: [u8; 32] =
+                (*bytes)
+                    .try_into()
+                    .map_err(|_| BattleshipError::InvalidInstanceLength {
+                        index,
+                        length: bytes.len(),
+                    })?;
+            BinaryValue::from_repr(repr)
+                .try_to_fp()
+                .map_err(|_| BattleshipError::NonCanonicalInstance { index })
+        })
+        .collect::<Result<Vec<pallas::Base>, _>>()?;
+    let strategy = SingleVerifier::new(params);
+    let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof);
+    Ok(verify_proof(params, vk, strategy, &[&[&instance]], &mut transcript).is_ok())
+}
+
+/**
+ * Circuit a proof bundle was generated by
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ProofKind {
+    // proof of a valid board opening for a commitment
+    Board,
+    // proof of the result of a shot against a committed board
+    Shot,
+    // proof of the result of a shot bound to the turn it was fired on
+    TurnShot,
+}
+
+impl ProofKind {
+    // number of public inputs exported by the circuit
+    pub fn instance_len(self) -> usize {
+        match self {
+            ProofKind::Board => 2,
+            ProofKind::Shot => 4,
+            ProofKind::TurnShot => 5,
+        }
+    }
+}
+
+/**
+ * A proof packaged with the public inputs it was generated for
+ * @dev self describing, so a verifier only needs the shared keys to check it
+ *
+ * @param vk - fingerprint of the verifying key the proof was generated for
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofBundle {
+    pub kind: ProofKind,
+    pub vk: [u8; 32],
+    pub instance: Vec<pallas::Base>,
+    pub proof: Vec<u8>,
+}
+
+impl ProofBundle {
+    /**
+     * Bundle a board proof with the commitment it opens
+     *
+     * @param commitment - the public board commitment
+     * @param proof - the serialized proof generated by BoardCircuit::prove()
+     * @param vk - fingerprint of the board verifying key
+     * @return - the board proof bundle
+     */
+    pub fn board(commitment: &BoardCommitment, proof: Vec<u8>, vk: [u8; 32]) -> ProofBundle {
+        ProofBundle {
+            kind: ProofKind::Board,
+            vk,
+            instance: commitment.0.to_vec(),
+            proof,
+        }
+    }
+
+    /**
+     * Bundle a shot proof with its public inputs
+     *
+     * @param instance - [commitment x, commitment y, shot, hit] as built by ShotCircuit::instance()
+     * @param proof - the serialized proof generated by ShotCircuit::prove()
+     * @param vk - fingerprint of the shot verifying key
+     * @return - the shot proof bundle
+     */
+    pub fn shot(instance: [pallas::Base; 4], proof: Vec<u8>, vk: [u8; 32]) -> ProofBundle {
+        ProofBundle {
+            kind: ProofKind::Shot,
+            vk,
+            instance: instance.to_vec(),
+            proof,
+        }
+    }
+
+    /**
+     * Bundle a turn bound shot proof with its public inputs
+     *
+     * @param instance - [commitment x, commitment y, shot, hit, turn] as built by
+     *                   ShotCircuit::instance_with_turn()
+     * @param proof - the serialized proof generated by ShotCircuit::prove()
+     * @param vk - fingerprint of the turn bound shot verifying key
+     * @return - the turn bound shot proof bundle
+     */
+    pub fn turn_shot(instance: [pallas::Base; 5], proof: Vec<u8>, vk: [u8; 32]) -> ProofBundle {
+        ProofBundle {
+            kind: ProofKind::TurnShot,
+            vk,
+            instance: instance.to_vec(),
+            proof,
+        }
+    }
+
+    /**
+     * Pack the bundle into bytes
+     * @dev layout: [version: 1] | [kind: 1] | [vk fingerprint: 32]
+     *              | instance_len x [little endian field element: 32] | proof
+     *
+     * @return - byte encoding of the bundle
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(34 + 32 * self.instance.len() + self.proof.len());
+        bytes.push(PROOF_BUNDLE_VERSION);
+        bytes.push(match self.kind {
+            ProofKind::Board => 0,
+            ProofKind::Shot => 1,
+            ProofKind::TurnShot => 2,
+        });
+        bytes.extend_from_slice(&self.vk);
+        for value in self.instance.iter() {
+            bytes.extend_from_slice(&BinaryValue::from_fp(*value).to_repr());
+        }
+        bytes.extend_from_slice(&self.proof);
+        bytes
+    }
+
+    /**
+     * Unpack a bundle encoded by to_bytes()
+     *
+     * @param bytes - byte encoding of the bundle
+     * @return - the bundle, or a string explaining why the encoding was rejected
+     */
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProofBundle, &'static str> {
+        if bytes.len() < 2 {
+            return Err("Proof bundle is truncated!");
+        } else if bytes[0] != PROOF_BUNDLE_VERSION {
+            return Err("Unsupported proof bundle version!");
+        }
+        let kind = match bytes[1] {
+            0 => ProofKind::Board,
+            1 => ProofKind::Shot,
+            2 => ProofKind::TurnShot,
+            _ => return Err("Unknown proof kind!"),
+        };
+        let proof_offset = 34 + 32 * kind.instance_len();
+        if bytes.len() < proof_offset {
+            return Err("Proof bundle is truncated!");
+        }
+        let instance = bytes[34..proof_offset]
+            .chunks(32)
+            .map(|chunk| BinaryValue::from_repr(chunk.try_into().unwrap()).try_to_fp())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "Public input is not a canonical field element!")?;
+        Ok(ProofBundle {
+            kind,
+            vk: bytes[2..34].try_into().unwrap(),
+            instance,
+            proof: bytes[proof_offset..].to_vec(),
+        })
+    }
+
+    /**
+     * Verify the bundled proof against its bundled public inputs
+     * @dev a bundle generated for a different verifying key fails with ConstraintSystemFailure
+     *
+     * @param context - context holding the board and shot verifying keys
+     * @return - Ok if the proof is valid for the public inputs
+     */
+    pub fn verify(&self, context: &ProverContext) -> Result<(), Error> {
+        let vk = match self.kind {
+            ProofKind::Board => context.board_vk_fingerprint(),
+            ProofKind::Shot => context.shot_vk_fingerprint(),
+            ProofKind::TurnShot => context.turn_shot_vk_fingerprint(),
+        };
+        if self.vk != vk {
+            return Err(Error::ConstraintSystemFailure);
+        }
+        match self.kind {
+            ProofKind::Board => {
+                let commitment = BoardCommitment(
+                    self.instance
+                        .as_slice()
+                        .try_into()
+                        .map_err(|_| Error::InvalidInstances)?,
+                );
+                context.verify_board(&commitment, &self.proof)
+            }
+            ProofKind::Shot => {
+                let instance = self
+                    .instance
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Error::InvalidInstances)?;
+                context.verify_shot(&instance, &self.proof)
+            }
+            ProofKind::TurnShot => {
+                let instance = self
+                    .instance
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Error::InvalidInstances)?;
+                context.verify_turn_shot(&instance, &self.proof)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            circuits::{
+                board::{BoardCircuit, BOARD_CIRCUIT_K},
+                shot::ShotCircuit,
+            },
+            utils::{
+                commitment::ShotCommitment, fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS,
+                shot::ShotResult,
+            },
+        },
+        halo2_proofs::{arithmetic::Field, pasta::group::ff::PrimeField},
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn bytes_round_trip() {
+        // bundle a placeholder proof for a hit at (3, 5) against board pattern 1
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let commitment = BoardCommitment::commit(&board, &pallas::Scalar::random(&mut OsRng));
+        let shot = ShotCommitment::new(3, 5).unwrap();
+        let instance = ShotCircuit::instance(&commitment, &shot, ShotResult::Hit);
+        let turn_instance = ShotCircuit::instance_with_turn(&commitment, &shot, ShotResult::Hit, 7);
+        let bundles = [
+            ProofBundle::board(&commitment, vec![1, 2, 3], [7; 32]),
+            ProofBundle::shot(instance, vec![4, 5, 6], [8; 32]),
+            ProofBundle::turn_shot(turn_instance, vec![7, 8, 9], [9; 32]),
+        ];
+        for bundle in bundles {
+            let bytes = bundle.to_bytes();
+            assert_eq!(ProofBundle::from_bytes(&bytes), Ok(bundle.clone()));
+            // a bundle truncated inside the public inputs is rejected
+            let proof_offset = 34 + 32 * bundle.kind.instance_len();
+            assert!(ProofBundle::from_bytes(&bytes[..proof_offset - 1]).is_err());
+        }
+        // unknown versions and kinds are rejected
+        let mut bytes = ProofBundle::board(&commitment, vec![], [0; 32]).to_bytes();
+        bytes[1] = 3;
+        assert_eq!(ProofBundle::from_bytes(&bytes), Err("Unknown proof kind!"));
+        bytes[0] = 0;
+        assert_eq!(
+            ProofBundle::from_bytes(&bytes),
+            Err("Unsupported proof bundle version!")
+        );
+    }
+
+    #[test]
+    fn verify_board_from_bytes() {
+        // prove board pattern 1
+        let board = pattern_1();
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+        );
+        let params: Params<vesta::Affine> = Params::new(BOARD_CIRCUIT_K);
+        let pk = BoardCircuit::keygen(&params).unwrap();
+        let proof = circuit.prove(&params, &pk).unwrap();
+        // the byte encoded commitment verifies, and the swapped coordinates do not
+        let [x, y] = circuit
+            .commitment()
+            .0
+            .map(|coordinate| coordinate.to_repr());
+        assert_eq!(
+            verify_from_bytes(&params, &proof, &[&x, &y], pk.get_vk()),
+            Ok(true)
+        );
+        assert_eq!(
+            verify_from_bytes(&params, &proof, &[&y, &x], pk.get_vk()),
+            Ok(false)
+        );
+        // the field modulus is the smallest encoding that is not canonical
+        let mut modulus = (-pallas::Base::one()).to_repr();
+        modulus[0] += 1;
+        assert_eq!(
+            verify_from_bytes(&params, &proof, &[&x, &modulus], pk.get_vk()),
+            Err(BattleshipError::NonCanonicalInstance { index: 1 })
+        );
+        assert_eq!(
+            verify_from_bytes(&params, &proof, &[&x[..31], &y], pk.get_vk()),
+            Err(BattleshipError::InvalidInstanceLength {
+                index: 0,
+                length: 31
+            })
+        );
+    }
+
+    // fingerprints of the board and shot verifying keys at the current circuit revision
+    // @dev any change to a gate, lookup, column layout or k of either circuit changes its fingerprint;
+    //      update these alongside tests/golden and the on-chain verifier registry
+    const BOARD_VK_FINGERPRINT: &str =
+        "e33400b897e4289eee7dd3613667e1db129df06b7ce045134b24aff4f28cbdcb";
+    const SHOT_VK_FINGERPRINT: &str =
+        "f8378ccef6ca27091dc46f3654d73fb1ac30d7f544675e6036e34031cb5d28cf";
+
+    #[test]
+    fn vk_fingerprint_snapshot() {
+        let context = ProverContext::new().unwrap();
+        assert_eq!(
+            hex::encode(context.board_vk_fingerprint()),
+            BOARD_VK_FINGERPRINT
+        );
+        assert_eq!(
+            hex::encode(context.shot_vk_fingerprint()),
+            SHOT_VK_FINGERPRINT
+        );
+        // a bundle carrying another circuit's fingerprint is rejected before verification
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let commitment = BoardCommitment::commit(&board, &pallas::Scalar::random(&mut OsRng));
+        let bundle = ProofBundle::board(&commitment, vec![], context.shot_vk_fingerprint());
+        assert_eq!(
+            format!("{:?}", bundle.verify(&context)),
+            format!("{:?}", Err::<(), _>(Error::ConstraintSystemFailure))
+        );
+    }
+}
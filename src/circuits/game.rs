@@ -0,0 +1,707 @@
+use {
+    crate::{
+        circuits::{
+            board::{BoardCircuit, BOARD_CIRCUIT_K},
+            bundle::vk_fingerprint,
+            shot::{ShotCircuit, SHOT_CIRCUIT_K},
+        },
+        utils::{
+            binary::{BinaryValue, BinaryValueError},
+            board::{Board, GameRuleViolation},
+            commitment::{BoardCommitment, ShotCommitment},
+            coordinate::Coordinate,
+            deck::Deck,
+            error::BattleshipError,
+            opponent::OpponentView,
+            ship::{DEFAULT_WITNESS_OPTIONS, STANDARD_FLEET},
+            shot::ShotResult,
+        },
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        pasta::{pallas, vesta},
+        plonk::{Error, ProvingKey},
+        poly::commitment::Params,
+    },
+    rand::{rngs::OsRng, CryptoRng, RngCore},
+    std::sync::Arc,
+};
+#[cfg(feature = "async")]
+use {
+    core::future::Future,
+    tokio::{
+        sync::Semaphore,
+        task::{self, JoinHandle},
+    },
+};
+
+/**
+ * Parameters and proving keys shared by every board and shot proof of a game
+ * @dev keygen is the expensive step; build one context and reuse it for every proof
+ *      keys are reference counted, so cloning the context never clones a proving key
+ */
+#[derive(Clone, Debug)]
+pub struct ProverContext {
+    pub board_params: Arc<Params<vesta::Affine>>,
+    pub board_pk: Arc<ProvingKey<vesta::Affine>>,
+    pub shot_params: Arc<Params<vesta::Affine>>,
+    pub shot_pk: Arc<ProvingKey<vesta::Affine>>,
+    pub shot_turn_pk: Arc<ProvingKey<vesta::Affine>>,
+}
+
+/**
+ * Errors returned when a proof offloaded to the blocking pool does not complete
+ */
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub enum AsyncProofError {
+    // the prover failed to create the proof
+    Proof(Error),
+    // the blocking task was cancelled before it ran, e.g. by runtime shutdown
+    Cancelled,
+}
+
+#[cfg(feature = "async")]
+impl core::fmt::Display for AsyncProofError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AsyncProofError::Proof(error) => write!(f, "Proof generation failed: {}", error),
+            AsyncProofError::Cancelled => write!(f, "Proof task was cancelled"),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl std::error::Error for AsyncProofError {}
+
+impl ProverContext {
+    /**
+     * Generate parameters and proving keys for the board and shot circuits
+     *
+     * @return - context holding params with k = BOARD_CIRCUIT_K and k = SHOT_CIRCUIT_K and their keys
+     */
+    pub fn new() -> Result<ProverContext, Error> {
+        let board_params = Params::new(BOARD_CIRCUIT_K);
+        let board_pk = BoardCircuit::keygen(&board_params)?;
+        let shot_params = Params::new(SHOT_CIRCUIT_K);
+        let shot_pk = ShotCircuit::keygen(&shot_params)?;
+        let shot_turn_pk = ShotCircuit::keygen_with_turn(&shot_params)?;
+        Ok(ProverContext {
+            board_params: Arc::new(board_params),
+            board_pk: Arc::new(board_pk),
+            shot_params: Arc::new(shot_params),
+            shot_pk: Arc::new(shot_pk),
+            shot_turn_pk: Arc::new(shot_turn_pk),
+        })
+    }
+
+    /**
+     * Fingerprint of the board verifying key
+     *
+     * @return - vk_fingerprint() of the board verifying key
+     */
+    pub fn board_vk_fingerprint(&self) -> [u8; 32] {
+        vk_fingerprint(self.board_pk.get_vk())
+    }
+
+    /**
+     * Fingerprint of the shot verifying key
+     *
+     * @return - vk_fingerprint() of the shot verifying key
+     */
+    pub fn shot_vk_fingerprint(&self) -> [u8; 32] {
+        vk_fingerprint(self.shot_pk.get_vk())
+    }
+
+    /**
+     * Fingerprint of the verifying key for shots bound to a turn
+     *
+     * @return - vk_fingerprint() of the turn bound shot verifying key
+     */
+    pub fn turn_shot_vk_fingerprint(&self) -> [u8; 32] {
+        vk_fingerprint(self.shot_turn_pk.get_vk())
+    }
+
+    /**
+     * Prove a valid board with the shared board key
+     * @dev rejects a board that does not occupy exactly the standard fleet's cells, or a ship whose
+     *      commitments do not set its length in bits, before proving; surfaced as Error::Synthesis
+     *      like every BattleshipError
+     *
+     * @param circuit - the board circuit to prove
+     * @return - the serialized proof
+     */
+    pub fn prove_board(&self, circuit: &BoardCircuit) -> Result<Vec<u8>, Error> {
+        self.prove_board_with_rng(circuit, OsRng)
+    }
+
+    /**
+     * Prove a valid board with the shared board key and a given rng
+     * @dev seed the rng only for reproducible tests; a known seed leaks the proof's blinding
+     *
+     * @param circuit - the board circuit to prove
+     * @param rng - source of the proof's blinding factors
+     * @return - the serialized proof
+     */
+    pub fn prove_board_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        circuit: &BoardCircuit,
+        rng: R,
+    ) -> Result<Vec<u8>, Error> {
+        let (actual, expected) = (circuit.cell_count(), STANDARD_FLEET.cells());
+        if actual != expected {
+            return Err(BattleshipError::InvalidFleetSize { actual, expected }.into());
+        }
+        circuit.prove_with_rng(&self.board_params, &self.board_pk, rng)
+    }
+
+    /**
+     * Prove the result of a shot with the shared shot key
+     * @dev circuits from ShotCircuit::new_with_turn() are proven with the turn bound shot key
+     *
+     * @param circuit - the shot circuit to prove
+     * @return - the serialized proof
+     */
+    pub fn prove_shot(&self, circuit: &ShotCircuit) -> Result<Vec<u8>, Error> {
+        self.prove_shot_with_rng(circuit, OsRng)
+    }
+
+    /**
+     * Prove the result of a shot with the shared shot key and a given rng
+     * @dev seed the rng only for reproducible tests; a known seed leaks the proof's blinding
+     *
+     * @param circuit - the shot circuit to prove
+     * @param rng - source of the proof's blinding factors
+     * @return - the serialized proof
+     */
+    pub fn prove_shot_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        circuit: &ShotCircuit,
+        rng: R,
+    ) -> Result<Vec<u8>, Error> {
+        circuit.prove_with_rng(&self.shot_params, self.shot_key(circuit), rng)
+    }
+
+    /**
+     * Select the shot proving key matching a circuit's public inputs
+     *
+     * @param circuit - the shot circuit to prove
+     * @return - shot_turn_pk if the circuit exports a turn, otherwise shot_pk
+     */
+    fn shot_key(&self, circuit: &ShotCircuit) -> &Arc<ProvingKey<vesta::Affine>> {
+        match circuit.turn {
+            Some(_) => &self.shot_turn_pk,
+            None => &self.shot_pk,
+        }
+    }
+
+    /**
+     * Prove the result of a shot on the tokio blocking pool
+     * @dev the proof starts once the future is first polled; dropping the future afterwards detaches
+     *      the proof rather than interrupting it
+     *
+     * @param circuit - the shot circuit to prove
+     * @return - future resolving to the serialized proof
+     */
+    #[cfg(feature = "async")]
+    pub fn prove_shot_async(
+        &self,
+        circuit: ShotCircuit,
+    ) -> impl Future<Output = Result<Vec<u8>, AsyncProofError>> + Send + 'static {
+        let params = Arc::clone(&self.shot_params);
+        let pk = Arc::clone(self.shot_key(&circuit));
+        async move { join_proof(task::spawn_blocking(move || circuit.prove(&params, &pk))).await }
+    }
+
+    /**
+     * Prove many shots on the tokio blocking pool with at most `concurrency` proofs running at once
+     *
+     * @param circuits - the shot circuits to prove
+     * @param concurrency - maximum number of proofs in flight (at least 1)
+     * @return - the serialized proof for each circuit in order, or the first error encountered
+     */
+    #[cfg(feature = "async")]
+    pub async fn prove_many_shots(
+        &self,
+        circuits: Vec<ShotCircuit>,
+        concurrency: usize,
+    ) -> Result<Vec<Vec<u8>>, AsyncProofError> {
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+        let mut handles = Vec::with_capacity(circuits.len());
+        for circuit in circuits {
+            // wait for a proof in flight to finish before starting the next
+            let permit = Arc::clone(&semaphore)
+                .acquire_owned()
+                .await
+                .map_err(|_| AsyncProofError::Cancelled)?;
+            let params = Arc::clone(&self.shot_params);
+            let pk = Arc::clone(self.shot_key(&circuit));
+            handles.push(task::spawn_blocking(move || {
+                let proof = circuit.prove(&params, &pk);
+                drop(permit);
+                proof
+            }));
+        }
+        let mut proofs = Vec::with_capacity(handles.len());
+        for handle in handles {
+            proofs.push(join_proof(handle).await?);
+        }
+        Ok(proofs)
+    }
+
+    /**
+     * Verify a board proof with the shared board key
+     *
+     * @param commitment - the public board commitment
+     * @param proof - the serialized proof generated by prove_board()
+     * @return - Ok if the proof is valid for the commitment
+     */
+    pub fn verify_board(&self, commitment: &BoardCommitment, proof: &[u8]) -> Result<(), Error> {
+        BoardCircuit::verify(
+            &self.board_params,
+            self.board_pk.get_vk(),
+            commitment,
+            proof,
+        )
+    }
+
+    /**
+     * Verify a shot proof with the shared shot key
+     *
+     * @param public_inputs - [commitment x, commitment y, shot, hit]
+     * @param proof - the serialized proof generated by prove_shot()
+     * @return - Ok if the proof is valid for the public inputs
+     */
+    pub fn verify_shot(
+        &self,
+        public_inputs: &[pallas::Base; 4],
+        proof: &[u8],
+    ) -> Result<(), Error> {
+        Ok(ShotCircuit::verify(
+            &self.shot_params,
+            self.shot_pk.get_vk(),
+            public_inputs,
+            proof,
+        )?)
+    }
+
+    /**
+     * Verify a shot proof bound to a turn with the shared turn bound shot key
+     *
+     * @param public_inputs - [commitment x, commitment y, shot, hit, turn]
+     * @param proof - the serialized proof generated by prove_shot()
+     * @return - Ok if the proof is valid for the public inputs
+     */
+    pub fn verify_turn_shot(
+        &self,
+        public_inputs: &[pallas::Base; 5],
+        proof: &[u8],
+    ) -> Result<(), Error> {
+        Ok(ShotCircuit::verify_with_turn(
+            &self.shot_params,
+            self.shot_turn_pk.get_vk(),
+            public_inputs,
+            proof,
+        )?)
+    }
+}
+
+/**
+ * Await a proof running on the blocking pool
+ * @dev a panic inside the prover is resumed on the awaiting task
+ *
+ * @param handle - handle to the blocking proof task
+ * @return - the serialized proof, or why it was not produced
+ */
+#[cfg(feature = "async")]
+async fn join_proof(
+    handle: JoinHandle<Result<Vec<u8>, Error>>,
+) -> Result<Vec<u8>, AsyncProofError> {
+    match handle.await {
+        Ok(proof) => proof.map_err(AsyncProofError::Proof),
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(_) => Err(AsyncProofError::Cancelled),
+    }
+}
+
+/**
+ * A player's published board commitment and the proof that it commits to a valid board
+ */
+#[derive(Clone, Debug)]
+pub struct BoardRecord {
+    pub commitment: BoardCommitment,
+    pub proof: Vec<u8>,
+}
+
+/**
+ * A shot fired at a player's board and the defender's proof of its result
+ *
+ * @param defender - index of the player whose board was shot at
+ * @param shot - x, y coordinates serialized into a shot commitment
+ * @param turn - index of the shot in the game, bound into the proof
+ */
+#[derive(Clone, Debug)]
+pub struct ShotRecord {
+    pub defender: usize,
+    pub shot: ShotCommitment,
+    pub hit: bool,
+    pub turn: u64,
+    pub proof: Vec<u8>,
+}
+
+/**
+ * Public record of a two player game that anyone holding the verifying keys can check
+ */
+#[derive(Clone, Debug)]
+pub struct GameTranscript {
+    pub boards: [BoardRecord; 2],
+    pub shots: Vec<ShotRecord>,
+}
+
+impl GameTranscript {
+    /**
+     * Start a transcript once both players have published their boards
+     *
+     * @param boards - board commitment and proof of each player
+     * @return - transcript with no shots
+     */
+    pub fn new(boards: [BoardRecord; 2]) -> GameTranscript {
+        GameTranscript {
+            boards,
+            shots: Vec::new(),
+        }
+    }
+
+    /**
+     * Append the proven result of a shot
+     *
+     * @param shot - the shot and the defender's proof of its result
+     */
+    pub fn record_shot(&mut self, shot: ShotRecord) {
+        self.shots.push(shot);
+    }
+
+    /**
+     * Return the player that has hit every cell of the other player's fleet
+     *
+     * @return - index of the winning player, or None while both fleets are afloat
+     */
+    pub fn winner(&self) -> Option<usize> {
+        (0..2).find(|player| {
+            let mut hits = BinaryValue::empty().value;
+            for shot in self.shots.iter() {
+                if shot.defender != *player && shot.hit {
+                    hits |= shot.shot.0.value;
+                }
+            }
+            hits.count_ones() == STANDARD_FLEET.cells()
+        })
+    }
+
+    /**
+     * Verify every board and shot proof in the transcript, replaying the verified shots
+     * @dev each shot proof is checked against the defender's published board commitment and must
+     *      be bound to its position in the transcript, so shots cannot be replayed or reordered
+     *
+     * @param context - context holding the board and shot verifying keys
+     * @return - each player's view of the opponent's board after the last shot, indexed like
+     *           boards, or the first verification error
+     */
+    pub fn verify(&self, context: &ProverContext) -> Result<[OpponentView; 2], Error> {
+        for board in self.boards.iter() {
+            context.verify_board(&board.commitment, &board.proof)?;
+        }
+        let mut views = [OpponentView::new(), OpponentView::new()];
+        for (expected, shot) in (0u64..).zip(self.shots.iter()) {
+            if shot.turn != expected {
+                return Err(BattleshipError::TurnMismatch {
+                    expected,
+                    got: shot.turn,
+                }
+                .into());
+            }
+            let commitment = self
+                .boards
+                .get(shot.defender)
+                .ok_or(Error::ConstraintSystemFailure)?
+                .commitment;
+            let hit = ShotResult::from(shot.hit);
+            let public_inputs =
+                ShotCircuit::instance_with_turn(&commitment, &shot.shot, hit, shot.turn);
+            context.verify_turn_shot(&public_inputs, &shot.proof)?;
+            // a verified shot sets exactly one bit of the board
+            let coordinate = shot
+                .shot
+                .0
+                .iter_set_bits()
+                .next()
+                .and_then(|index| Coordinate::from_index(index).ok())
+                .ok_or(Error::ConstraintSystemFailure)?;
+            views[1 - shot.defender].apply(coordinate, shot.hit);
+        }
+        Ok(views)
+    }
+}
+
+/**
+ * One of the two players of a game
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Player {
+    One,
+    Two,
+}
+
+impl Player {
+    /**
+     * Index of the player in GameTranscript::boards and ShotRecord::defender
+     *
+     * @return - 0 for Player::One, 1 for Player::Two
+     */
+    pub fn index(self) -> usize {
+        match self {
+            Player::One => 0,
+            Player::Two => 1,
+        }
+    }
+
+    /**
+     * The player on the other side of the board
+     *
+     * @return - Player::Two for Player::One and vice versa
+     */
+    pub fn opponent(self) -> Player {
+        match self {
+            Player::One => Player::Two,
+            Player::Two => Player::One,
+        }
+    }
+}
+
+/**
+ * Private state a player keeps for the duration of a game
+ *
+ * @param board - the player's board, never revealed during the game
+ * @param trapdoor - trapdoor of the player's published board commitment
+ * @param view - what the player has learned about the opponent's board from verified shots
+ */
+#[derive(Clone, Debug)]
+pub struct GameSession {
+    pub board: Board,
+    pub trapdoor: pallas::Scalar,
+    pub view: OpponentView,
+}
+
+/**
+ * Reasons a step of the two player protocol is rejected
+ */
+#[derive(Debug)]
+pub enum ProtocolError {
+    // a player's deck does not place a valid board
+    InvalidBoard {
+        player: Player,
+        violations: Vec<GameRuleViolation>,
+    },
+    // the attacker fired out of turn
+    OutOfTurn {
+        expected: Player,
+    },
+    // a fleet is already sunk
+    GameOver {
+        winner: Player,
+    },
+    // the shot is not on the board
+    InvalidShot(BinaryValueError),
+    // the attacker already knows the result of a shot at this cell
+    RepeatedShot {
+        x: u8,
+        y: u8,
+    },
+    // the prover failed to create a board or shot proof
+    Proof(Error),
+}
+
+impl core::fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ProtocolError::InvalidBoard { player, violations } => {
+                write!(
+                    f,
+                    "Player {:?} placed an invalid board: {:?}",
+                    player, violations
+                )
+            }
+            ProtocolError::OutOfTurn { expected } => {
+                write!(f, "It is player {:?}'s turn", expected)
+            }
+            ProtocolError::GameOver { winner } => write!(f, "Player {:?} already won", winner),
+            ProtocolError::InvalidShot(error) => write!(f, "Invalid shot: {:?}", error),
+            ProtocolError::RepeatedShot { x, y } => {
+                write!(f, "({}, {}) was already fired at", x, y)
+            }
+            ProtocolError::Proof(error) => write!(f, "Proof generation failed: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ProtocolError {}
+
+/**
+ * Both sides of a game: each player commits to a board, then players alternate shots that the
+ * defender answers with a proof, until every cell of one fleet is hit
+ * @dev Player::One fires first; every board and shot proof is recorded in the public transcript
+ */
+#[derive(Clone, Debug)]
+pub struct TwoPlayerProtocol {
+    pub context: ProverContext,
+    pub player1: GameSession,
+    pub player2: GameSession,
+    pub transcript: GameTranscript,
+    pub next: Player,
+}
+
+impl TwoPlayerProtocol {
+    /**
+     * Commit both players to their boards and prove each board valid
+     *
+     * @param context - prover context shared by every proof of the game
+     * @param p1_deck - ship placements of Player::One
+     * @param p2_deck - ship placements of Player::Two
+     * @return - the protocol ready for the first shot and each player's published board record
+     */
+    pub fn setup(
+        context: ProverContext,
+        p1_deck: &Deck,
+        p2_deck: &Deck,
+    ) -> Result<(TwoPlayerProtocol, BoardRecord, BoardRecord), ProtocolError> {
+        let mut sessions = Vec::new();
+        let mut records = Vec::new();
+        for (player, deck) in [(Player::One, p1_deck), (Player::Two, p2_deck)] {
+            let board = Board::from(deck);
+            board
+                .is_valid()
+                .map_err(|violations| ProtocolError::InvalidBoard { player, violations })?;
+            let trapdoor = pallas::Scalar::random(&mut OsRng);
+            let circuit = BoardCircuit::new(
+                board.witness(DEFAULT_WITNESS_OPTIONS),
+                board.state(DEFAULT_WITNESS_OPTIONS),
+                trapdoor,
+            );
+            let proof = context
+                .prove_board(&circuit)
+                .map_err(ProtocolError::Proof)?;
+            records.push(BoardRecord {
+                commitment: circuit.commitment(),
+                proof,
+            });
+            sessions.push(GameSession {
+                board,
+                trapdoor,
+                view: OpponentView::new(),
+            });
+        }
+        let [player1, player2]: [GameSession; 2] = sessions.try_into().unwrap();
+        let [record1, record2]: [BoardRecord; 2] = records.try_into().unwrap();
+        let protocol = TwoPlayerProtocol {
+            context,
+            player1,
+            player2,
+            transcript: GameTranscript::new([record1.clone(), record2.clone()]),
+            next: Player::One,
+        };
+        Ok((protocol, record1, record2))
+    }
+
+    /**
+     * Fire a shot and have the defender prove its result
+     * @dev the turn passes to the defender whether the shot hits or misses
+     *
+     * @param attacker - the player firing the shot
+     * @param x - x coordinate of the shot
+     * @param y - y coordinate of the shot
+     * @return - the shot and the defender's proof of its result, also appended to the transcript
+     */
+    pub fn take_turn(
+        &mut self,
+        attacker: Player,
+        x: u8,
+        y: u8,
+    ) -> Result<ShotRecord, ProtocolError> {
+        if let Some(winner) = self.is_game_over() {
+            return Err(ProtocolError::GameOver { winner });
+        }
+        if attacker != self.next {
+            return Err(ProtocolError::OutOfTurn {
+                expected: self.next,
+            });
+        }
+        let shot = ShotCommitment::new(x, y).map_err(ProtocolError::InvalidShot)?;
+        if self.session(attacker).view.is_cell_resolved(x, y) {
+            return Err(ProtocolError::RepeatedShot { x, y });
+        }
+        // the defender proves the result of the shot against their committed board
+        let defender = self.session(attacker.opponent());
+        let hit = defender
+            .board
+            .hit(x, y)
+            .map_err(ProtocolError::InvalidShot)?;
+        let turn = self.transcript.shots.len() as u64;
+        let circuit = ShotCircuit::new_with_turn(
+            defender.board.state(DEFAULT_WITNESS_OPTIONS),
+            defender.trapdoor,
+            shot.0,
+            ShotResult::from(hit),
+            turn,
+        );
+        let proof = self
+            .context
+            .prove_shot(&circuit)
+            .map_err(ProtocolError::Proof)?;
+        let record = ShotRecord {
+            defender: attacker.opponent().index(),
+            shot,
+            hit,
+            turn,
+            proof,
+        };
+        self.transcript.record_shot(record.clone());
+        self.session_mut(attacker)
+            .view
+            .apply_shot(x, y, hit)
+            .map_err(ProtocolError::InvalidShot)?;
+        self.next = attacker.opponent();
+        Ok(record)
+    }
+
+    /**
+     * Return the player that has hit every cell of the other player's fleet
+     *
+     * @return - the winning player, or None while both fleets are afloat
+     */
+    pub fn is_game_over(&self) -> Option<Player> {
+        self.transcript.winner().map(|index| match index {
+            0 => Player::One,
+            _ => Player::Two,
+        })
+    }
+
+    /**
+     * Private state of a player
+     *
+     * @param player - the player to look up
+     * @return - the player's session
+     */
+    pub fn session(&self, player: Player) -> &GameSession {
+        match player {
+            Player::One => &self.player1,
+            Player::Two => &self.player2,
+        }
+    }
+
+    fn session_mut(&mut self, player: Player) -> &mut GameSession {
+        match player {
+            Player::One => &mut self.player1,
+            Player::Two => &mut self.player2,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use {
+    crate::{
+        chips::{
+            history::{history_hash, HistoryChip, HistoryConfig},
+            shot::{HitMode, ShotChip, ShotConfig},
+        },
+        utils::{binary::BinaryValue, commitment::BoardCommitment, error::BattleshipError},
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/**
+ * Storage for a shot circuit that also extends a Poseidon shot history accumulator
+ *
+ * @param shot - ShotChip config for the hit/ miss proof
+ * @param history - HistoryChip config over the shot columns
+ */
+#[derive(Clone, Debug)]
+pub struct HistoryShotConfig {
+    pub shot: ShotConfig,
+    pub history: HistoryConfig,
+}
+
+/**
+ * Shot circuit variant whose history is the public accumulator h = Poseidon(h_prev, shot) instead
+ * of every earlier shot, proving the shot does not repeat any of the last T shots
+ */
+#[derive(Debug, Clone)]
+pub struct HistoryShotCircuit<const T: usize> {
+    pub board: BinaryValue,
+    pub board_commitment_trapdoor: pallas::Scalar,
+    pub shot: BinaryValue,
+    pub hit: BinaryValue,
+    pub h_base: pallas::Base,
+    pub recent: [BinaryValue; T],
+}
+
+impl<const T: usize> Circuit<pallas::Base> for HistoryShotCircuit<T> {
+    type Config = HistoryShotConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        self.clone()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        let shot = ShotChip::configure(meta, HitMode::Boolean);
+        let history = HistoryChip::configure(meta, shot.advice, shot.fixed);
+        HistoryShotConfig { shot, history }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let instance = config.shot.instance;
+        ShotChip::new(config.shot).synthesize(
+            layouter.namespace(|| "shot"),
+            self.board,
+            self.board_commitment_trapdoor,
+            self.shot,
+            self.hit,
+        )?;
+        let [shot, h_prev, h_next] = HistoryChip::new(config.history).synthesize(
+            layouter.namespace(|| "history"),
+            self.h_base,
+            self.recent.map(|shot| shot.to_fp()),
+            self.shot.to_fp(),
+        )?;
+        // the history shot is the shot ShotChip exported, since both are bound to instance row 2
+        layouter.constrain_instance(shot.cell(), instance, 2)?;
+        layouter.constrain_instance(h_prev.cell(), instance, 4)?;
+        layouter.constrain_instance(h_next.cell(), instance, 5)?;
+        Ok(())
+    }
+}
+
+impl<const T: usize> HistoryShotCircuit<T> {
+    /**
+     * Construct a new shot circuit extending the shot history
+     *
+     * @param board - the board state of the defender
+     * @param board_commitment_trapdoor - trapdoor to the pedersen board commitment
+     * @param shot - the serialized shot being fired
+     * @param hit - assertion that the shot hits the board
+     * @param h_base - accumulator before the oldest recent shot (history_genesis() early in a game)
+     * @param recent - the last T shots, oldest first (empty shots early in a game)
+     * @return - instantiated HistoryShotCircuit object
+     */
+    pub fn new(
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+        h_base: pallas::Base,
+        recent: [BinaryValue; T],
+    ) -> HistoryShotCircuit<T> {
+        HistoryShotCircuit {
+            board,
+            board_commitment_trapdoor,
+            shot,
+            hit,
+            h_base,
+            recent,
+        }
+    }
+
+    /**
+     * Compute the accumulator this circuit proves the recent shots chain to
+     *
+     * @return - h_base with each recent shot folded in, oldest first
+     */
+    pub fn h_prev(&self) -> pallas::Base {
+        self.recent
+            .iter()
+            .fold(self.h_base, |h, shot| history_hash(h, shot.to_fp()))
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [commitment x, commitment y, shot, hit, h_prev, h_next], or IdentityCommitment
+     */
+    pub fn try_public_inputs(&self) -> Result<[pallas::Base; 6], BattleshipError> {
+        let commitment = BoardCommitment::try_commit(&self.board, &self.board_commitment_trapdoor)?;
+        let h_prev = self.h_prev();
+        Ok([
+            commitment.0[0],
+            commitment.0[1],
+            self.shot.to_fp(),
+            self.hit.to_fp(),
+            h_prev,
+            history_hash(h_prev, self.shot.to_fp()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::{gate_names::GATE_HISTORY_NON_MEMBERSHIP, history::history_genesis},
+            circuits::layout::recommended_k,
+            utils::{fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS, shot::serialize},
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            plonk::Any,
+        },
+        rand::rngs::OsRng,
+    };
+
+    /**
+     * Build the circuit for the next turn of a history, sliding the last T shots along
+     *
+     * @param history - every shot fired so far, oldest first
+     * @param shot - the shot being fired
+     * @return - the circuit proving the shot against pattern 1
+     */
+    fn next_turn<const T: usize>(
+        history: &[BinaryValue],
+        shot: BinaryValue,
+    ) -> HistoryShotCircuit<T> {
+        let board = pattern_1();
+        // seed the window with empty shots so it is always T long
+        let mut window = vec![BinaryValue::empty(); T];
+        window.extend_from_slice(history);
+        let (older, recent) = window.split_at(window.len() - T);
+        let h_base = older.iter().fold(pallas::Base::zero(), |h, shot| {
+            history_hash(h, shot.to_fp())
+        });
+        let hit = board.hit_count(&[shot]) as u8;
+        HistoryShotCircuit::new(
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+            shot,
+            BinaryValue::from_u8(hit),
+            h_base,
+            recent.to_vec().try_into().unwrap(),
+        )
+    }
+
+    #[test]
+    fn valid_history_chaining() {
+        // fire three shots, each turn starting from the accumulator the previous turn exported
+        let shots = [
+            serialize::<1>([3], [3]),
+            serialize::<1>([0], [0]),
+            serialize::<1>([7], [1]),
+        ];
+        let mut h = history_genesis::<2>();
+        for (turn, shot) in shots.iter().enumerate() {
+            let circuit = next_turn::<2>(&shots[..turn], *shot);
+            let public_inputs = circuit.try_public_inputs().unwrap();
+            assert_eq!(public_inputs[4], h);
+            let k = recommended_k(&circuit);
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.to_vec()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+            h = public_inputs[5];
+        }
+        // the final accumulator folds every shot onto the genesis accumulator
+        let expected = shots.iter().fold(history_genesis::<2>(), |h, shot| {
+            history_hash(h, shot.to_fp())
+        });
+        assert_eq!(h, expected);
+    }
+
+    #[test]
+    fn invalid_forged_h_next() {
+        let history = [serialize::<1>([3], [3])];
+        let circuit = next_turn::<2>(&history, serialize::<1>([0], [0]));
+        let k = recommended_k(&circuit);
+        // claim the shot was folded into a different accumulator
+        let mut public_inputs = circuit.try_public_inputs().unwrap();
+        public_inputs[5] += pallas::Base::one();
+        let prover = MockProver::run(k, &circuit, vec![public_inputs.to_vec()]).unwrap();
+        assert!(prover
+            .verify()
+            .unwrap_err()
+            .contains(&VerifyFailure::Permutation {
+                column: (Any::Instance, 0).into(),
+                location: FailureLocation::OutsideRegion { row: 5 }
+            }));
+    }
+
+    #[test]
+    fn invalid_repeated_shot() {
+        // (3, 3) is still in the window of the last 2 shots
+        let history = [serialize::<1>([3], [3]), serialize::<1>([0], [0])];
+        let circuit = next_turn::<2>(&history, serialize::<1>([3], [3]));
+        let k = recommended_k(&circuit);
+        let public_inputs = circuit.try_public_inputs().unwrap().to_vec();
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        let failures = prover.verify().unwrap_err();
+        assert_eq!(failures.len(), 1);
+        match &failures[0] {
+            VerifyFailure::ConstraintNotSatisfied {
+                constraint,
+                location,
+                ..
+            } => {
+                assert!(format!("{}", constraint).contains(GATE_HISTORY_NON_MEMBERSHIP));
+                assert!(matches!(
+                    location,
+                    FailureLocation::InRegion { offset: 0, .. }
+                ));
+            }
+            failure => panic!("Unexpected failure {:?}", failure),
+        }
+        // once it slides out of the window it may be fired again
+        let history = [
+            serialize::<1>([3], [3]),
+            serialize::<1>([0], [0]),
+            serialize::<1>([7], [1]),
+        ];
+        let circuit = next_turn::<2>(&history, serialize::<1>([3], [3]));
+        let public_inputs = circuit.try_public_inputs().unwrap().to_vec();
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+}
@@ -0,0 +1,82 @@
+use halo2_proofs::{
+    dev::MockProver,
+    pasta::pallas,
+    plonk::{Circuit, Error},
+};
+
+// largest k probed by recommended_k before giving up
+pub const MAX_K: u32 = 18;
+
+/**
+ * Find the smallest k such that the circuit's layout fits in 2^k rows
+ * @dev probes increasing k with the mock prover until it stops running out of rows
+ *      panics if synthesis fails for any other reason or no k up to MAX_K fits
+ *
+ * @param circuit - the circuit to lay out (witness values do not need to satisfy constraints)
+ * @return - the minimal k for the circuit
+ */
+pub fn recommended_k<C: Circuit<pallas::Base>>(circuit: &C) -> u32 {
+    // the number of instance columns is not public, so grow it until the mock prover accepts it
+    let mut instance_columns = 0;
+    let mut k = 1;
+    while k <= MAX_K {
+        match MockProver::run(k, circuit, vec![vec![]; instance_columns]) {
+            Ok(_) => return k,
+            Err(Error::NotEnoughRowsAvailable { .. }) => k += 1,
+            Err(Error::InvalidInstances) => instance_columns += 1,
+            Err(error) => panic!("Failed to lay out circuit at k = {}: {:?}", k, error),
+        }
+    }
+    panic!("Circuit does not fit in 2^{} rows", MAX_K);
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            circuits::{
+                board::{BoardCircuit, BOARD_CIRCUIT_K},
+                shot::{ShotCircuit, SHOT_CIRCUIT_K},
+            },
+            utils::{
+                board::Board,
+                deck::Deck,
+                ship::DEFAULT_WITNESS_OPTIONS,
+                shot::{serialize, ShotResult},
+            },
+        },
+        halo2_proofs::arithmetic::Field,
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn recommended_k_board() {
+        let board = Board::from(&Deck::default());
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+        );
+        assert_eq!(recommended_k(&circuit), 12);
+    }
+
+    #[test]
+    fn recommended_k_shot() {
+        let board = Board::from(&Deck::default());
+        let circuit = ShotCircuit::new(
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            pallas::Scalar::random(&mut OsRng),
+            serialize::<1>([0], [0]),
+            ShotResult::Hit,
+        );
+        assert_eq!(recommended_k(&circuit), 11);
+    }
+
+    #[test]
+    fn min_k_matches_constants() {
+        // the hardcoded k used for keygen is exactly the minimal k of each circuit
+        assert_eq!(BoardCircuit::min_k(), BOARD_CIRCUIT_K);
+        assert_eq!(ShotCircuit::min_k(), SHOT_CIRCUIT_K);
+    }
+}
@@ -1,2 +1,11 @@
 pub mod board;
-pub mod shot;
\ No newline at end of file
+pub mod shot;
+pub mod layout;
+pub mod reveal;
+pub mod turn;
+pub mod game;
+pub mod bundle;
+pub mod history;
+pub mod board_and_shot;
+pub mod blind_shot;
+pub mod verifier;
\ No newline at end of file
@@ -0,0 +1,226 @@
+use {
+    crate::{
+        chips::reveal::{RevealChip, RevealConfig},
+        utils::{
+            binary::BinaryValue, board::Board, pedersen::pedersen_commit,
+            ship::DEFAULT_WITNESS_OPTIONS,
+        },
+    },
+    halo2_proofs::{
+        arithmetic::CurveAffine,
+        circuit::{Layouter, SimpleFloorPlanner},
+        pasta::{group::Curve, pallas},
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+// 2^K rows used by the reveal circuit
+pub const REVEAL_CIRCUIT_K: u32 = 11;
+
+#[derive(Debug, Clone)]
+pub struct RevealCircuit {
+    pub board: BinaryValue,
+    pub board_commitment_trapdoor: pallas::Scalar,
+    pub hits: BinaryValue,
+    pub misses: BinaryValue,
+}
+
+impl Circuit<pallas::Base> for RevealCircuit {
+    type Config = RevealConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        self.clone()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        RevealChip::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        RevealChip::new(config).synthesize(
+            layouter,
+            self.board,
+            self.board_commitment_trapdoor,
+            self.hits,
+            self.misses,
+        )
+    }
+}
+
+impl RevealCircuit {
+    /**
+     * Construct a new reveal circuit opening a board commitment at the end of a game
+     *
+     * @param board - the revealed board state
+     * @param board_commitment_trapdoor - trapdoor to the pedersen board commitment
+     * @param hits - union of all shots answered as hits during the game
+     * @param misses - union of all shots answered as misses during the game
+     * @return - instantiated RevealCircuit object
+     */
+    pub fn new(
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        hits: BinaryValue,
+        misses: BinaryValue,
+    ) -> RevealCircuit {
+        RevealCircuit {
+            board,
+            board_commitment_trapdoor,
+            hits,
+            misses,
+        }
+    }
+
+    /**
+     * Construct a reveal circuit that only opens the board commitment, with no claims to check
+     *
+     * @param board - the revealed board
+     * @param board_commitment_trapdoor - trapdoor to the pedersen board commitment
+     * @return - instantiated RevealCircuit object with empty claimed hits and misses
+     */
+    pub fn opening(board: &Board, board_commitment_trapdoor: pallas::Scalar) -> RevealCircuit {
+        RevealCircuit::new(
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            board_commitment_trapdoor,
+            BinaryValue::empty(),
+            BinaryValue::empty(),
+        )
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [commitment x, commitment y, board, claimed hits, claimed misses]
+     */
+    pub fn public_inputs(&self) -> [pallas::Base; 5] {
+        let message = self.board.to_fp();
+        let commitment = pedersen_commit(&message, &self.board_commitment_trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        [
+            *coordinates.x(),
+            *coordinates.y(),
+            message,
+            self.hits.to_fp(),
+            self.misses.to_fp(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::gate_names::GATE_REVEAL_CONSISTENCY,
+            circuits::layout::recommended_k,
+            utils::{fixtures::pattern_1, shot::serialize},
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            plonk::Any,
+        },
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn valid_reveal() {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        // hits on the carrier at (3, 3), (3, 5) and the destroyer at (7, 1)
+        let hits = serialize::<3>([3, 3, 7], [3, 5, 1]);
+        // misses at (0, 0), (9, 9)
+        let misses = serialize::<2>([0, 9], [0, 9]);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = RevealCircuit::new(board, trapdoor, hits, misses);
+        assert_eq!(recommended_k(&circuit), REVEAL_CIRCUIT_K);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover = MockProver::run(REVEAL_CIRCUIT_K, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_reveal_contradicts_miss() {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let hits = serialize::<3>([3, 3, 7], [3, 5, 1]);
+        // (6, 1) was answered as a miss but the destroyer occupies it
+        let misses = serialize::<3>([0, 9, 6], [0, 9, 1]);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = RevealCircuit::new(board, trapdoor, hits, misses);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover = MockProver::run(REVEAL_CIRCUIT_K, &circuit, vec![public_inputs]).unwrap();
+        // expect the board AND claimed misses check to fail at cell 16
+        assert_eq!(
+            prover.verify(),
+            Err(vec![VerifyFailure::ConstraintNotSatisfied {
+                constraint: (
+                    (20, GATE_REVEAL_CONSISTENCY).into(),
+                    0,
+                    "claimed miss is not on the board"
+                )
+                    .into(),
+                location: FailureLocation::InRegion {
+                    region: (2, "reveal consistency").into(),
+                    offset: 16
+                },
+                cell_values: vec![
+                    (((Any::Advice, 5).into(), 0).into(), String::from("1")),
+                    (((Any::Advice, 7).into(), 0).into(), String::from("1"))
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn valid_opening() {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = RevealCircuit::opening(&pattern_1(), trapdoor);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover = MockProver::run(REVEAL_CIRCUIT_K, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_opening_wrong_trapdoor() {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // the prior commitment was made with trapdoor
+        let public_inputs = RevealCircuit::opening(&pattern_1(), trapdoor)
+            .public_inputs()
+            .to_vec();
+        // open with a different trapdoor
+        let circuit = RevealCircuit::opening(&pattern_1(), trapdoor + pallas::Scalar::one());
+        let prover = MockProver::run(REVEAL_CIRCUIT_K, &circuit, vec![public_inputs]).unwrap();
+        // expect the re-derived commitment (x, y) to not match the public commitment
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 2).into(),
+                    location: FailureLocation::InRegion {
+                        region: (10, "complete point addition").into(),
+                        offset: 1
+                    }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 3).into(),
+                    location: FailureLocation::InRegion {
+                        region: (10, "complete point addition").into(),
+                        offset: 1
+                    }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Instance, 0).into(),
+                    location: FailureLocation::OutsideRegion { row: 0 }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Instance, 0).into(),
+                    location: FailureLocation::OutsideRegion { row: 1 }
+                }
+            ])
+        );
+    }
+}
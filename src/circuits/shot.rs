@@ -1,22 +1,50 @@
 use {
     crate::{
-        chips::shot::{ShotChip, ShotConfig},
-        utils::binary::BinaryValue,
+        chips::shot::{poseidon_board_commitment, ShotChip, ShotConfig},
+        circuits::layout::recommended_k,
+        utils::{
+            binary::BinaryValue,
+            commitment::{BoardCommitment, ShotCommitment},
+            error::{check_instance_len, BattleshipError, VerifyError},
+            shot::ShotResult,
+        },
     },
     halo2_proofs::{
         arithmetic::FieldExt,
-        circuit::{Layouter, SimpleFloorPlanner},
-        pasta::pallas,
-        plonk::{Circuit, ConstraintSystem, Error},
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::{pallas, vesta},
+        plonk::{
+            create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ConstraintSystem, Error,
+            ProvingKey, SingleVerifier, VerifyingKey,
+        },
+        poly::commitment::Params,
+        transcript::{Blake2bRead, Blake2bWrite, Challenge255},
     },
+    rand::{rngs::OsRng, CryptoRng, RngCore},
 };
 
+// 2^K rows used by the shot circuit
+pub const SHOT_CIRCUIT_K: u32 = 11;
+
+/**
+ * Board commitment a ShotCircuit proves the shot against
+ */
+#[derive(Debug, Clone, Copy)]
+enum CommitmentScheme {
+    // pedersen commitment blinded by the trapdoor, exported as [x, y]
+    Pedersen(pallas::Scalar),
+    // unblinded Poseidon(board state), exported as [hash, 0]
+    Poseidon,
+}
+
 #[derive(Debug, Clone)]
 pub struct ShotCircuit {
     pub board: BinaryValue,
-    pub board_commitment_trapdoor: pallas::Scalar,
+    scheme: CommitmentScheme,
     pub shot: BinaryValue,
     pub hit: BinaryValue,
+    // turn number bound into the proof as a fifth public input, if any
+    pub turn: Option<pallas::Base>,
 }
 
 impl Circuit<pallas::Base> for ShotCircuit {
@@ -25,44 +53,83 @@ impl Circuit<pallas::Base> for ShotCircuit {
 
     fn without_witnesses(&self) -> Self {
         // @TODO FIX
-        ShotCircuit::new(
-            self.board,
-            self.board_commitment_trapdoor,
-            self.shot,
-            self.hit,
-        )
+        self.clone()
     }
 
     fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
-        ShotChip::configure(meta)
+        ShotChip::configure_poseidon(meta)
     }
 
     fn synthesize(
         &self,
         config: Self::Config,
-        layouter: impl Layouter<pallas::Base>,
+        mut layouter: impl Layouter<pallas::Base>,
     ) -> Result<(), Error> {
-        Ok(ShotChip::new(config).synthesize(
-            layouter,
-            self.board,
-            self.board_commitment_trapdoor,
-            self.shot,
-            self.hit,
-        )?)
+        let chip = ShotChip::new(config.clone());
+        match self.scheme {
+            CommitmentScheme::Pedersen(trapdoor) => chip.synthesize(
+                layouter.namespace(|| "shot"),
+                self.board,
+                trapdoor,
+                self.shot,
+                self.hit,
+            )?,
+            CommitmentScheme::Poseidon => chip.synthesize_poseidon(
+                layouter.namespace(|| "shot"),
+                self.board,
+                self.shot,
+                self.hit,
+            )?,
+        }
+        // copy the turn into an advice cell so the proof only verifies for the exported turn
+        if let Some(turn) = self.turn {
+            let turn = layouter.assign_region(
+                || "bind turn",
+                |mut region| {
+                    region.assign_advice(|| "turn", config.advice[0], 0, || Value::known(turn))
+                },
+            )?;
+            layouter.constrain_instance(turn.cell(), config.instance, 4)?;
+        }
+        Ok(())
     }
 }
 
 impl ShotCircuit {
+    // number of public inputs exported by the circuit: [commitment x, commitment y, shot, hit]
+    pub const EXPECTED_INSTANCE_LEN: usize = 4;
+    // number of public inputs exported by a circuit from new_with_turn(), with the turn appended
+    pub const EXPECTED_INSTANCE_LEN_WITH_TURN: usize = 5;
+
     /**
      * Construct a new shot circuit to evaluate whether a valid shot hits a ship
      *
      * @param board - private board placement
      * @param board_commitment_trapdoor - trapdoor to the board commitment
      * @param shot - x, y coordinates serialized into a shot commitment
-     * @param hit - assertion that the shot either hits or misses the decomposed board (constrained 0 or 1)
-     * @return - instantiated BoardCircuit object containing BoardGadget
+     * @param hit - assertion that the shot either hits or misses the decomposed board
+     * @return - instantiated ShotCircuit object
      */
     pub fn new(
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: ShotResult,
+    ) -> ShotCircuit {
+        ShotCircuit::new_raw(board, board_commitment_trapdoor, shot, hit.into())
+    }
+
+    /**
+     * Construct a new shot circuit witnessing an arbitrary hit assertion
+     * @dev escape hatch for adversarial tests; a non-boolean hit is rejected by the circuit
+     *
+     * @param board - private board placement
+     * @param board_commitment_trapdoor - trapdoor to the board commitment
+     * @param shot - x, y coordinates serialized into a shot commitment
+     * @param hit - raw hit assertion witnessed as is (constrained 0 or 1)
+     * @return - instantiated ShotCircuit object
+     */
+    pub fn new_raw(
         board: BinaryValue,
         board_commitment_trapdoor: pallas::Scalar,
         shot: BinaryValue,
@@ -70,23 +137,370 @@ impl ShotCircuit {
     ) -> ShotCircuit {
         ShotCircuit {
             board,
-            board_commitment_trapdoor,
+            scheme: CommitmentScheme::Pedersen(board_commitment_trapdoor),
             shot,
             hit,
+            turn: None,
         }
     }
+
+    /**
+     * Construct a new shot circuit against a pedersen commitment to the board
+     * @dev same circuit as new(), named next to with_poseidon(); prove with keys from keygen()
+     *
+     * @param board - private board placement
+     * @param board_commitment_trapdoor - trapdoor to the pedersen board commitment
+     * @param shot - x, y coordinates serialized into a shot commitment
+     * @param hit - assertion that the shot either hits or misses the decomposed board
+     * @return - instantiated ShotCircuit object exporting [commitment x, commitment y, shot, hit]
+     */
+    pub fn with_pedersen(
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: ShotResult,
+    ) -> ShotCircuit {
+        ShotCircuit::new(board, board_commitment_trapdoor, shot, hit)
+    }
+
+    /**
+     * Construct a new shot circuit against an unblinded Poseidon commitment to the board
+     * @dev exports [Poseidon(board state), 0, shot, hit] so the instance keeps the pedersen layout;
+     *      prove with keys from keygen_poseidon()
+     *
+     * @param board - private board placement
+     * @param shot - x, y coordinates serialized into a shot commitment
+     * @param hit - assertion that the shot either hits or misses the decomposed board
+     * @return - instantiated ShotCircuit object exporting [Poseidon(board state), 0, shot, hit]
+     */
+    pub fn with_poseidon(board: BinaryValue, shot: BinaryValue, hit: ShotResult) -> ShotCircuit {
+        ShotCircuit {
+            board,
+            scheme: CommitmentScheme::Poseidon,
+            shot,
+            hit: hit.into(),
+            turn: None,
+        }
+    }
+
+    /**
+     * Trapdoor of the board commitment
+     *
+     * @return - the pedersen trapdoor, or None for a circuit from with_poseidon()
+     */
+    pub fn board_commitment_trapdoor(&self) -> Option<pallas::Scalar> {
+        match self.scheme {
+            CommitmentScheme::Pedersen(trapdoor) => Some(trapdoor),
+            CommitmentScheme::Poseidon => None,
+        }
+    }
+
+    /**
+     * Construct a new shot circuit that also binds the turn the shot was fired on
+     * @dev the turn is exported as a fifth public input; prove with keys from keygen_with_turn()
+     *
+     * @param board - private board placement
+     * @param board_commitment_trapdoor - trapdoor to the board commitment
+     * @param shot - x, y coordinates serialized into a shot commitment
+     * @param hit - assertion that the shot either hits or misses the decomposed board
+     * @param turn - index of the shot in the game, starting at 0
+     * @return - instantiated ShotCircuit object exporting the turn
+     */
+    pub fn new_with_turn(
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: ShotResult,
+        turn: u64,
+    ) -> ShotCircuit {
+        ShotCircuit {
+            turn: Some(pallas::Base::from(turn)),
+            ..ShotCircuit::new(board, board_commitment_trapdoor, shot, hit)
+        }
+    }
+
+    /**
+     * Build the public values of a shot proof
+     * @dev the typed commitments keep a shot from being passed where the board commitment belongs
+     *
+     * ```compile_fail
+     * # use battlezips_halo2::{utils::{binary::BinaryValue, commitment::*}, ShotCircuit, ShotResult};
+     * # use halo2_proofs::pasta::pallas;
+     * # let board = BoardCommitment::commit(&BinaryValue::empty(), &pallas::Scalar::one());
+     * let shot = ShotCommitment::new(3, 5).unwrap();
+     * ShotCircuit::instance(&shot, &board, ShotResult::Hit);
+     * ```
+     *
+     * @param board - the public pedersen commitment to the defender's board
+     * @param shot - the serialized shot
+     * @param hit - whether the shot hits the board
+     * @return - [commitment x, commitment y, shot, hit]
+     */
+    pub fn instance(
+        board: &BoardCommitment,
+        shot: &ShotCommitment,
+        hit: ShotResult,
+    ) -> [pallas::Base; 4] {
+        [board.0[0], board.0[1], shot.to_fp(), hit.to_fp()]
+    }
+
+    /**
+     * Build the public values of a shot proof bound to a turn
+     *
+     * @param board - the public pedersen commitment to the defender's board
+     * @param shot - the serialized shot
+     * @param hit - whether the shot hits the board
+     * @param turn - index of the shot in the game
+     * @return - [commitment x, commitment y, shot, hit, turn]
+     */
+    pub fn instance_with_turn(
+        board: &BoardCommitment,
+        shot: &ShotCommitment,
+        hit: ShotResult,
+        turn: u64,
+    ) -> [pallas::Base; 5] {
+        let [x, y, shot, hit] = ShotCircuit::instance(board, shot, hit);
+        [x, y, shot, hit, pallas::Base::from(turn)]
+    }
+
+    /**
+     * Compute the public values this circuit exports, excluding any turn
+     * @dev panics on an identity board commitment; provers use try_public_inputs()
+     *
+     * @return - [commitment x, commitment y, shot, hit]
+     */
+    pub fn public_inputs(&self) -> [pallas::Base; 4] {
+        self.try_public_inputs().unwrap()
+    }
+
+    /**
+     * Compute the public values this circuit exports, excluding any turn, rejecting an identity
+     * board commitment
+     *
+     * @return - [commitment x, commitment y, shot, hit] ([hash, 0, shot, hit] for Poseidon), or
+     *           IdentityCommitment
+     */
+    pub fn try_public_inputs(&self) -> Result<[pallas::Base; 4], BattleshipError> {
+        // built from the raw witness so circuits from new_raw() export what they assert
+        let commitment = match self.scheme {
+            CommitmentScheme::Pedersen(trapdoor) => {
+                BoardCommitment::try_commit(&self.board, &trapdoor)?.0
+            }
+            CommitmentScheme::Poseidon => [
+                poseidon_board_commitment(self.board.to_fp()),
+                pallas::Base::zero(),
+            ],
+        };
+        Ok([
+            commitment[0],
+            commitment[1],
+            self.shot.to_fp(),
+            self.hit.to_fp(),
+        ])
+    }
+
+    /**
+     * Compute the smallest k such that the shot circuit fits in 2^k rows
+     * @dev lays out the keygen circuit with the mock prover rather than counting rows by hand
+     *
+     * @return - the minimal k to pass to Params::new() for any ShotCircuit
+     */
+    pub fn min_k() -> u32 {
+        // ShotChip witnesses the commitment coordinates, so the commitment must not be the identity
+        recommended_k(&ShotCircuit::new(
+            BinaryValue::empty(),
+            pallas::Scalar::one(),
+            BinaryValue::empty(),
+            ShotResult::Miss,
+        ))
+    }
+
+    /**
+     * Generate the proving key for the shot circuit
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @return - the proving key for any ShotCircuit
+     */
+    pub fn keygen(params: &Params<vesta::Affine>) -> Result<ProvingKey<vesta::Affine>, Error> {
+        // ShotChip witnesses the commitment coordinates, so the keygen commitment must not be the identity
+        let empty_circuit = ShotCircuit::new(
+            BinaryValue::empty(),
+            pallas::Scalar::one(),
+            BinaryValue::empty(),
+            ShotResult::Miss,
+        );
+        let vk = keygen_vk(params, &empty_circuit)?;
+        keygen_pk(params, vk, &empty_circuit)
+    }
+
+    /**
+     * Generate the proving key for shot circuits built with with_poseidon()
+     * @dev the Poseidon commitment lays out different regions, so this key differs from keygen()
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @return - the proving key for any ShotCircuit committing to the board with Poseidon
+     */
+    pub fn keygen_poseidon(
+        params: &Params<vesta::Affine>,
+    ) -> Result<ProvingKey<vesta::Affine>, Error> {
+        let empty_circuit = ShotCircuit::with_poseidon(
+            BinaryValue::empty(),
+            BinaryValue::empty(),
+            ShotResult::Miss,
+        );
+        let vk = keygen_vk(params, &empty_circuit)?;
+        keygen_pk(params, vk, &empty_circuit)
+    }
+
+    /**
+     * Generate the proving key for shot circuits built with new_with_turn()
+     * @dev the turn copy constraint changes the permutation, so this key differs from keygen()
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @return - the proving key for any ShotCircuit exporting a turn
+     */
+    pub fn keygen_with_turn(
+        params: &Params<vesta::Affine>,
+    ) -> Result<ProvingKey<vesta::Affine>, Error> {
+        let empty_circuit = ShotCircuit::new_with_turn(
+            BinaryValue::empty(),
+            pallas::Scalar::one(),
+            BinaryValue::empty(),
+            ShotResult::Miss,
+            0,
+        );
+        let vk = keygen_vk(params, &empty_circuit)?;
+        keygen_pk(params, vk, &empty_circuit)
+    }
+
+    /**
+     * Prove the result of a shot against a committed board
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @param pk - proving key from keygen(), or keygen_with_turn() if the circuit has a turn
+     * @return - the serialized proof
+     */
+    pub fn prove(
+        &self,
+        params: &Params<vesta::Affine>,
+        pk: &ProvingKey<vesta::Affine>,
+    ) -> Result<Vec<u8>, Error> {
+        self.prove_with_rng(params, pk, OsRng)
+    }
+
+    /**
+     * Prove the result of a shot drawing the proof's blinding factors from a given rng
+     * @dev the same rng state always yields the same proof bytes; only seed it for tests
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @param pk - proving key from keygen(), or keygen_with_turn() if the circuit has a turn
+     * @param rng - source of the proof's blinding factors
+     * @return - the serialized proof
+     */
+    pub fn prove_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        params: &Params<vesta::Affine>,
+        pk: &ProvingKey<vesta::Affine>,
+        rng: R,
+    ) -> Result<Vec<u8>, Error> {
+        let mut public_inputs = self.try_public_inputs()?.to_vec();
+        public_inputs.extend(self.turn);
+        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+        create_proof(
+            params,
+            pk,
+            std::slice::from_ref(self),
+            &[&[&public_inputs]],
+            rng,
+            &mut transcript,
+        )?;
+        Ok(transcript.finalize())
+    }
+
+    /**
+     * Verify a proof of the result of a shot against a committed board
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @param vk - verifying key for the shot circuit
+     * @param public_inputs - [commitment x, commitment y, shot, hit]
+     * @param proof - the serialized proof generated by prove()
+     * @return - Ok if the proof is valid for the public inputs, or InstanceLengthMismatch if there
+     *           are not EXPECTED_INSTANCE_LEN public inputs
+     */
+    pub fn verify(
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+        public_inputs: &[pallas::Base],
+        proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        check_instance_len(ShotCircuit::EXPECTED_INSTANCE_LEN, public_inputs)?;
+        ShotCircuit::verify_instance(params, vk, public_inputs, proof)
+    }
+
+    /**
+     * Verify a proof of the result of a shot bound to a turn
+     *
+     * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+     * @param vk - verifying key generated by keygen_with_turn()
+     * @param public_inputs - [commitment x, commitment y, shot, hit, turn]
+     * @param proof - the serialized proof generated by prove()
+     * @return - Ok if the proof is valid for the public inputs, or InstanceLengthMismatch if there
+     *           are not EXPECTED_INSTANCE_LEN_WITH_TURN public inputs
+     */
+    pub fn verify_with_turn(
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+        public_inputs: &[pallas::Base],
+        proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        check_instance_len(ShotCircuit::EXPECTED_INSTANCE_LEN_WITH_TURN, public_inputs)?;
+        ShotCircuit::verify_instance(params, vk, public_inputs, proof)
+    }
+
+    fn verify_instance(
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+        public_inputs: &[pallas::Base],
+        proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        let strategy = SingleVerifier::new(params);
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof);
+        Ok(verify_proof(
+            params,
+            vk,
+            strategy,
+            &[&[public_inputs]],
+            &mut transcript,
+        )?)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use {
         super::*,
-        crate::utils::{
-            binary::U256, board::Board, deck::Deck, pedersen::pedersen_commit,
-            ship::DEFAULT_WITNESS_OPTIONS, shot::serialize,
+        crate::{
+            chips::{
+                bitify::Num2BitsChip,
+                columns::ColumnSet,
+                gate_names::{GATE_BOOLEAN_HIT_ASSERTION, GATE_SHOT_RUNNING_SUM_OUTPUT},
+                shot::{compute_shot_trace, HitMode, ShotInstructions},
+            },
+            utils::{
+                binary::U256,
+                board::{Board, BOARD_SIZE},
+                commitment::{pack, unpack},
+                deck::Deck,
+                error::BattleshipError,
+                fixtures::{pattern_1, pattern_2},
+                pedersen::pedersen_commit,
+                ship::DEFAULT_WITNESS_OPTIONS,
+                shot::{deserialize, serialize},
+            },
         },
         halo2_proofs::{
             arithmetic::{CurveAffine, Field},
+            circuit::Value,
             dev::{FailureLocation, MockProver, VerifyFailure},
             pasta::{group::Curve, pallas, vesta},
             plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Any, SingleVerifier},
@@ -99,21 +513,15 @@ mod test {
     #[test]
     fn valid_hit_0() {
         // construct valid battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (3, 5) into `53u256`
         let shot = serialize::<1>([3], [5]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = board.state(DEFAULT_WITNESS_OPTIONS).to_fp();
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -140,21 +548,15 @@ mod test {
     #[test]
     fn valid_hit_1() {
         // construct valid battleship board pattern 2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // serialize a shot at (9, 8) into `89u256`
         let shot = serialize::<1>([9], [8]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -166,8 +568,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -180,21 +582,15 @@ mod test {
     #[test]
     fn valid_miss_0() {
         // construct valid battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (4, 3) into `34u256`
         let shot = serialize::<1>([4], [3]);
-        // assert a miss and wrap in u256
-        let hit = BinaryValue::from_u8(0);
+        // assert a miss
+        let hit = ShotResult::Miss;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -206,8 +602,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -220,21 +616,15 @@ mod test {
     #[test]
     fn valid_miss_1() {
         // construct battleship board pattern 2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // serialize a shot at (3, 3) into `33u256`
         let shot = serialize::<1>([3], [3]);
-        // assert a miss and wrap in u256
-        let hit = BinaryValue::from_u8(0);
+        // assert a miss
+        let hit = ShotResult::Miss;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -246,8 +636,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -257,16 +647,266 @@ mod test {
         assert_eq!(prover.verify(), Ok(()));
     }
 
+    // board with a ship along every edge, covering the boundary cells patterns 1 and 2 leave open
+    const EDGE_PATTERN: [Option<(u8, u8, bool)>; 5] = [
+        Some((5, 0, false)),
+        Some((0, 0, true)),
+        Some((9, 4, true)),
+        Some((5, 9, false)),
+        Some((0, 8, true)),
+    ];
+
+    // every corner followed by the middle of every edge
+    const BOUNDARY_CELLS: [(u8, u8); 8] = [
+        (0, 0),
+        (9, 0),
+        (0, 9),
+        (9, 9),
+        (5, 0),
+        (0, 5),
+        (9, 5),
+        (5, 9),
+    ];
+
+    /**
+     * Prove the honest result of a shot and check that the opposite assertion fails
+     *
+     * @param board - board the shot is fired at
+     * @param x - horizontal coordinate of the shot
+     * @param y - vertical coordinate of the shot
+     * @return - whether the shot hit
+     */
+    fn prove_boundary_shot(board: &Board, x: u8, y: u8) -> bool {
+        let hit = board.hit(x, y).unwrap();
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        for assertion in [hit, !hit] {
+            let circuit = ShotCircuit::new(
+                board.state(DEFAULT_WITNESS_OPTIONS),
+                trapdoor,
+                serialize::<1>([x], [y]),
+                ShotResult::from(assertion),
+            );
+            let prover =
+                MockProver::run(11, &circuit, vec![circuit.public_inputs().to_vec()]).unwrap();
+            assert_eq!(
+                prover.verify().is_ok(),
+                assertion == hit,
+                "shot at ({}, {}) asserting hit = {}",
+                x,
+                y,
+                assertion
+            );
+        }
+        hit
+    }
+
+    #[test]
+    fn valid_boundary_hits() {
+        // between them the three boards place a ship on every boundary cell
+        let boards = [
+            pattern_1(),
+            pattern_2(),
+            Board::from(&Deck::from(EDGE_PATTERN)),
+        ];
+        assert!(boards[2].is_valid().is_ok());
+        for (x, y) in BOUNDARY_CELLS {
+            let hits = boards
+                .iter()
+                .filter(|board| board.hit(x, y).unwrap())
+                .collect::<Vec<_>>();
+            assert!(!hits.is_empty(), "no board places a ship on ({}, {})", x, y);
+            for board in hits {
+                assert!(prove_boundary_shot(board, x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn valid_boundary_misses() {
+        // every boundary cell is open on pattern 1 or pattern 2
+        let boards = [pattern_1(), pattern_2()];
+        for (x, y) in BOUNDARY_CELLS {
+            let misses = boards
+                .iter()
+                .filter(|board| !board.hit(x, y).unwrap())
+                .collect::<Vec<_>>();
+            assert!(!misses.is_empty(), "every board places a ship on ({}, {})", x, y);
+            for board in misses {
+                assert!(!prove_boundary_shot(board, x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn valid_max_bit_index() {
+        // a shot at (9, 9) flips bit 99, the highest bit on the board
+        let shot = serialize::<1>([9], [9]);
+        assert_eq!(shot.value.first_one(), Some(BOARD_SIZE - 1));
+        assert_eq!(deserialize(shot), Ok((9, 9)));
+        // pattern 2 places its battleship over (9, 9) and pattern 1 leaves it open
+        assert!(prove_boundary_shot(&pattern_2(), 9, 9));
+        assert!(!prove_boundary_shot(&pattern_1(), 9, 9));
+    }
+
+    #[test]
+    fn invalid_off_board_shot() {
+        // bit 100 is the first cell past (9, 9), off the board
+        let mut value = U256::ZERO;
+        value.set(BOARD_SIZE, true);
+        let shot = BinaryValue::new_unchecked(value);
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = ShotCircuit::new(board, trapdoor, shot, ShotResult::Miss);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover = MockProver::run(SHOT_CIRCUIT_K, &circuit, vec![public_inputs]).unwrap();
+        // the 100 bit decomposition leaves no set bit for the shot index to bound
+        let failures = prover.verify().unwrap_err();
+        assert!(failures
+            .iter()
+            .any(|failure| format!("{}", failure).contains("Shot only fires at one board cell")));
+    }
+
+    #[test]
+    fn cross_scheme_consistency() {
+        // both commitment schemes accept exactly the honest hit assertion for the same shots
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        for board in [pattern_1(), pattern_2()] {
+            let state = board.state(DEFAULT_WITNESS_OPTIONS);
+            for (x, y) in [(3, 5), (0, 0), (9, 9)] {
+                let hit = board.hit(x, y).unwrap();
+                let shot = serialize::<1>([x], [y]);
+                for assertion in [hit, !hit] {
+                    let result = ShotResult::from(assertion);
+                    let circuits = [
+                        ShotCircuit::with_pedersen(state, trapdoor, shot, result),
+                        ShotCircuit::with_poseidon(state, shot, result),
+                    ];
+                    let verified = circuits
+                        .iter()
+                        .map(|circuit| {
+                            let public_inputs = circuit.public_inputs().to_vec();
+                            MockProver::run(SHOT_CIRCUIT_K, circuit, vec![public_inputs])
+                                .unwrap()
+                                .verify()
+                                .is_ok()
+                        })
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        verified,
+                        vec![assertion == hit; 2],
+                        "shot at ({}, {}) asserting hit = {}",
+                        x,
+                        y,
+                        assertion
+                    );
+                    // the instances only differ in the exported commitment
+                    let pedersen = circuits[0].public_inputs();
+                    let poseidon = circuits[1].public_inputs();
+                    assert_eq!(pedersen[2..], poseidon[2..]);
+                    let commitment = poseidon_board_commitment(state.to_fp());
+                    assert_eq!(poseidon[..2], [commitment, pallas::Base::zero()]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_poseidon_commitment() {
+        // (3, 5) hits pattern 1, which does not open a Poseidon commitment to pattern 2
+        let circuit = ShotCircuit::with_poseidon(
+            pattern_1().state(DEFAULT_WITNESS_OPTIONS),
+            serialize::<1>([3], [5]),
+            ShotResult::Hit,
+        );
+        assert_eq!(circuit.board_commitment_trapdoor(), None);
+        let mut public_inputs = circuit.public_inputs().to_vec();
+        public_inputs[0] =
+            poseidon_board_commitment(pattern_2().state(DEFAULT_WITNESS_OPTIONS).to_fp());
+        let prover = MockProver::run(SHOT_CIRCUIT_K, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover
+            .verify()
+            .unwrap_err()
+            .contains(&VerifyFailure::Permutation {
+                column: (Any::Instance, 0).into(),
+                location: FailureLocation::OutsideRegion { row: 0 }
+            }));
+    }
+
+    #[test]
+    fn valid_origin_hit() {
+        // a shot at (0, 0) flips bit 0, the first row of both running sums
+        let shot = serialize::<1>([0], [0]);
+        assert_eq!(shot.value.first_one(), Some(0));
+        // pattern 2 places its cruiser horizontally from (0, 0)
+        assert!(prove_boundary_shot(&pattern_2(), 0, 0));
+    }
+
+    #[test]
+    fn valid_origin_miss() {
+        // pattern 1 leaves (0, 0) open
+        assert!(!prove_boundary_shot(&pattern_1(), 0, 0));
+    }
+
+    #[test]
+    fn identity_commitment_errors() {
+        // an empty board committed with a zero trapdoor has no coordinates to witness
+        let circuit = ShotCircuit::new(
+            BinaryValue::empty(),
+            pallas::Scalar::zero(),
+            serialize::<1>([0], [0]),
+            ShotResult::Miss,
+        );
+        let trapdoor = circuit.board_commitment_trapdoor().unwrap();
+        assert_eq!(
+            BoardCommitment::try_commit(&circuit.board, &trapdoor),
+            Err(BattleshipError::IdentityCommitment)
+        );
+        assert_eq!(
+            circuit.try_public_inputs(),
+            Err(BattleshipError::IdentityCommitment)
+        );
+        // synthesis fails cleanly instead of panicking
+        let public_outputs = vec![pallas::Base::zero(); 4];
+        assert!(matches!(
+            MockProver::run(11, &circuit, vec![public_outputs]),
+            Err(Error::Synthesis)
+        ));
+        // and so does proving
+        let params: Params<vesta::Affine> = Params::new(ShotCircuit::min_k());
+        let pk = ShotCircuit::keygen(&params).unwrap();
+        assert!(matches!(circuit.prove(&params, &pk), Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn board_hit_matches_circuit() {
+        // construct valid battleship board pattern 1 and commit to it once
+        let board = pattern_1();
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let commitment = pedersen_commit(&state.to_fp(), &trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        for y in 0..10 {
+            for x in 0..10 {
+                // the circuit must accept the hit assertion computed by Board::hit
+                let shot = serialize::<1>([x], [y]);
+                let hit = ShotResult::from(board.hit(x, y).unwrap());
+                let public_outputs = vec![
+                    coordinates.x().to_owned(),
+                    coordinates.y().to_owned(),
+                    shot.to_fp(),
+                    hit.to_fp(),
+                ];
+                let circuit = ShotCircuit::new(state, trapdoor, shot, hit);
+                let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
+                assert_eq!(prover.verify(), Ok(()), "shot at ({}, {})", x, y);
+            }
+        }
+    }
+
     #[test]
     fn invalid_non_boolean_hit_assertion() {
         // construct battleship board pattern 2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // serialize a shot at (9, 8) into `89u256`
         let shot = serialize::<1>([9], [8]);
         // assert a non-boolean value and wrap in u256
@@ -274,7 +914,7 @@ mod test {
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -286,11 +926,12 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
-        let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
+        let circuit =
+            ShotCircuit::new_raw(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
         // prove a non-boolean hit assertions will fail verification
         let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
         // expect failure
@@ -300,9 +941,9 @@ mod test {
                 VerifyFailure::ConstraintNotSatisfied {
                     // check violation of boolean input constraint
                     constraint: (
-                        (21, "boolean hit assertion").into(),
+                        (21, GATE_BOOLEAN_HIT_ASSERTION).into(),
                         0,
-                        "asserted hit value is boolean"
+                        "value is boolean"
                     )
                         .into(),
                     location: FailureLocation::InRegion {
@@ -314,13 +955,13 @@ mod test {
                 VerifyFailure::ConstraintNotSatisfied {
                     // counted counted hit # does not match asserted hit #
                     constraint: (
-                        (23, "constrain shot running sum output").into(),
+                        (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                         1,
                         "Public hit assertion matches private witness"
                     )
                         .into(),
                     location: FailureLocation::InRegion {
-                        region: (4, "shot running sum output checks").into(),
+                        region: (3, "shot running sum output checks").into(),
                         offset: 0
                     },
                     cell_values: vec![
@@ -332,24 +973,324 @@ mod test {
         );
     }
 
+    // shot circuit that counts the hits of a salvo instead of asserting a boolean hit
+    #[derive(Debug, Clone)]
+    struct SalvoCircuit(ShotCircuit);
+
+    impl Circuit<pallas::Base> for SalvoCircuit {
+        type Config = ShotConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            ShotChip::configure(meta, HitMode::Count)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            ShotChip::new(config).synthesize(
+                layouter,
+                self.0.board,
+                self.0.board_commitment_trapdoor().unwrap(),
+                self.0.shot,
+                self.0.hit,
+            )
+        }
+    }
+
+    /**
+     * Build a salvo at (3, 3) and (3, 4) against board pattern 1, hitting the carrier twice
+     *
+     * @param hit - the asserted hit value
+     * @return - the shot circuit and its public outputs
+     */
+    fn two_hit_salvo(hit: u8) -> (ShotCircuit, Vec<pallas::Base>) {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let shot = serialize::<2>([3, 3], [3, 4]);
+        let hit = BinaryValue::from_u8(hit);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let commitment = pedersen_commit(&board.to_fp(), &trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        let public_outputs = vec![
+            coordinates.x().to_owned(),
+            coordinates.y().to_owned(),
+            shot.to_fp(),
+            hit.to_fp(),
+        ];
+        (ShotCircuit::new_raw(board, trapdoor, shot, hit), public_outputs)
+    }
+
+    #[test]
+    fn invalid_boolean_mode_salvo() {
+        // boolean mode rejects both the hit count and the second shot
+        let (circuit, public_outputs) = two_hit_salvo(2);
+        let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (21, GATE_BOOLEAN_HIT_ASSERTION).into(),
+                        0,
+                        "value is boolean"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (0, "load private ShotChip advice values").into(),
+                        offset: 4
+                    },
+                    cell_values: vec![(((Any::Advice, 4).into(), 0).into(), String::from("0x2"))]
+                },
+                VerifyFailure::ConstraintNotSatisfied {
+                    constraint: (
+                        (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
+                        0,
+                        "Shot only fires at one board cell"
+                    )
+                        .into(),
+                    location: FailureLocation::InRegion {
+                        region: (3, "shot running sum output checks").into(),
+                        offset: 0
+                    },
+                    cell_values: vec![(((Any::Advice, 6).into(), 0).into(), String::from("0x2"))]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn valid_count_mode_salvo() {
+        // count mode exposes the two hits of the salvo as the public hit value
+        let (circuit, public_outputs) = two_hit_salvo(2);
+        assert_eq!(public_outputs[3], pallas::Base::from(2));
+        let prover = MockProver::run(11, &SalvoCircuit(circuit), vec![public_outputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // the hit count must still match the hits against the board
+        let (circuit, public_outputs) = two_hit_salvo(1);
+        let prover = MockProver::run(11, &SalvoCircuit(circuit), vec![public_outputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    // shot circuit exporting the shot and hit as one packed public output
+    #[derive(Debug, Clone)]
+    struct PackedShotCircuit(ShotCircuit);
+
+    impl Circuit<pallas::Base> for PackedShotCircuit {
+        type Config = ShotConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            ShotChip::configure_packed(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            ShotChip::new(config).synthesize(
+                layouter,
+                self.0.board,
+                self.0.board_commitment_trapdoor().unwrap(),
+                self.0.shot,
+                self.0.hit,
+            )
+        }
+    }
+
+    /**
+     * Build a packed shot at (3, 5) against board pattern 1, hitting the carrier
+     *
+     * @param hit - the asserted hit value
+     * @return - the packed shot circuit and its public outputs
+     */
+    fn packed_shot(hit: bool) -> (PackedShotCircuit, Vec<pallas::Base>) {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let commitment = BoardCommitment::commit(&board, &trapdoor);
+        let public_outputs = vec![commitment.0[0], commitment.0[1], pack(3, 5, hit).unwrap()];
+        let circuit = ShotCircuit::new(
+            board,
+            trapdoor,
+            serialize::<1>([3], [5]),
+            ShotResult::from(hit),
+        );
+        (PackedShotCircuit(circuit), public_outputs)
+    }
+
+    #[test]
+    fn valid_packed_shot() {
+        let (circuit, public_outputs) = packed_shot(true);
+        assert_eq!(unpack(public_outputs[2]), Some((3, 5, true)));
+        let k = recommended_k(&circuit);
+        let prover = MockProver::run(k, &circuit, vec![public_outputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_packed_shot_claims_miss() {
+        // packing the false hit assertion consistently does not hide it from the running sum
+        let (circuit, public_outputs) = packed_shot(false);
+        let k = recommended_k(&circuit);
+        let prover = MockProver::run(k, &circuit, vec![public_outputs]).unwrap();
+        let failures = prover.verify().unwrap_err();
+        assert_eq!(failures.len(), 1);
+        match &failures[0] {
+            VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                let constraint = format!("{}", constraint);
+                assert!(constraint.contains(GATE_SHOT_RUNNING_SUM_OUTPUT));
+                assert!(constraint.contains("Public hit assertion matches private witness"));
+            }
+            failure => panic!("Unexpected failure {:?}", failure),
+        }
+        // a packed output that disagrees with the witnessed hit is rejected at the instance
+        let (circuit, mut public_outputs) = packed_shot(true);
+        public_outputs[2] = pack(3, 5, false).unwrap();
+        let prover = MockProver::run(k, &circuit, vec![public_outputs]).unwrap();
+        assert!(prover
+            .verify()
+            .unwrap_err()
+            .contains(&VerifyFailure::Permutation {
+                column: (Any::Instance, 0).into(),
+                location: FailureLocation::OutsideRegion { row: 2 }
+            }));
+    }
+
+    #[test]
+    fn constraint_system_shape() {
+        // any change to these counts changes the shot verifying key; update tests/golden alongside
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        ShotChip::configure(&mut meta, HitMode::Boolean);
+        // 10 shared advice columns + input
+        assert_eq!(meta.num_advice_columns(), 11);
+        // 8 shared fixed columns + range check table + ecc fixed_z
+        assert_eq!(meta.num_fixed_columns(), 10);
+        assert_eq!(meta.num_instance_columns(), 1);
+        // shot 3 + num2bits 2 + ecc 17 + range check 3 + shot index 2
+        assert_eq!(meta.num_selectors(), 27);
+        // num2bits 2 + ecc and range check 19 + boolean hit, running sum row and output
+        // + shot index sum and bound
+        assert_eq!(meta.gates().len(), 26);
+        // 10 bit range check table, shared by the ecc chip and the shot index
+        assert_eq!(meta.lookups().len(), 1);
+        // counting hits drops the boolean hit gate and the shot index
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        ShotChip::configure(&mut meta, HitMode::Count);
+        assert_eq!(meta.num_selectors(), 25);
+        assert_eq!(meta.gates().len(), 23);
+    }
+
+    #[test]
+    fn config_column_map() {
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        let config = ShotChip::configure(&mut meta, HitMode::Boolean);
+        let column_map = config.column_map();
+        // every advice column is named exactly once, in column order
+        assert_eq!(column_map.len(), config.advice.len());
+        for (name, column) in ShotConfig::debug_column_names().iter().zip(config.advice) {
+            assert_eq!(column_map[name], column);
+        }
+        // both decompositions write bits into the board bits column
+        for num2bits in config.num2bits.iter() {
+            assert_eq!(num2bits.bits, column_map["board_bits"]);
+        }
+    }
+
+    // two shot chips configured over the same advice and fixed columns
+    #[derive(Debug, Clone)]
+    struct SharedColumnsShotCircuit([ShotCircuit; 2]);
+
+    impl Circuit<pallas::Base> for SharedColumnsShotCircuit {
+        type Config = [ShotConfig; 2];
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let (columns, _) = ColumnSet::configure(meta, 0);
+            // each shot loads its own lookup table and exports to its own instance column
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            let second = ColumnSet {
+                instance,
+                table_idx: meta.lookup_table_column(),
+                ..columns
+            };
+            [
+                ShotChip::configure_with_columns(meta, columns, HitMode::Boolean),
+                ShotChip::configure_with_columns(meta, second, HitMode::Boolean),
+            ]
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            for (i, (config, shot)) in config.into_iter().zip(self.0.iter()).enumerate() {
+                ShotChip::new(config).synthesize(
+                    layouter.namespace(|| format!("shot {}", i)),
+                    shot.board,
+                    shot.board_commitment_trapdoor().unwrap(),
+                    shot.shot,
+                    shot.hit,
+                )?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn valid_shared_columns() {
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        SharedColumnsShotCircuit::configure(&mut meta);
+        // the second shot chip allocates no advice columns of its own
+        assert_eq!(meta.num_advice_columns(), 10);
+        assert_eq!(meta.num_instance_columns(), 2);
+        // (3, 5) hits the carrier of pattern 1 and (0, 0) is open water
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let commitment = BoardCommitment::commit(&board, &trapdoor);
+        let shots = [
+            (ShotCommitment::new(3, 5).unwrap(), ShotResult::Hit),
+            (ShotCommitment::new(0, 0).unwrap(), ShotResult::Miss),
+        ];
+        let circuit = SharedColumnsShotCircuit(
+            shots.map(|(shot, hit)| ShotCircuit::new(board, trapdoor, shot.0, hit)),
+        );
+        let public_outputs = shots
+            .iter()
+            .map(|(shot, hit)| ShotCircuit::instance(&commitment, shot, *hit).to_vec())
+            .collect();
+        let k = recommended_k(&circuit);
+        let prover = MockProver::run(k, &circuit, public_outputs).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
     #[test]
     fn invalid_assert_hit_when_miss() {
         // construct battleship board pattern 2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // serialize a shot at (8, 8) into `88u256`
         let shot = serialize::<1>([8], [8]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -361,8 +1302,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -374,13 +1315,13 @@ mod test {
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 // fail constraint: count 0 hits but 1 inputted
                 constraint: (
-                    (23, "constrain shot running sum output").into(),
+                    (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                     1,
                     "Public hit assertion matches private witness"
                 )
                     .into(),
                 location: FailureLocation::InRegion {
-                    region: (4, "shot running sum output checks").into(),
+                    region: (3, "shot running sum output checks").into(),
                     offset: 0
                 },
                 cell_values: vec![
@@ -394,21 +1335,15 @@ mod test {
     #[test]
     fn invalid_assert_miss_when_hit() {
         // construct battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (7, 1) into `17u256`
         let shot = serialize::<1>([7], [1]);
-        // assert a miss and wrap in u256
-        let hit = BinaryValue::from_u8(0);
+        // assert a miss
+        let hit = ShotResult::Miss;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -420,8 +1355,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -433,13 +1368,13 @@ mod test {
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 // fail constraint: count 1 hits but 0 inputted
                 constraint: (
-                    (23, "constrain shot running sum output").into(),
+                    (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                     1,
                     "Public hit assertion matches private witness"
                 )
                     .into(),
                 location: FailureLocation::InRegion {
-                    region: (4, "shot running sum output checks").into(),
+                    region: (3, "shot running sum output checks").into(),
                     offset: 0
                 },
                 cell_values: vec![
@@ -453,21 +1388,15 @@ mod test {
     #[test]
     fn invalid_no_shot() {
         // construct battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // set the shot commitment to be `0u256`
         let shot = BinaryValue::empty();
-        // assert a miss and wrap in u256
-        let hit = BinaryValue::from_u8(0);
+        // assert a miss
+        let hit = ShotResult::Miss;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -479,8 +1408,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -492,13 +1421,13 @@ mod test {
             Err(vec![VerifyFailure::ConstraintNotSatisfied {
                 // fail constraint: 0 shots counted when 1 expected
                 constraint: (
-                    (23, "constrain shot running sum output").into(),
+                    (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                     0,
                     "Shot only fires at one board cell"
                 )
                     .into(),
                 location: FailureLocation::InRegion {
-                    region: (4, "shot running sum output checks").into(),
+                    region: (3, "shot running sum output checks").into(),
                     offset: 0
                 },
                 cell_values: vec![(((Any::Advice, 6).into(), 0).into(), String::from("0")),]
@@ -509,21 +1438,15 @@ mod test {
     #[test]
     fn invalid_multi_shot() {
         // construct battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize one shot that misses at 9, 9 and one that hits at 3, 3 in a single commitment
         let shot = serialize::<2>([3, 9], [3, 9]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -535,52 +1458,48 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
         // prove that attempting multiple shots in one shot commitment fails verification
         let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
         // expect failure
-        assert_eq!(
-            prover.verify(),
-            Err(vec![VerifyFailure::ConstraintNotSatisfied {
-                // fail constraint: 2 shots counted when 1 expected
-                constraint: (
-                    (23, "constrain shot running sum output").into(),
-                    0,
-                    "Shot only fires at one board cell"
-                )
-                    .into(),
-                location: FailureLocation::InRegion {
-                    region: (4, "shot running sum output checks").into(),
-                    offset: 0
-                },
-                cell_values: vec![(((Any::Advice, 6).into(), 0).into(), String::from("0x2")),]
-            }])
-        );
+        let failures = prover.verify().unwrap_err();
+        assert!(failures.contains(&VerifyFailure::ConstraintNotSatisfied {
+            // fail constraint: 2 shots counted when 1 expected
+            constraint: (
+                (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
+                0,
+                "Shot only fires at one board cell"
+            )
+                .into(),
+            location: FailureLocation::InRegion {
+                region: (3, "shot running sum output checks").into(),
+                offset: 0
+            },
+            cell_values: vec![(((Any::Advice, 6).into(), 0).into(), String::from("0x2")),]
+        }));
+        // the shot index sums to 33 + 99 = 132, which the lookup also rejects as off the board
+        assert!(failures
+            .iter()
+            .any(|failure| format!("{}", failure).contains("range check")));
     }
 
     #[test]
     fn invalid_multi_hit() {
         // construct battleship board pattern 2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // serialize 3 shots that all hit at (0, 0), (1, 0), (2, 0)
         let shot = serialize::<3>([0, 1, 2], [0, 0, 0]);
-        // assert a hit and wrap in u256
+        // assert a hit
         // @dev could either constrain this way which will count wrong # of hits, or nonzero hit assertion
-        let hit = BinaryValue::from_u8(1);
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -592,8 +1511,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -606,13 +1525,13 @@ mod test {
                 VerifyFailure::ConstraintNotSatisfied {
                     // fail constraint: 3 shots counted when 1 expected
                     constraint: (
-                        (23, "constrain shot running sum output").into(),
+                        (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                         0,
                         "Shot only fires at one board cell"
                     )
                         .into(),
                     location: FailureLocation::InRegion {
-                        region: (4, "shot running sum output checks").into(),
+                        region: (3, "shot running sum output checks").into(),
                         offset: 0
                     },
                     cell_values: vec![(((Any::Advice, 6).into(), 0).into(), String::from("0x3")),]
@@ -620,13 +1539,13 @@ mod test {
                 VerifyFailure::ConstraintNotSatisfied {
                     // fail constraint: count 1 hits but 0 inputted
                     constraint: (
-                        (23, "constrain shot running sum output").into(),
+                        (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                         1,
                         "Public hit assertion matches private witness"
                     )
                         .into(),
                     location: FailureLocation::InRegion {
-                        region: (4, "shot running sum output checks").into(),
+                        region: (3, "shot running sum output checks").into(),
                         offset: 0
                     },
                     cell_values: vec![
@@ -641,21 +1560,15 @@ mod test {
     #[test]
     fn invalid_commitment() {
         // construct battleship board pattern 2
-        let board = Board::from(&Deck::from([
-            Some((3, 4, false)),
-            Some((9, 6, true)),
-            Some((0, 0, false)),
-            Some((0, 6, false)),
-            Some((6, 1, true)),
-        ]));
+        let board = pattern_2();
         // serialize a shot at (0, 0) into `1u256`
         let shot = serialize::<1>([0], [0]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state and add one to the x coordinate to make it incorrect
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -667,8 +1580,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -681,7 +1594,7 @@ mod test {
                 VerifyFailure::Permutation {
                     column: (Any::Advice, 2).into(),
                     location: FailureLocation::InRegion {
-                        region: (12, "complete point addition").into(),
+                        region: (11, "complete point addition").into(),
                         offset: 1
                     }
                 },
@@ -693,24 +1606,230 @@ mod test {
         );
     }
 
+    // shot circuit that witnesses the board state with extra bits set above the 100 bit board
+    #[derive(Debug, Clone)]
+    struct HighBitShotCircuit {
+        board: BinaryValue,
+        high_bits: pallas::Base,
+        trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+    }
+
+    impl Circuit<pallas::Base> for HighBitShotCircuit {
+        type Config = ShotConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            ShotChip::configure(meta, HitMode::Boolean)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = ShotChip::new(config.clone());
+            // the low 100 bits match the board but the witnessed state carries the high bits
+            let board_state = self.board.to_fp::<pallas::Base>() + self.high_bits;
+            let commitment = pedersen_commit(&board_state, &self.trapdoor).to_affine();
+            let coordinates = commitment.coordinates().unwrap();
+            let inputs = chip.load_advice(
+                &mut layouter,
+                board_state,
+                [coordinates.x().to_owned(), coordinates.y().to_owned()],
+                self.shot.to_fp(),
+                self.hit.to_fp(),
+            )?;
+            let bits = [
+                self.board.bitfield::<pallas::Base, BOARD_SIZE>(),
+                self.shot.bitfield::<pallas::Base, BOARD_SIZE>(),
+            ];
+            let assigned_bits =
+                chip.decompose(&mut layouter, [inputs[0].clone(), inputs[3].clone()], bits)?;
+            let trace = compute_shot_trace(self.board, self.shot);
+            let running_sum_results = chip.running_sums(&mut layouter, assigned_bits, trace)?;
+            chip.running_sum_output(&mut layouter, inputs[4].clone(), running_sum_results)?;
+            let commitment = chip.commit_board(&mut layouter, inputs[0].clone(), self.trapdoor)?;
+            layouter.constrain_instance(commitment[0].cell(), config.instance, 0)?;
+            layouter.constrain_instance(commitment[1].cell(), config.instance, 1)?;
+            layouter.constrain_instance(inputs[3].cell(), config.instance, 2)?;
+            layouter.constrain_instance(inputs[4].cell(), config.instance, 3)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn invalid_board_state_high_bits() {
+        // construct valid battleship board pattern 1
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        // serialize a shot at (3, 5) into `53u256` and assert a hit
+        let shot = serialize::<1>([3], [5]);
+        let hit = BinaryValue::from_u8(1);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        // set bit 100 of the witnessed board state, just above the board
+        let high_bits = pallas::Base::from_u128(1 << BOARD_SIZE);
+        // commit to the board state including the high bits
+        let message = board.to_fp::<pallas::Base>() + high_bits;
+        let commitment = pedersen_commit(&message, &trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        let public_outputs = vec![
+            coordinates.x().to_owned(),
+            coordinates.y().to_owned(),
+            shot.to_fp(),
+            hit.to_fp(),
+        ];
+        let circuit = HighBitShotCircuit {
+            board,
+            high_bits,
+            trapdoor,
+            shot,
+            hit,
+        };
+        // prove that a board state above 2^100 cannot be decomposed into the board bits
+        let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
+        // expect failure where the num2bits sum is permuted against the witnessed board state
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 6).into(),
+                    location: FailureLocation::InRegion {
+                        region: (1, "num2bits x 2").into(),
+                        offset: 100
+                    }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 6).into(),
+                    location: FailureLocation::InRegion {
+                        region: (8, "Canonicity checks").into(),
+                        offset: 0
+                    }
+                }
+            ])
+        );
+    }
+
+    // decompose three values with one shared num2bits config
+    #[derive(Debug, Clone)]
+    struct DecomposeCircuit {
+        values: [BinaryValue; 3],
+        bits: [BinaryValue; 3],
+    }
+
+    impl Circuit<pallas::Base> for DecomposeCircuit {
+        type Config = ShotConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            ShotChip::configure(meta, HitMode::Boolean)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = ShotChip::new(config.clone());
+            let nums = layouter.assign_region(
+                || "load values",
+                |mut region| {
+                    let mut nums = Vec::new();
+                    for (i, value) in self.values.iter().enumerate() {
+                        nums.push(region.assign_advice(
+                            || format!("value {}", i),
+                            config.advice[4],
+                            i,
+                            || Value::known(value.to_fp::<pallas::Base>()),
+                        )?);
+                    }
+                    Ok(nums)
+                },
+            )?;
+            let bits = self
+                .bits
+                .map(|bits| bits.bitfield::<pallas::Base, BOARD_SIZE>());
+            let decomposed = chip.decompose_n(&mut layouter, nums.try_into().unwrap(), bits)?;
+            // the decomposed bits are the bits of each value
+            for i in 0..3 {
+                decomposed[i].iter().zip(bits[i]).for_each(|(cell, bit)| {
+                    cell.value().assert_if_known(|value| **value == bit);
+                });
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn decompose_n() {
+        let values = [
+            pattern_1().state(DEFAULT_WITNESS_OPTIONS),
+            pattern_2().state(DEFAULT_WITNESS_OPTIONS),
+            serialize::<1>([9], [9]),
+        ];
+        let circuit = DecomposeCircuit {
+            values,
+            bits: values,
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![]; 1]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_decompose_n() {
+        let values = [
+            pattern_1().state(DEFAULT_WITNESS_OPTIONS),
+            pattern_2().state(DEFAULT_WITNESS_OPTIONS),
+            serialize::<1>([9], [9]),
+        ];
+        // witness the bits of pattern 1 for the second value
+        let circuit = DecomposeCircuit {
+            values,
+            bits: [values[0], values[0], values[2]],
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![]; 1]).unwrap();
+        // expect the second running sum (rows 101..=201) to not match its value
+        assert_eq!(
+            prover.verify(),
+            Err(vec![
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 4).into(),
+                    location: FailureLocation::InRegion {
+                        region: (0, "load values").into(),
+                        offset: 1
+                    }
+                },
+                VerifyFailure::Permutation {
+                    column: (Any::Advice, 6).into(),
+                    location: FailureLocation::InRegion {
+                        region: (1, "num2bits x 3").into(),
+                        offset: 201
+                    }
+                }
+            ])
+        );
+    }
+
     #[test]
     fn invalid_public_board_commitment() {
         // construct battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (0, 0) into `1u256`
         let shot = serialize::<1>([0], [0]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(0);
+        // assert a miss
+        let hit = ShotResult::Miss;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -723,8 +1842,8 @@ mod test {
         let public_outputs = vec![
             commitment.0 + pallas::Base::one(),
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -737,7 +1856,7 @@ mod test {
                 VerifyFailure::Permutation {
                     column: (Any::Advice, 2).into(),
                     location: FailureLocation::InRegion {
-                        region: (12, "complete point addition").into(),
+                        region: (11, "complete point addition").into(),
                         offset: 1
                     }
                 },
@@ -752,21 +1871,15 @@ mod test {
     #[test]
     fn invalid_public_shot_commitment() {
         // construct battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (0, 0) into `1u256`
         let shot = serialize::<1>([0], [0]);
-        // assert a miss and wrap in u256
-        let hit = BinaryValue::from_u8(0);
+        // assert a miss
+        let hit = ShotResult::Miss;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state and add one to the x coordinate to make it incorrect
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -779,8 +1892,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()) + pallas::Base::one(),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp::<pallas::Base>() + pallas::Base::one(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -808,21 +1921,15 @@ mod test {
     #[test]
     fn invalid_public_hit_assertion() {
         // construct battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (1, 6) into `61u256`
         let shot = serialize::<1>([1], [6]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state and add one to the x coordinate to make it incorrect
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -835,8 +1942,8 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()) + pallas::Base::one(),
+            shot.to_fp(),
+            hit.to_fp::<pallas::Base>() + pallas::Base::one(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -848,13 +1955,13 @@ mod test {
             Err(vec![
                 VerifyFailure::ConstraintNotSatisfied {
                     constraint: (
-                        (23, "constrain shot running sum output").into(),
+                        (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
                         1,
                         "Public hit assertion matches private witness"
                     )
                         .into(),
                     location: FailureLocation::InRegion {
-                        region: (4, "shot running sum output checks").into(),
+                        region: (3, "shot running sum output checks").into(),
                         offset: 0
                     },
                     cell_values: vec![
@@ -877,24 +1984,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn typed_instance() {
+        // construct battleship board pattern 1 and a hit at (3, 5)
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let shot = ShotCommitment::new(3, 5).unwrap();
+        let hit = ShotResult::Hit;
+        let circuit = ShotCircuit::new(board, trapdoor, shot.0, hit);
+        // the typed builder lays out the same public inputs the circuit exports
+        let commitment = BoardCommitment::commit(&board, &trapdoor);
+        let instance = ShotCircuit::instance(&commitment, &shot, hit);
+        assert_eq!(instance, circuit.public_inputs());
+        assert_eq!(instance[2], serialize::<1>([3], [5]).to_fp());
+        let prover = MockProver::run(11, &circuit, vec![instance.to_vec()]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // off board coordinates are rejected before they reach the circuit
+        assert!(ShotCommitment::new(10, 0).is_err());
+    }
+
     #[test]
     fn production() {
         // construct valid battleship board pattern 1
-        let board = Board::from(&Deck::from([
-            Some((3, 3, true)),
-            Some((5, 4, false)),
-            Some((0, 1, false)),
-            Some((0, 5, true)),
-            Some((6, 1, false)),
-        ]));
+        let board = pattern_1();
         // serialize a shot at (3, 5) into `53u256`
         let shot = serialize::<1>([3], [5]);
-        // assert a hit and wrap in u256
-        let hit = BinaryValue::from_u8(1);
+        // assert a hit
+        let hit = ShotResult::Hit;
         // sample a random trapdoor value for commitment
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // marshall the board state into a pallas base field element
-        let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
         // commit to the board state
         let commitment = {
             let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -906,13 +2026,13 @@ mod test {
         let public_outputs = vec![
             commitment.0,
             commitment.1,
-            pallas::Base::from_u128(shot.lower_u128()),
-            pallas::Base::from_u128(hit.lower_u128()),
+            shot.to_fp(),
+            hit.to_fp(),
         ];
         // construct Shot circuit
         let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
         // Initialize the polynomial commitment parameters
-        let params: Params<vesta::Affine> = Params::new(11);
+        let params: Params<vesta::Affine> = Params::new(ShotCircuit::min_k());
         // Initialize the proving key
         let vk = keygen_vk(&params, &circuit).expect("keygen_vk should not fail");
         let pk = keygen_pk(&params, vk, &circuit).expect("keygen_pk should not fail");
@@ -940,6 +2060,170 @@ mod test {
         .is_ok());
     }
 
+    #[test]
+    fn invalid_instance_length() {
+        // prove a hit at (3, 5) against board pattern 1
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = ShotCircuit::new(board, trapdoor, serialize::<1>([3], [5]), ShotResult::Hit);
+        let params: Params<vesta::Affine> = Params::new(ShotCircuit::min_k());
+        let pk = ShotCircuit::keygen(&params).unwrap();
+        let proof = circuit.prove(&params, &pk).unwrap();
+        let public_inputs = circuit.public_inputs();
+        assert!(ShotCircuit::verify(&params, pk.get_vk(), &public_inputs, &proof).is_ok());
+        // dropping the hit assertion is reported before the proof is checked
+        let result = ShotCircuit::verify(&params, pk.get_vk(), &public_inputs[..3], &proof);
+        assert!(matches!(
+            result,
+            Err(VerifyError::Instance(BattleshipError::InstanceLengthMismatch {
+                expected: 4,
+                got: 3
+            }))
+        ));
+        assert_eq!(
+            format!("{}", result.unwrap_err()),
+            "Invalid public inputs: Expected 4 public inputs, got 3"
+        );
+    }
+
+    #[test]
+    fn turn_bound_instance() {
+        // prove a hit at (3, 5) against board pattern 1 on the third turn
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let shot = ShotCommitment::new(3, 5).unwrap();
+        let circuit = ShotCircuit::new_with_turn(board, trapdoor, shot.0, ShotResult::Hit, 2);
+        let commitment = BoardCommitment::commit(&board, &trapdoor);
+        let instance = ShotCircuit::instance_with_turn(&commitment, &shot, ShotResult::Hit, 2);
+        assert_eq!(instance.len(), ShotCircuit::EXPECTED_INSTANCE_LEN_WITH_TURN);
+        let prover = MockProver::run(11, &circuit, vec![instance.to_vec()]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+        // the same proof cannot be claimed for another turn
+        let instance = ShotCircuit::instance_with_turn(&commitment, &shot, ShotResult::Hit, 1);
+        let prover = MockProver::run(11, &circuit, vec![instance.to_vec()]).unwrap();
+        assert!(prover
+            .verify()
+            .unwrap_err()
+            .contains(&VerifyFailure::Permutation {
+                column: (Any::Instance, 0).into(),
+                location: FailureLocation::OutsideRegion { row: 4 }
+            }));
+    }
+
+    // decompose the board outside of ShotChip then prove the shot over the existing bits
+    #[derive(Debug, Clone)]
+    struct DecomposedBoardShotCircuit {
+        board: BinaryValue,
+        trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+    }
+
+    impl Circuit<pallas::Base> for DecomposedBoardShotCircuit {
+        type Config = ShotConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            ShotChip::configure(meta, HitMode::Boolean)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let board_state = layouter.assign_region(
+                || "load board state",
+                |mut region| {
+                    region.assign_advice(
+                        || "board state",
+                        config.advice[4],
+                        0,
+                        || Value::known(self.board.to_fp::<pallas::Base>()),
+                    )
+                },
+            )?;
+            let board_bits = Num2BitsChip::<_, BOARD_SIZE>::new(
+                board_state.clone(),
+                self.board.bitfield::<pallas::Base, BOARD_SIZE>(),
+            )
+            .synthesize(config.num2bits[0], layouter.namespace(|| "board num2bits"))?;
+            ShotChip::new(config).synthesize_with_board_bits(
+                layouter,
+                self.board,
+                &board_state,
+                &board_bits,
+                self.trapdoor,
+                self.shot,
+                self.hit,
+            )
+        }
+    }
+
+    #[test]
+    fn valid_with_board_bits() {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        // shot at (3, 5) hits the carrier
+        let shot = serialize::<1>([3], [5]);
+        let hit = BinaryValue::from_u8(1);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = DecomposedBoardShotCircuit {
+            board,
+            trapdoor,
+            shot,
+            hit,
+        };
+        // public outputs are the same as for ShotCircuit
+        let commitment = pedersen_commit(&board.to_fp(), &trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        let public_outputs = vec![*coordinates.x(), *coordinates.y(), shot.to_fp(), hit.to_fp()];
+        let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_with_board_bits() {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        // shot at (0, 0) misses but a hit is asserted
+        let shot = serialize::<1>([0], [0]);
+        let hit = BinaryValue::from_u8(1);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = DecomposedBoardShotCircuit {
+            board,
+            trapdoor,
+            shot,
+            hit,
+        };
+        let commitment = pedersen_commit(&board.to_fp(), &trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        let public_outputs = vec![*coordinates.x(), *coordinates.y(), shot.to_fp(), hit.to_fp()];
+        let prover = MockProver::run(11, &circuit, vec![public_outputs]).unwrap();
+        // expect the running sum over the reused board bits to count 0 hits
+        assert_eq!(
+            prover.verify(),
+            Err(vec![VerifyFailure::ConstraintNotSatisfied {
+                constraint: (
+                    (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
+                    1,
+                    "Public hit assertion matches private witness"
+                )
+                    .into(),
+                location: FailureLocation::InRegion {
+                    region: (6, "shot running sum output checks").into(),
+                    offset: 0
+                },
+                cell_values: vec![
+                    (((Any::Advice, 5).into(), 0).into(), String::from("1")),
+                    (((Any::Advice, 7).into(), 0).into(), String::from("0"))
+                ]
+            }])
+        );
+    }
+
     // #[test]
     // fn print_circuit() {
     //     use plotters::prelude::*;
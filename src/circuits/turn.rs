@@ -0,0 +1,319 @@
+use {
+    crate::{
+        chips::{
+            board::{BoardChip, BoardConfig},
+            shot::{HitMode, ShotChip, ShotConfig},
+        },
+        circuits::shot::ShotCircuit,
+        utils::{binary::BinaryValue, ship::STANDARD_FLEET},
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        pasta::{pallas, vesta},
+        plonk::{
+            create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ConstraintSystem, Error,
+            ProvingKey, SingleVerifier, VerifyingKey,
+        },
+        poly::commitment::Params,
+        transcript::{Blake2bRead, Blake2bWrite, Challenge255},
+    },
+    rand::rngs::OsRng,
+};
+
+// 2^K rows used by the turn circuit
+pub const TURN_CIRCUIT_K: u32 = 12;
+
+/**
+ * Storage for a proof of a valid board and a shot against it
+ * @dev the board chip's instance column (column 1) is unused; public values are exported by the shot chip
+ */
+#[derive(Clone, Debug)]
+pub struct TurnConfig {
+    pub shot: ShotConfig,
+    pub board: BoardConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct TurnCircuit {
+    pub ship_commitments: [BinaryValue; 10],
+    pub board: BinaryValue,
+    pub board_commitment_trapdoor: pallas::Scalar,
+    pub shot: BinaryValue,
+    pub hit: BinaryValue,
+}
+
+impl Circuit<pallas::Base> for TurnCircuit {
+    type Config = TurnConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        self.clone()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // configure the shot chip first so that its instance column is column 0
+        let shot = ShotChip::configure(meta, HitMode::Boolean);
+        let board = BoardChip::configure(meta, &STANDARD_FLEET);
+        TurnConfig { shot, board }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // constrain a valid board and keep its decomposition
+        let (board_state, board_bits) = BoardChip::new(config.board).synthesize_board_state(
+            &mut layouter,
+            &self.ship_commitments,
+            self.board,
+        )?;
+        // evaluate the shot over the same board bits and commit to the board once
+        ShotChip::new(config.shot).synthesize_with_board_bits(
+            layouter,
+            self.board,
+            &board_state,
+            &board_bits,
+            self.board_commitment_trapdoor,
+            self.shot,
+            self.hit,
+        )
+    }
+}
+
+impl TurnCircuit {
+    /**
+     * Construct a new turn circuit proving a valid board and the result of a shot against it
+     *
+     * @param ship_commitments - assignments for each of the ships according to order in chips::board::commitment_label
+     * @param board - the resulting board state when all ship commitments are transposed onto one bitfield
+     * @param board_commitment_trapdoor - randomly sampled blinding factor for board commitment
+     * @param shot - x, y coordinates serialized into a shot commitment
+     * @param hit - assertion that the shot either hits or misses the board (constrained 0 or 1)
+     * @return - instantiated TurnCircuit object
+     */
+    pub fn new(
+        ship_commitments: [BinaryValue; 10],
+        board: BinaryValue,
+        board_commitment_trapdoor: pallas::Scalar,
+        shot: BinaryValue,
+        hit: BinaryValue,
+    ) -> TurnCircuit {
+        TurnCircuit {
+            ship_commitments,
+            board,
+            board_commitment_trapdoor,
+            shot,
+            hit,
+        }
+    }
+
+    /**
+     * Compute the public values this circuit exports
+     *
+     * @return - [commitment x, commitment y, shot, hit]
+     */
+    pub fn public_inputs(&self) -> [pallas::Base; 4] {
+        ShotCircuit::new_raw(
+            self.board,
+            self.board_commitment_trapdoor,
+            self.shot,
+            self.hit,
+        )
+        .public_inputs()
+    }
+
+    /**
+     * Generate the proving key for the turn circuit
+     *
+     * @param params - polynomial commitment parameters with k = TURN_CIRCUIT_K
+     * @return - the proving key for any TurnCircuit
+     */
+    pub fn keygen(params: &Params<vesta::Affine>) -> Result<ProvingKey<vesta::Affine>, Error> {
+        // ShotChip witnesses the commitment coordinates, so the keygen commitment must not be the identity
+        let empty_circuit = TurnCircuit::new(
+            [BinaryValue::empty(); 10],
+            BinaryValue::empty(),
+            pallas::Scalar::one(),
+            BinaryValue::empty(),
+            BinaryValue::empty(),
+        );
+        let vk = keygen_vk(params, &empty_circuit)?;
+        keygen_pk(params, vk, &empty_circuit)
+    }
+
+    /**
+     * Prove a valid board and the result of a shot against it in one proof
+     *
+     * @param params - polynomial commitment parameters with k = TURN_CIRCUIT_K
+     * @param pk - proving key generated by keygen()
+     * @return - the serialized proof
+     */
+    pub fn prove(
+        &self,
+        params: &Params<vesta::Affine>,
+        pk: &ProvingKey<vesta::Affine>,
+    ) -> Result<Vec<u8>, Error> {
+        let public_inputs = self.public_inputs();
+        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+        create_proof(
+            params,
+            pk,
+            std::slice::from_ref(self),
+            &[&[&public_inputs, &[]]],
+            OsRng,
+            &mut transcript,
+        )?;
+        Ok(transcript.finalize())
+    }
+
+    /**
+     * Verify a proof of a valid board and the result of a shot against it
+     *
+     * @param params - polynomial commitment parameters with k = TURN_CIRCUIT_K
+     * @param vk - verifying key for the turn circuit
+     * @param public_inputs - [commitment x, commitment y, shot, hit]
+     * @param proof - the serialized proof generated by prove()
+     * @return - Ok if the proof is valid for the public inputs
+     */
+    pub fn verify(
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+        public_inputs: &[pallas::Base; 4],
+        proof: &[u8],
+    ) -> Result<(), Error> {
+        let strategy = SingleVerifier::new(params);
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof);
+        verify_proof(
+            params,
+            vk,
+            strategy,
+            &[&[public_inputs, &[]]],
+            &mut transcript,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            chips::gate_names::{GATE_ORIENTATION_ZERO, GATE_SHOT_RUNNING_SUM_OUTPUT},
+            circuits::layout::recommended_k,
+            utils::{
+                board::Board,
+                fixtures::pattern_1,
+                ship::{WitnessOption, DEFAULT_WITNESS_OPTIONS},
+                shot::serialize,
+            },
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            dev::{FailureLocation, MockProver, VerifyFailure},
+            plonk::Any,
+        },
+    };
+
+    /**
+     * Construct a turn circuit firing a shot at a board
+     *
+     * @param board - the board to prove
+     * @param options - witness options for each ship, used to build malicious boards
+     * @param x - x coordinate of the shot
+     * @param y - y coordinate of the shot
+     * @param hit - asserted hit (1) or miss (0)
+     * @return - turn circuit for the shot
+     */
+    fn turn(board: Board, options: [WitnessOption; 5], x: u8, y: u8, hit: u8) -> TurnCircuit {
+        TurnCircuit::new(
+            board.witness(options),
+            board.state(options),
+            pallas::Scalar::random(&mut OsRng),
+            serialize::<1>([x], [y]),
+            BinaryValue::from_u8(hit),
+        )
+    }
+
+    #[test]
+    fn valid_turn() {
+        // shot at (3, 5) hits the carrier
+        let circuit = turn(pattern_1(), DEFAULT_WITNESS_OPTIONS, 3, 5, 1);
+        assert_eq!(recommended_k(&circuit), TURN_CIRCUIT_K);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover =
+            MockProver::run(TURN_CIRCUIT_K, &circuit, vec![public_inputs, vec![]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_turn_hit_assertion() {
+        // shot at (0, 0) misses but a hit is asserted
+        let circuit = turn(pattern_1(), DEFAULT_WITNESS_OPTIONS, 0, 0, 1);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover =
+            MockProver::run(TURN_CIRCUIT_K, &circuit, vec![public_inputs, vec![]]).unwrap();
+        // expect the running sum over the board chip's bits to count 0 hits
+        assert_eq!(
+            prover.verify(),
+            Err(vec![VerifyFailure::ConstraintNotSatisfied {
+                constraint: (
+                    (23, GATE_SHOT_RUNNING_SUM_OUTPUT).into(),
+                    1,
+                    "Public hit assertion matches private witness"
+                )
+                    .into(),
+                location: FailureLocation::InRegion {
+                    region: (32, "shot running sum output checks").into(),
+                    offset: 0
+                },
+                cell_values: vec![
+                    (((Any::Advice, 5).into(), 0).into(), String::from("1")),
+                    (((Any::Advice, 7).into(), 0).into(), String::from("0"))
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn invalid_turn_board() {
+        // the carrier is placed both horizontally and vertically, but the shot result is honest
+        let options = [
+            WitnessOption::DualPlacement,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+            WitnessOption::Default,
+        ];
+        let circuit = turn(pattern_1(), options, 3, 5, 1);
+        let public_inputs = circuit.public_inputs().to_vec();
+        let prover =
+            MockProver::run(TURN_CIRCUIT_K, &circuit, vec![public_inputs, vec![]]).unwrap();
+        // expect the board chip to reject the turn even though the shot result is honest
+        assert_eq!(
+            prover.verify(),
+            Err(vec![VerifyFailure::ConstraintNotSatisfied {
+                constraint: (
+                    (80, GATE_ORIENTATION_ZERO).into(),
+                    0,
+                    "Aircraft Carrier H OR V == 0"
+                )
+                    .into(),
+                location: FailureLocation::InRegion {
+                    region: (0, "load ship placements").into(),
+                    offset: 0
+                },
+                cell_values: vec![
+                    (
+                        ((Any::Advice, 11).into(), 0).into(),
+                        String::from("0x200000000")
+                    ),
+                    (
+                        ((Any::Advice, 12).into(), 0).into(),
+                        String::from("0x3c00000000")
+                    )
+                ]
+            }])
+        );
+    }
+}
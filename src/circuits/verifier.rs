@@ -0,0 +1,164 @@
+use {
+    crate::{
+        circuits::{board::BoardCircuit, bundle::ProofKind, shot::ShotCircuit},
+        utils::{
+            commitment::BoardCommitment,
+            error::{check_instance_len, VerifyError},
+        },
+    },
+    halo2_proofs::{
+        arithmetic::CurveAffine,
+        pasta::{pallas, vesta},
+        plonk::VerifyingKey,
+        poly::commitment::Params,
+    },
+};
+
+/**
+ * Parameters and verifying key of one circuit, bundled so callers never touch halo2's verifier
+ * strategy or transcript
+ * @dev pairs with ProverContext; build one verifier from the board key and one from the shot key
+ */
+#[derive(Clone, Debug)]
+pub struct Verifier<C: CurveAffine> {
+    pub params: Params<C>,
+    pub vk: VerifyingKey<C>,
+}
+
+/**
+ * A rejected proof and the circuit it was checked against
+ */
+#[derive(Debug)]
+pub struct VerifierError {
+    // circuit the verifier expected the proof to be for
+    pub kind: ProofKind,
+    // why the proof was rejected
+    pub error: VerifyError,
+}
+
+impl core::fmt::Display for VerifierError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} proof rejected: {}", self.kind, self.error)
+    }
+}
+
+impl std::error::Error for VerifierError {}
+
+impl<C: CurveAffine> Verifier<C> {
+    /**
+     * Bundle the parameters and verifying key a circuit's proofs are checked with
+     *
+     * @param params - polynomial commitment parameters the proofs were generated with
+     * @param vk - verifying key of the circuit
+     * @return - the verifier
+     */
+    pub fn new(params: Params<C>, vk: VerifyingKey<C>) -> Verifier<C> {
+        Verifier { params, vk }
+    }
+}
+
+impl Verifier<vesta::Affine> {
+    /**
+     * Verify a board proof
+     *
+     * @param proof - the serialized proof generated by BoardCircuit::prove()
+     * @param commitment - the public (x, y) board commitment
+     * @return - Ok if the proof is valid for the commitment, or the reason it was rejected
+     */
+    pub fn verify_board(
+        &self,
+        proof: &[u8],
+        commitment: &[pallas::Base],
+    ) -> Result<(), VerifierError> {
+        let reject = |error: VerifyError| VerifierError {
+            kind: ProofKind::Board,
+            error,
+        };
+        check_instance_len(BoardCircuit::EXPECTED_INSTANCE_LEN, commitment)
+            .map_err(|error| reject(error.into()))?;
+        let commitment = BoardCommitment([commitment[0], commitment[1]]);
+        BoardCircuit::verify(&self.params, &self.vk, &commitment, proof)
+            .map_err(|error| reject(error.into()))
+    }
+
+    /**
+     * Verify a shot proof
+     * @dev five public outputs are checked as a shot bound to a turn, so the verifying key must
+     *      come from ShotCircuit::keygen_with_turn()
+     *
+     * @param proof - the serialized proof generated by ShotCircuit::prove()
+     * @param outputs - [commitment x, commitment y, shot, hit] with the turn appended if bound
+     * @return - Ok if the proof is valid for the public outputs, or the reason it was rejected
+     */
+    pub fn verify_shot(&self, proof: &[u8], outputs: &[pallas::Base]) -> Result<(), VerifierError> {
+        let (kind, result) = match outputs.len() {
+            ShotCircuit::EXPECTED_INSTANCE_LEN_WITH_TURN => (
+                ProofKind::TurnShot,
+                ShotCircuit::verify_with_turn(&self.params, &self.vk, outputs, proof),
+            ),
+            _ => (
+                ProofKind::Shot,
+                ShotCircuit::verify(&self.params, &self.vk, outputs, proof),
+            ),
+        };
+        result.map_err(|error| VerifierError { kind, error })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            circuits::shot::SHOT_CIRCUIT_K,
+            utils::{
+                error::BattleshipError,
+                fixtures::pattern_1,
+                ship::DEFAULT_WITNESS_OPTIONS,
+                shot::{serialize, ShotResult},
+            },
+        },
+        halo2_proofs::arithmetic::Field,
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn verify_shot_outputs() {
+        // prove a hit at (3, 5) against board pattern 1
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = ShotCircuit::new(board, trapdoor, serialize::<1>([3], [5]), ShotResult::Hit);
+        let params: Params<vesta::Affine> = Params::new(SHOT_CIRCUIT_K);
+        let pk = ShotCircuit::keygen(&params).unwrap();
+        let proof = circuit.prove(&params, &pk).unwrap();
+        let verifier = Verifier::new(params, pk.get_vk().clone());
+        let mut outputs = circuit.public_inputs();
+        assert!(verifier.verify_shot(&proof, &outputs).is_ok());
+        // a proof that does not match its outputs is rejected by halo2
+        outputs[3] = pallas::Base::zero();
+        let result = verifier.verify_shot(&proof, &outputs);
+        assert!(matches!(
+            result,
+            Err(VerifierError {
+                kind: ProofKind::Shot,
+                error: VerifyError::Proof(_)
+            })
+        ));
+        // outputs of the wrong length never reach halo2
+        let result = verifier.verify_board(&proof, &outputs);
+        assert!(matches!(
+            result,
+            Err(VerifierError {
+                kind: ProofKind::Board,
+                error: VerifyError::Instance(BattleshipError::InstanceLengthMismatch {
+                    expected: 2,
+                    got: 4
+                })
+            })
+        ));
+        assert_eq!(
+            format!("{}", result.unwrap_err()),
+            "Board proof rejected: Invalid public inputs: Expected 2 public inputs, got 4"
+        );
+    }
+}
@@ -1,5 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod chips;
+#[cfg(feature = "std")]
 pub mod circuits;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod utils;
-#[cfg(target_family = "wasm")]
+// the threaded wasm build needs the nightly build-std option, see Toolchain in the README
+#[cfg(all(feature = "std", not(feature = "stable"), target_family = "wasm"))]
 pub mod wasm;
+
+pub use utils::{
+    binary::{BinaryValue, BinaryValueError, BitOrder},
+    board::{Board, GameRuleViolation},
+    coordinate::Coordinate,
+    deck::{Deck, DeckNotationError},
+    error::BattleshipError,
+    opponent::OpponentView,
+    ship::{Ship, ShipType},
+    shot::ShotResult,
+};
+#[cfg(feature = "std")]
+pub use {
+    circuits::{
+        board::BoardCircuit, reveal::RevealCircuit, shot::ShotCircuit, turn::TurnCircuit,
+    },
+    utils::pedersen::pedersen_commit,
+};
@@ -0,0 +1,420 @@
+use {
+    crate::{
+        circuits::{
+            board::BoardCircuit,
+            bundle::{ProofBundle, ProofKind},
+            game::{BoardRecord, GameTranscript, ProverContext, ShotRecord},
+            shot::ShotCircuit,
+        },
+        utils::{
+            binary::BinaryValue,
+            board::Board,
+            commitment::{BoardCommitment, ShotCommitment},
+            coordinate::Coordinate,
+            deck::Deck,
+            error::to_fixed_array,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::ShotResult,
+        },
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        pasta::{group::ff::PrimeField, pallas},
+    },
+    once_cell::sync::OnceCell,
+    pyo3::{
+        exceptions::{PyRuntimeError, PyValueError},
+        prelude::*,
+        types::PyBytes,
+    },
+    rand::rngs::OsRng,
+};
+
+// keys shared by every call, generated on first use
+static CONTEXT: OnceCell<ProverContext> = OnceCell::new();
+
+/**
+ * Raise a ValueError for an argument that could not be converted
+ *
+ * @param reason - why the argument was rejected
+ * @return - the python exception
+ */
+fn value_error(reason: impl ToString) -> PyErr {
+    PyValueError::new_err(reason.to_string())
+}
+
+/**
+ * Raise a RuntimeError for a keygen or proving failure
+ *
+ * @param error - the halo2 error
+ * @return - the python exception
+ */
+fn runtime_error(error: halo2_proofs::plonk::Error) -> PyErr {
+    PyRuntimeError::new_err(error.to_string())
+}
+
+/**
+ * Return the shared prover context, running keygen on the first call
+ *
+ * @return - the prover context, or a RuntimeError if keygen failed
+ */
+fn context() -> PyResult<&'static ProverContext> {
+    CONTEXT
+        .get_or_try_init(ProverContext::new)
+        .map_err(runtime_error)
+}
+
+/**
+ * Convert a python list of (x, y, vertical) placements into a deck
+ *
+ * @param ships - one placement or None per ship in deck order
+ * @return - the deck, or a ValueError if there are not five placements or a ship is off the board
+ */
+fn deck(ships: Vec<Option<(u8, u8, bool)>>) -> PyResult<Deck> {
+    let ships: [Option<(u8, u8, bool)>; 5] = to_fixed_array(ships).map_err(value_error)?;
+    Deck::try_from(ships).map_err(value_error)
+}
+
+/**
+ * Convert bytes into a board commitment trapdoor
+ *
+ * @param trapdoor - 32 byte little endian scalar
+ * @return - the trapdoor, or a ValueError if it is not a canonical scalar
+ */
+fn trapdoor(trapdoor: &[u8]) -> PyResult<pallas::Scalar> {
+    let repr: [u8; 32] = trapdoor
+        .try_into()
+        .map_err(|_| value_error("Trapdoor must be 32 bytes"))?;
+    Option::from(pallas::Scalar::from_repr(repr))
+        .ok_or_else(|| value_error("Trapdoor is not a canonical scalar"))
+}
+
+/**
+ * Parse a proof bundle of the expected kind
+ *
+ * @param bytes - ProofBundle::to_bytes() of the proof
+ * @param kind - the circuit the bundle must be for
+ * @return - the bundle, or a ValueError if it is malformed or for the other circuit
+ */
+fn bundle(bytes: &[u8], kind: ProofKind) -> PyResult<ProofBundle> {
+    let bundle = ProofBundle::from_bytes(bytes).map_err(value_error)?;
+    match bundle.kind == kind {
+        true => Ok(bundle),
+        false => Err(value_error(format!("Expected a {:?} proof bundle", kind))),
+    }
+}
+
+/**
+ * Convert a board proof bundle into a transcript record
+ *
+ * @param bytes - ProofBundle::to_bytes() of a board proof
+ * @return - the board record, or a ValueError if the bundle is not a board proof
+ */
+fn board_record(bytes: &[u8]) -> PyResult<BoardRecord> {
+    let bundle = bundle(bytes, ProofKind::Board)?;
+    Ok(BoardRecord {
+        commitment: BoardCommitment([bundle.instance[0], bundle.instance[1]]),
+        proof: bundle.proof,
+    })
+}
+
+/**
+ * Convert a turn bound shot proof bundle into a transcript record
+ *
+ * @param defender - index of the player whose board was shot at
+ * @param bytes - ProofBundle::to_bytes() of a shot proof generated with a turn
+ * @return - the shot record, or a ValueError if the bundle is not a turn bound shot proof
+ */
+fn shot_record(defender: usize, bytes: &[u8]) -> PyResult<ShotRecord> {
+    let bundle = bundle(bytes, ProofKind::TurnShot)?;
+    let turn = BinaryValue::from_fp(bundle.instance[4])
+        .try_lower_u128()
+        .ok()
+        .and_then(|turn| u64::try_from(turn).ok())
+        .ok_or_else(|| value_error("Shot turn does not fit in 64 bits"))?;
+    Ok(ShotRecord {
+        defender,
+        shot: ShotCommitment(BinaryValue::from_fp(bundle.instance[2])),
+        hit: bundle.instance[3] == pallas::Base::one(),
+        turn,
+        proof: bundle.proof,
+    })
+}
+
+/**
+ * Five ship placements in deck order
+ */
+#[pyclass(name = "Deck")]
+#[derive(Clone)]
+pub struct PyDeck(Deck);
+
+#[pymethods]
+impl PyDeck {
+    #[new]
+    fn new(ships: Vec<Option<(u8, u8, bool)>>) -> PyResult<Self> {
+        Ok(PyDeck(deck(ships)?))
+    }
+
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Ok(PyDeck(Deck::from_bytes(bytes).map_err(value_error)?))
+    }
+
+    fn to_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.0.to_bytes())
+    }
+
+    #[staticmethod]
+    fn from_notation(notation: &str) -> PyResult<Self> {
+        Ok(PyDeck(Deck::from_notation(notation).map_err(value_error)?))
+    }
+
+    fn to_notation(&self) -> String {
+        self.0.to_notation()
+    }
+}
+
+/**
+ * Board state of a placed deck
+ */
+#[pyclass(name = "Board")]
+pub struct PyBoard(Board);
+
+#[pymethods]
+impl PyBoard {
+    #[new]
+    fn new(deck: &PyDeck) -> Self {
+        PyBoard(Board::from(&deck.0))
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_valid().is_ok()
+    }
+
+    fn violations(&self) -> Vec<String> {
+        match self.0.is_valid() {
+            Ok(()) => Vec::new(),
+            Err(violations) => violations.iter().map(|v| format!("{:?}", v)).collect(),
+        }
+    }
+
+    fn hit(&self, x: u8, y: u8) -> PyResult<bool> {
+        self.0.hit(x, y).map_err(value_error)
+    }
+
+    fn cells(&self) -> Vec<(u8, u8)> {
+        self.0.cells().collect()
+    }
+}
+
+/**
+ * Prove that a deck places a valid board
+ *
+ * @param deck - the deck to prove
+ * @param trapdoor - optional 32 byte trapdoor; a random trapdoor is sampled if omitted
+ * @return - (ProofBundle bytes, trapdoor bytes) where the trapdoor is needed to prove shots
+ */
+#[pyfunction]
+fn prove_board<'py>(
+    py: Python<'py>,
+    deck: &PyDeck,
+    trapdoor: Option<&[u8]>,
+) -> PyResult<(&'py PyBytes, &'py PyBytes)> {
+    let board = Board::from(&deck.0);
+    board
+        .is_valid()
+        .map_err(|violations| value_error(format!("Invalid board: {:?}", violations)))?;
+    let trapdoor = match trapdoor {
+        Some(trapdoor) => self::trapdoor(trapdoor)?,
+        None => pallas::Scalar::random(&mut OsRng),
+    };
+    let bundle = py.allow_threads(|| {
+        let context = context()?;
+        let circuit = BoardCircuit::new(
+            board.witness(DEFAULT_WITNESS_OPTIONS),
+            board.state(DEFAULT_WITNESS_OPTIONS),
+            trapdoor,
+        );
+        let commitment = circuit.try_commitment().map_err(value_error)?;
+        let proof = context.prove_board(&circuit).map_err(runtime_error)?;
+        Ok::<_, PyErr>(ProofBundle::board(
+            &commitment,
+            proof,
+            context.board_vk_fingerprint(),
+        ))
+    })?;
+    Ok((
+        PyBytes::new(py, &bundle.to_bytes()),
+        PyBytes::new(py, &trapdoor.to_repr()),
+    ))
+}
+
+/**
+ * Prove the result of a shot against a deck committed to with prove_board()
+ *
+ * @param deck - the defender's deck
+ * @param trapdoor - 32 byte trapdoor returned by prove_board()
+ * @param x - horizontal coordinate of the shot
+ * @param y - vertical coordinate of the shot
+ * @param turn - optional index of the shot in the game, required to record it in a transcript
+ * @return - (ProofBundle bytes, whether the shot hit)
+ */
+#[pyfunction]
+fn prove_shot<'py>(
+    py: Python<'py>,
+    deck: &PyDeck,
+    trapdoor: &[u8],
+    x: u8,
+    y: u8,
+    turn: Option<u64>,
+) -> PyResult<(&'py PyBytes, bool)> {
+    let board = Board::from(&deck.0);
+    let trapdoor = self::trapdoor(trapdoor)?;
+    let coordinate = Coordinate::new(x, y).map_err(value_error)?;
+    let shot = ShotCommitment::from(coordinate);
+    let hit = board.hit_at(coordinate);
+    let bundle = py.allow_threads(|| {
+        let context = context()?;
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let hit = ShotResult::from(hit);
+        let commitment = BoardCommitment::try_commit(&state, &trapdoor).map_err(value_error)?;
+        let bundle = match turn {
+            Some(turn) => {
+                let circuit = ShotCircuit::new_with_turn(state, trapdoor, shot.0, hit, turn);
+                let proof = context.prove_shot(&circuit).map_err(runtime_error)?;
+                ProofBundle::turn_shot(
+                    ShotCircuit::instance_with_turn(&commitment, &shot, hit, turn),
+                    proof,
+                    context.turn_shot_vk_fingerprint(),
+                )
+            }
+            None => {
+                let circuit = ShotCircuit::new(state, trapdoor, shot.0, hit);
+                let proof = context.prove_shot(&circuit).map_err(runtime_error)?;
+                ProofBundle::shot(
+                    ShotCircuit::instance(&commitment, &shot, hit),
+                    proof,
+                    context.shot_vk_fingerprint(),
+                )
+            }
+        };
+        Ok::<_, PyErr>(bundle)
+    })?;
+    Ok((PyBytes::new(py, &bundle.to_bytes()), hit))
+}
+
+/**
+ * Verify a board or shot proof bundle
+ *
+ * @param bundle - ProofBundle bytes returned by prove_board() or prove_shot()
+ * @return - true if the proof is valid for its bundled public inputs
+ */
+#[pyfunction]
+fn verify_bundle(py: Python<'_>, bundle: &[u8]) -> PyResult<bool> {
+    let bundle = ProofBundle::from_bytes(bundle).map_err(value_error)?;
+    py.allow_threads(|| Ok(bundle.verify(context()?).is_ok()))
+}
+
+/**
+ * Public record of a two player game built from proof bundles
+ */
+#[pyclass(name = "GameTranscript")]
+pub struct PyGameTranscript(GameTranscript);
+
+#[pymethods]
+impl PyGameTranscript {
+    #[new]
+    fn new(boards: (&[u8], &[u8])) -> PyResult<Self> {
+        Ok(PyGameTranscript(GameTranscript::new([
+            board_record(boards.0)?,
+            board_record(boards.1)?,
+        ])))
+    }
+
+    fn record_shot(&mut self, defender: usize, bundle: &[u8]) -> PyResult<()> {
+        self.0.record_shot(shot_record(defender, bundle)?);
+        Ok(())
+    }
+
+    fn winner(&self) -> Option<usize> {
+        self.0.winner()
+    }
+
+    fn verify(&self, py: Python<'_>) -> PyResult<bool> {
+        py.allow_threads(|| Ok(self.0.verify(context()?).is_ok()))
+    }
+}
+
+#[pymodule]
+fn battlezips(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyDeck>()?;
+    m.add_class::<PyBoard>()?;
+    m.add_class::<PyGameTranscript>()?;
+    m.add_function(wrap_pyfunction!(prove_board, m)?)?;
+    m.add_function(wrap_pyfunction!(prove_shot, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_bundle, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::utils::fixtures::PATTERN_1};
+
+    #[test]
+    fn conversion_errors() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            // decks must hold five placements on the board
+            assert!(deck(PATTERN_1.to_vec()).is_ok());
+            assert!(deck(PATTERN_1[..4].to_vec())
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+            let mut ships = PATTERN_1.to_vec();
+            ships[0] = Some((10, 0, false));
+            assert!(deck(ships).unwrap_err().is_instance_of::<PyValueError>(py));
+            // trapdoors must be canonical 32 byte scalars
+            let one = pallas::Scalar::one().to_repr();
+            assert_eq!(trapdoor(&one).unwrap(), pallas::Scalar::one());
+            assert!(trapdoor(&one[..31])
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+            assert!(trapdoor(&[0xff; 32])
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn bundle_records() {
+        pyo3::prepare_freethreaded_python();
+        let state = Board::from(&Deck::from(PATTERN_1)).state(DEFAULT_WITNESS_OPTIONS);
+        let commitment = BoardCommitment::commit(&state, &pallas::Scalar::one());
+        let board = ProofBundle::board(&commitment, vec![1, 2, 3], [0; 32]).to_bytes();
+        let shot = ShotCommitment::new(3, 3).unwrap();
+        let instance = ShotCircuit::instance_with_turn(&commitment, &shot, ShotResult::Hit, 4);
+        let shot_bundle = ProofBundle::turn_shot(instance, vec![4, 5, 6], [0; 32]).to_bytes();
+        // bundles convert into the records a transcript verifies
+        let record = board_record(&board).unwrap();
+        assert_eq!(record.commitment, commitment);
+        assert_eq!(record.proof, vec![1, 2, 3]);
+        let record = shot_record(1, &shot_bundle).unwrap();
+        assert_eq!(
+            (record.defender, record.shot, record.hit, record.turn),
+            (1, shot, true, 4)
+        );
+        // a bundle for the other circuit, or a shot without a turn, is rejected
+        let instance = ShotCircuit::instance(&commitment, &shot, ShotResult::Hit);
+        let unbound = ProofBundle::shot(instance, vec![4, 5, 6], [0; 32]).to_bytes();
+        Python::with_gil(|py| {
+            assert!(board_record(&shot_bundle)
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+            assert!(shot_record(0, &board)
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+            assert!(shot_record(0, &unbound)
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+        });
+    }
+}
@@ -0,0 +1,205 @@
+use {
+    crate::utils::{
+        board::BOARD_SIZE,
+        opponent::OpponentView,
+        ship::{Ship, ShipType},
+    },
+    alloc::vec::Vec,
+};
+
+/**
+ * Compute the probability that each cell of an opponent's board contains a ship
+ * @dev enumerates every joint placement of the ships still afloat, so cost grows with the product
+ *      of their placement counts; intended for bots and hints once a few ships remain
+ * @dev hits not explained by ships announced as sunk must be covered by the remaining ships
+ *
+ * @param view - verified hits, misses and sunk ships of the opponent's board
+ * @param roster - every ship type in the opponent's fleet, including sunk ships
+ * @return - probabilities indexed [y][x]; known hits are 1 and cells with no consistent placement 0
+ */
+pub fn heatmap(view: &OpponentView, roster: &[ShipType]) -> [[f64; 10]; 10] {
+    let hits = view.hits.lower_u128();
+    let misses = view.misses.lower_u128();
+    // remove each announced sunk ship from the roster once
+    let mut remaining = roster.to_vec();
+    for ship in view.sunk.iter() {
+        if let Some(i) = remaining.iter().position(|remaining| remaining == ship) {
+            remaining.remove(i);
+        }
+    }
+    // sunk ships cover exactly their own length in hits; the remaining ships cover the rest
+    let sunk_cells: usize = view.sunk.iter().map(|ship| ship.length()).sum();
+    let required = (hits.count_ones() as usize).saturating_sub(sunk_cells) as u32;
+    // legal placements of each remaining ship that do not cover a miss
+    let placements: Vec<Vec<u128>> = remaining
+        .iter()
+        .map(|ship_type| {
+            Ship::placements(*ship_type)
+                .map(|ship| ship.bits(false).lower_u128())
+                .filter(|placement| placement & misses == 0)
+                .collect()
+        })
+        .collect();
+    let mut heatmap = Heatmap {
+        hits,
+        required,
+        counts: [0; BOARD_SIZE],
+        total: 0,
+    };
+    heatmap.enumerate(&placements, 0);
+    // normalize counts to probabilities
+    let mut probabilities = [[0.0; 10]; 10];
+    for i in 0..BOARD_SIZE {
+        probabilities[i / 10][i % 10] = if hits >> i & 1 == 1 {
+            1.0
+        } else if heatmap.total == 0 {
+            0.0
+        } else {
+            heatmap.counts[i] as f64 / heatmap.total as f64
+        };
+    }
+    probabilities
+}
+
+/**
+ * Running tally of consistent fleet placements
+ *
+ * @param hits - serialized known hits
+ * @param required - number of hits the remaining ships must cover
+ * @param counts - number of consistent placements occupying each cell
+ * @param total - number of consistent placements
+ */
+struct Heatmap {
+    hits: u128,
+    required: u32,
+    counts: [u64; BOARD_SIZE],
+    total: u64,
+}
+
+impl Heatmap {
+    /**
+     * Recursively place each remaining ship without overlap, tallying every consistent fleet
+     *
+     * @param placements - candidate placements of each ship not yet placed
+     * @param occupied - cells occupied by the ships placed so far
+     */
+    fn enumerate(&mut self, placements: &[Vec<u128>], occupied: u128) {
+        let covered = (occupied & self.hits).count_ones();
+        match placements.split_first() {
+            None => {
+                if covered == self.required {
+                    self.total += 1;
+                    let mut cells = occupied;
+                    while cells != 0 {
+                        self.counts[cells.trailing_zeros() as usize] += 1;
+                        cells &= cells - 1;
+                    }
+                }
+            }
+            Some((ship, rest)) => {
+                for placement in ship.iter() {
+                    if placement & occupied != 0 {
+                        continue;
+                    }
+                    let next = occupied | placement;
+                    // the remaining ships cannot uncover a hit once covered
+                    if (next & self.hits).count_ones() <= self.required {
+                        self.enumerate(rest, next);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /**
+     * Find the most likely cell that has not been shot at yet
+     *
+     * @return - (x, y) of the first cell with the highest probability, scanning from (0, 0)
+     */
+    fn argmax(view: &OpponentView, heatmap: &[[f64; 10]; 10]) -> (u8, u8) {
+        let mut best = (0, 0);
+        let mut best_probability = -1.0;
+        for y in 0..10u8 {
+            for x in 0..10u8 {
+                let probability = heatmap[y as usize][x as usize];
+                if !view.is_cell_resolved(x, y) && probability > best_probability {
+                    best = (x, y);
+                    best_probability = probability;
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn placement_counts() {
+        // an L-length ship has (11 - L) * 10 placements in each orientation
+        assert_eq!(Ship::placements(ShipType::Carrier).count(), 120);
+        assert_eq!(Ship::placements(ShipType::Destroyer).count(), 180);
+    }
+
+    #[test]
+    fn corner_miss() {
+        let mut view = OpponentView::new();
+        view.apply_shot(0, 0, false).unwrap();
+        let heatmap = heatmap(&view, &[ShipType::Destroyer]);
+        // 180 destroyer placements, 2 of which cover the corner
+        assert_eq!(heatmap[0][0], 0.0);
+        assert_eq!(heatmap[0][1], 2.0 / 178.0);
+        assert_eq!(heatmap[1][0], 2.0 / 178.0);
+        assert_eq!(heatmap[0][2], 3.0 / 178.0);
+        assert_eq!(heatmap[5][5], 4.0 / 178.0);
+        // interior cells tie; the first in scan order is (1, 1)
+        assert_eq!(argmax(&view, &heatmap), (1, 1));
+        // the destroyer occupies 2 cells in every placement
+        let expected: f64 = heatmap.iter().flatten().sum();
+        assert!((expected - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn isolated_hit() {
+        let mut view = OpponentView::new();
+        view.apply_shot(0, 0, true).unwrap();
+        view.apply_shot(1, 0, false).unwrap();
+        view.apply_shot(0, 3, false).unwrap();
+        // either the destroyer or the cruiser covers the hit and can only extend up from it
+        let heatmap = heatmap(&view, &[ShipType::Destroyer, ShipType::Cruiser]);
+        assert_eq!(heatmap[0][0], 1.0);
+        assert_eq!(heatmap[1][0], 1.0);
+        assert_eq!(argmax(&view, &heatmap), (0, 1));
+        // 5 ship cells, one of which is the known hit
+        let expected: f64 = heatmap.iter().flatten().sum::<f64>() - 1.0;
+        assert!((expected - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sunk_ship_explains_hits() {
+        let mut view = OpponentView::new();
+        view.apply_shot(0, 0, true).unwrap();
+        view.apply_shot(1, 0, true).unwrap();
+        view.mark_sunk(ShipType::Destroyer);
+        let heatmap = heatmap(&view, &[ShipType::Destroyer, ShipType::Cruiser]);
+        // the cruiser cannot overlap the sunk destroyer, leaving 156 of its 160 placements
+        assert_eq!(heatmap[0][2], 2.0 / 156.0);
+        assert_eq!(heatmap[1][0], 2.0 / 156.0);
+        assert_eq!(heatmap[5][5], 6.0 / 156.0);
+        assert_eq!(argmax(&view, &heatmap), (2, 2));
+    }
+
+    #[test]
+    fn inconsistent_view() {
+        let mut view = OpponentView::new();
+        // a hit boxed in by misses cannot be covered by a destroyer
+        view.apply_shot(0, 0, true).unwrap();
+        view.apply_shot(1, 0, false).unwrap();
+        view.apply_shot(0, 1, false).unwrap();
+        let heatmap = heatmap(&view, &[ShipType::Destroyer]);
+        assert_eq!(heatmap[0][0], 1.0);
+        assert_eq!(heatmap.iter().flatten().sum::<f64>(), 1.0);
+    }
+}
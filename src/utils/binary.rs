@@ -1,11 +1,17 @@
+#[cfg(feature = "std")]
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    pasta::{group::ff::PrimeField, Fp},
+};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 use {
     super::board::BOARD_SIZE,
+    alloc::{string::String, vec::Vec},
     bitvec::prelude::*,
-    halo2_proofs::{
-        arithmetic::FieldExt,
-        pasta::{group::ff::PrimeField, Fp},
+    core::{
+        cmp::Ordering,
+        ops::{BitAnd, Shr},
     },
 };
 
@@ -15,25 +21,129 @@ use {
  */
 pub type U256 = BitArray<[u8; 32], Lsb0>; // 256 bit integer in little endian
 
+/**
+ * Errors returned when constructing a BinaryValue from unvalidated input
+ */
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BinaryValueError {
+    // value has a bit set at or above index max_bits
+    Overflow { max_bits: usize },
+    // shot commitment does not have exactly one bit set
+    InvalidShot { popcount: usize },
+    // input is not 32 bytes of hex
+    InvalidHex,
+    // value is not a canonical field element
+    NotInField,
+    // x, y coordinate does not fall on the board
+    InvalidCoordinate { x: u8, y: u8 },
+    // coordinate is not a column letter followed by a row number, e.g. "B7"
+    InvalidCoordinateNotation,
+    // grid row is not 10 '.' or '#' cells, or falls below the 10th row of the board
+    InvalidGrid { row: usize },
+}
+
+/**
+ * Order in which bits are exported from a BinaryValue
+ */
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BitOrder {
+    // bit i of the value at index i; used by Num2BitsChip and every in-circuit trace
+    LittleEndian,
+    // bit S - 1 - i of the value at index i; matches big endian on-chain encodings
+    BigEndian,
+}
+
+impl core::fmt::Display for BinaryValueError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BinaryValueError::Overflow { max_bits } => {
+                write!(f, "Value does not fit in {} bits", max_bits)
+            }
+            BinaryValueError::InvalidShot { popcount } => {
+                write!(f, "Shot must have exactly 1 bit set, found {}", popcount)
+            }
+            BinaryValueError::InvalidHex => write!(f, "Value must be 32 bytes of hex"),
+            BinaryValueError::NotInField => write!(f, "Value is not a canonical field element"),
+            BinaryValueError::InvalidCoordinate { x, y } => {
+                write!(f, "Coordinate ({}, {}) is not on the board", x, y)
+            }
+            BinaryValueError::InvalidCoordinateNotation => {
+                write!(f, "Coordinate must be a column A-J followed by a row 1-10")
+            }
+            BinaryValueError::InvalidGrid { row } => {
+                write!(f, "Grid row {} must be 10 '.' or '#' cells", row)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinaryValueError {}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct BinaryValue {
     pub value: U256,
 }
 
 impl BinaryValue {
-    // wrap 256 bit BitArray in BinaryValue object
-    pub fn new(value: U256) -> BinaryValue {
+    // wrap 256 bit BitArray in BinaryValue object without validating it
+    pub fn new_unchecked(value: U256) -> BinaryValue {
         BinaryValue { value }
     }
 
+    // wrap 256 bit BitArray in BinaryValue object if value < 2^max_bits
+    pub fn new_checked(value: U256, max_bits: usize) -> Result<BinaryValue, BinaryValueError> {
+        if value[max_bits.min(256)..].any() {
+            Err(BinaryValueError::Overflow { max_bits })
+        } else {
+            Ok(BinaryValue::new_unchecked(value))
+        }
+    }
+
+    // wrap 256 bit BitArray in BinaryValue object if it is a valid 100 bit shot commitment
+    pub fn new_shot(value: U256) -> Result<BinaryValue, BinaryValueError> {
+        let shot = BinaryValue::new_checked(value, BOARD_SIZE)?;
+        match shot.value.count_ones() {
+            1 => Ok(shot),
+            popcount => Err(BinaryValueError::InvalidShot { popcount }),
+        }
+    }
+
     // instantiate from an array of 32 bytes
     pub fn from_repr(value: [u8; 32]) -> BinaryValue {
-        BinaryValue::new(U256::new(value))
+        BinaryValue::new_unchecked(U256::new(value))
+    }
+
+    // instantiate from 32 bytes in little endian, i.e. byte 0 holds bits 0..8
+    pub fn from_le_bytes(bytes: [u8; 32]) -> BinaryValue {
+        BinaryValue::from_repr(bytes)
+    }
+
+    // instantiate from 64 hex characters encoding 32 bytes in little endian
+    pub fn from_hex(value: &str) -> Result<BinaryValue, BinaryValueError> {
+        let bytes: [u8; 32] = hex::decode(value)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(BinaryValueError::InvalidHex)?;
+        Ok(BinaryValue::from_repr(bytes))
     }
 
     // instantiate from an element of Fp
+    #[cfg(feature = "std")]
     pub fn from_fp(value: Fp) -> BinaryValue {
-        BinaryValue::new(U256::new(value.to_repr()))
+        BinaryValue::new_unchecked(U256::new(value.to_repr()))
+    }
+
+    // instantiate from a bitvec slice of at most 256 bits
+    pub fn from_bitslice(bits: &BitSlice<u32, Lsb0>) -> Result<BinaryValue, BinaryValueError> {
+        if bits.len() > 256 {
+            return Err(BinaryValueError::Overflow { max_bits: 256 });
+        }
+        let mut value = U256::ZERO;
+        for (i, bit) in bits.iter().by_vals().enumerate() {
+            value.set(i, bit);
+        }
+        Ok(BinaryValue::new_unchecked(value))
     }
 
     // return a 256 bit number from an 8 bit number
@@ -43,9 +153,34 @@ impl BinaryValue {
         BinaryValue::from_repr(buf)
     }
 
+    /**
+     * Instantiate from a 10x10 grid of '.' (empty) and '#' (occupied) cells
+     * @dev rows are separated by any whitespace; row i maps to bits 10i..10i + 10 and rows missing
+     *      from the end of the grid are empty
+     *
+     * @param grid - up to 10 rows of 10 cells each
+     * @return - the value with a bit set for every occupied cell, or the index of the first bad row
+     */
+    pub fn from_grid_string(grid: &str) -> Result<BinaryValue, BinaryValueError> {
+        let mut value = U256::ZERO;
+        for (row, cells) in grid.split_whitespace().enumerate() {
+            if row >= BOARD_SIZE / 10 || cells.len() != 10 {
+                return Err(BinaryValueError::InvalidGrid { row });
+            }
+            for (column, cell) in cells.chars().enumerate() {
+                match cell {
+                    '.' => {}
+                    '#' => value.set(row * 10 + column, true),
+                    _ => return Err(BinaryValueError::InvalidGrid { row }),
+                }
+            }
+        }
+        Ok(BinaryValue::new_unchecked(value))
+    }
+
     // wrap an empty 256 bit BitArray in BinaryValue object
     pub fn empty() -> BinaryValue {
-        BinaryValue::new(U256::ZERO)
+        BinaryValue::new_unchecked(U256::ZERO)
     }
 
     // return the underlying buffer of bytes
@@ -53,12 +188,90 @@ impl BinaryValue {
         self.value.into_inner()
     }
 
-    // return the value as an element of Fp
-    pub fn to_fp(&self) -> Fp {
-        Fp::from_repr(self.to_repr()).unwrap()
+    /**
+     * Enumerate the indices of set bits in ascending order
+     * @dev scans the value as four 64 bit limbs, skipping zero limbs and jumping between set bits
+     *      with trailing_zeros(), so enumeration is O(popcount) rather than O(256)
+     *
+     * @return - iterator over the index of every set bit
+     */
+    pub fn iter_set_bits(&self) -> impl Iterator<Item = usize> {
+        let bytes = self.to_repr();
+        (0..4).flat_map(move |limb| {
+            let mut word = u64::from_le_bytes(bytes[limb * 8..limb * 8 + 8].try_into().unwrap());
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                // clear the lowest set bit
+                word &= word - 1;
+                Some(limb * 64 + bit)
+            })
+        })
+    }
+
+    /**
+     * Render the first 100 bits as the grid parsed by from_grid_string()
+     *
+     * @return - 10 rows of '.' (empty) and '#' (occupied) cells separated by newlines
+     */
+    pub fn to_grid_string(&self) -> String {
+        let mut grid = String::new();
+        for (i, bit) in self.value[..BOARD_SIZE].iter().by_vals().enumerate() {
+            if i > 0 && i % 10 == 0 {
+                grid.push('\n');
+            }
+            grid.push(if bit { '#' } else { '.' });
+        }
+        grid
+    }
+
+    // return all 256 bits as 32 bytes in little endian, the inverse of from_le_bytes()
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        self.to_repr()
+    }
+
+    // return the underlying buffer of bytes as 64 hex characters
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_repr())
+    }
+
+    /**
+     * Convert the binary value to an element of a given prime field
+     * @dev composes all 256 bits as sum(bit_i * 2^i) in F, so values at or above the modulus are
+     *      reduced rather than rejected; prefer this over from_u128(lower_u128()), which drops bits 128..256
+     *
+     * @param F: the prime field to wrap the value in
+     * @return - the value reduced into F
+     */
+    #[cfg(feature = "std")]
+    pub fn to_fp<F: FieldExt>(&self) -> F {
+        self.value
+            .iter()
+            .by_vals()
+            .rev()
+            .fold(F::zero(), |acc, bit| acc.double() + F::from(bit))
+    }
+
+    // return the value as an element of Fp if it is canonical
+    #[cfg(feature = "std")]
+    pub fn try_to_fp(&self) -> Result<Fp, BinaryValueError> {
+        Option::from(Fp::from_repr(self.to_repr())).ok_or(BinaryValueError::NotInField)
+    }
+
+    // return the value as an owned bitvec in little endian
+    pub fn to_bitvec(&self) -> BitVec<u32, Lsb0> {
+        self.value.iter().by_vals().collect()
+    }
+
+    // returns the u128 from first half of U256 in LE if bits 128..256 are all unset
+    pub fn try_lower_u128(self) -> Result<u128, BinaryValueError> {
+        BinaryValue::new_checked(self.value, 128).map(BinaryValue::lower_u128)
     }
 
     // returns the u128 from first half of U256 in LE
+    // silently truncates bits 128..256; prefer try_lower_u128(), or to_fp() for field elements
     pub fn lower_u128(self) -> u128 {
         u128::from_le_bytes(
             self.value.into_inner()[0..16]
@@ -73,18 +286,124 @@ impl BinaryValue {
 
     /**
      * Convert the binary value to an array of bits on a given prime field
+     * @dev little endian: bit i of the value is at index i, as consumed by Num2BitsChip & placement traces
      *
      * @param F: the prime field to wrap bits in
      * @param S: the number of bits in the field
      * @return - array of bits of length S on Field F
      */
+    #[cfg(feature = "std")]
     pub fn bitfield<F: FieldExt, const S: usize>(self) -> [F; S] {
-        self.value.into_inner().view_bits::<Lsb0>()[0..S]
-            .into_iter()
-            .map(|bit| F::from(*bit))
-            .collect::<Vec<F>>()
-            .try_into()
-            .unwrap()
+        self.bitfield_ordered::<F, S>(BitOrder::LittleEndian)
+    }
+
+    /**
+     * Convert the binary value to an array of bits on a given prime field in big endian order
+     * @dev bit S - 1 of the value is at index 0; never witness this where the circuit expects bitfield()
+     *
+     * @param F: the prime field to wrap bits in
+     * @param S: the number of bits in the field
+     * @return - array of bits of length S on Field F, most significant first
+     */
+    #[cfg(feature = "std")]
+    pub fn bitfield_be<F: FieldExt, const S: usize>(self) -> [F; S] {
+        self.bitfield_ordered::<F, S>(BitOrder::BigEndian)
+    }
+
+    /**
+     * Convert the binary value to an array of bits on a given prime field in a given bit order
+     *
+     * @param F: the prime field to wrap bits in
+     * @param S: the number of bits in the field
+     * @param order - whether to export the S low bits least or most significant first
+     * @return - array of bits of length S on Field F
+     */
+    #[cfg(feature = "std")]
+    pub fn bitfield_ordered<F: FieldExt, const S: usize>(self, order: BitOrder) -> [F; S] {
+        let mut bits = self.bitfield_bitvec::<S>();
+        if order == BitOrder::BigEndian {
+            bits.reverse();
+        }
+        bits.map(|bit| F::from(bit))
+    }
+
+    /**
+     * Convert the binary value to an array of booleans
+     *
+     * @param N: the number of bits to export; bits past 256 are unset
+     * @return - array of the first N bits in little endian order
+     */
+    pub fn bitfield_bitvec<const N: usize>(&self) -> [bool; N] {
+        let mut bits = [false; N];
+        for (i, bit) in self.value[..N.min(256)].iter().by_vals().enumerate() {
+            bits[i] = bit;
+        }
+        bits
+    }
+
+    /**
+     * Return a value with the low n bits set
+     *
+     * @param n - the number of bits to set (at most 256)
+     * @return - 2^n - 1 as a BinaryValue
+     */
+    pub fn bit_mask(n: usize) -> BinaryValue {
+        let mut value = U256::ZERO;
+        value[..n].fill(true);
+        BinaryValue::new_unchecked(value)
+    }
+
+    /**
+     * Extract a subrange of bits, right aligned so bit lo of the value is bit 0 of the result
+     * @dev panics if hi < lo or hi > 256
+     *
+     * @param lo - index of the first bit to extract
+     * @param hi - index one past the last bit to extract
+     * @return - bits [lo, hi) of the value
+     */
+    pub fn mask(self, lo: usize, hi: usize) -> BinaryValue {
+        (self >> lo) & BinaryValue::bit_mask(hi - lo)
+    }
+
+    /**
+     * Extract a row of the board
+     *
+     * @param r - the row to extract, 0-9
+     * @return - bits [10r, 10r + 10) of the value, right aligned
+     */
+    pub fn row(self, r: u8) -> BinaryValue {
+        let lo = r as usize * 10;
+        self.mask(lo, lo + 10)
+    }
+
+    /**
+     * Count the set bits in a subrange of the value without building a mask
+     * @dev panics if hi < lo or hi > 256; reads the value one 64 bit limb at a time, so the cost is
+     *      the number of limbs spanned rather than the number of bits
+     *
+     * @param lo - index of the first bit to count
+     * @param hi - index one past the last bit to count
+     * @return - number of bits set in [lo, hi)
+     */
+    pub fn popcount_range(self, lo: usize, hi: usize) -> u32 {
+        assert!(lo <= hi && hi <= 256, "Invalid bit range {}..{}", lo, hi);
+        let bytes = self.value.into_inner();
+        let mut count = 0;
+        let mut start = lo;
+        while start < hi {
+            let limb = start / 64;
+            let end = hi.min((limb + 1) * 64);
+            let word = u64::from_le_bytes(bytes[limb * 8..limb * 8 + 8].try_into().unwrap());
+            // mask [start, end) within the limb; a full limb would overflow the shift
+            let width = end - start;
+            let mask = match width {
+                64 => u64::MAX,
+                _ => ((1 << width) - 1) << (start % 64),
+            };
+            count += (word & mask).count_ones();
+            start = end;
+        }
+        count
     }
 
     /**
@@ -104,6 +423,389 @@ impl BinaryValue {
             let bit = self.value[i] || to.value[i];
             zipped.set(i, bit);
         }
-        BinaryValue::new(zipped)
+        BinaryValue::new_unchecked(zipped)
+    }
+}
+
+impl Shr<usize> for BinaryValue {
+    type Output = BinaryValue;
+
+    // shift towards bit 0, dropping the low bits and filling the high bits with 0
+    fn shr(self, n: usize) -> BinaryValue {
+        let mut value = self.value;
+        value.shift_left(n.min(256));
+        BinaryValue::new_unchecked(value)
+    }
+}
+
+impl BitAnd for BinaryValue {
+    type Output = BinaryValue;
+
+    fn bitand(self, rhs: BinaryValue) -> BinaryValue {
+        BinaryValue::new_unchecked(self.value & rhs.value)
+    }
+}
+
+// numeric order of the 256 bit integer, i.e. compares the most significant byte first
+impl Ord for BinaryValue {
+    fn cmp(&self, other: &BinaryValue) -> Ordering {
+        let (lhs, rhs) = (self.value.into_inner(), other.value.into_inner());
+        lhs.iter().rev().cmp(rhs.iter().rev())
+    }
+}
+
+impl PartialOrd for BinaryValue {
+    fn partial_cmp(&self, other: &BinaryValue) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{fixtures::PATTERN_1_STATE, shot::serialize},
+        alloc::collections::BTreeSet,
+        std::collections::HashSet,
+    };
+
+    #[test]
+    fn new_checked_bounds() {
+        let mut value = U256::ZERO;
+        value.set(BOARD_SIZE - 1, true);
+        assert!(BinaryValue::new_checked(value, BOARD_SIZE).is_ok());
+        value.set(BOARD_SIZE, true);
+        assert_eq!(
+            BinaryValue::new_checked(value, BOARD_SIZE),
+            Err(BinaryValueError::Overflow {
+                max_bits: BOARD_SIZE
+            })
+        );
+        assert!(BinaryValue::new_checked(value, 256).is_ok());
+    }
+
+    #[test]
+    fn bitslice_round_trip() {
+        let mut repr = [0u8; 32];
+        repr[..16].copy_from_slice(&PATTERN_1_STATE.to_le_bytes());
+        let value = BinaryValue::from_repr(repr);
+        let bits = value.to_bitvec();
+        assert_eq!(bits.len(), 256);
+        assert_eq!(BinaryValue::from_bitslice(&bits), Ok(value));
+        // shorter slices fill the low bits
+        assert_eq!(BinaryValue::from_bitslice(&bits[..BOARD_SIZE]), Ok(value));
+        let flags = value.bitfield_bitvec::<BOARD_SIZE>();
+        assert!(flags
+            .iter()
+            .zip(bits.iter().by_vals())
+            .all(|(flag, bit)| *flag == bit));
+    }
+
+    #[test]
+    fn bitslice_oversize() {
+        let bits = bitvec![u32, Lsb0; 1; 257];
+        assert_eq!(
+            BinaryValue::from_bitslice(&bits),
+            Err(BinaryValueError::Overflow { max_bits: 256 })
+        );
+        assert!(BinaryValue::from_bitslice(&bits[..256]).is_ok());
+        // bits past the 256 bit value are exported unset
+        let value = BinaryValue::from_bitslice(&bits[..256]).unwrap();
+        let flags = value.bitfield_bitvec::<257>();
+        assert!(flags[..256].iter().all(|flag| *flag));
+        assert!(!flags[256]);
+        assert_eq!(value.to_bitvec().count_ones(), 256);
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let value = BinaryValue::from_u8(0x2a);
+        assert_eq!(BinaryValue::from_hex(&value.to_hex()), Ok(value));
+        assert_eq!(
+            BinaryValue::from_hex("2a"),
+            Err(BinaryValueError::InvalidHex)
+        );
+        assert_eq!(
+            BinaryValue::from_hex(&"zz".repeat(32)),
+            Err(BinaryValueError::InvalidHex)
+        );
+        let max = BinaryValue::from_repr([0xff; 32]);
+        assert_eq!(max.try_to_fp(), Err(BinaryValueError::NotInField));
+    }
+
+    #[test]
+    fn le_bytes_round_trip() {
+        // bits in every byte, including the top byte that lower_u128 drops
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37) | 1;
+        }
+        let value = BinaryValue::from_le_bytes(bytes);
+        assert_eq!(value.to_le_bytes(), bytes);
+        assert!(value.value[248]);
+        assert_eq!(BinaryValue::from_hex(&value.to_hex()), Ok(value));
+        assert_eq!(value.to_hex(), hex::encode(bytes));
+        // byte 0 holds the least significant bits
+        assert_eq!(BinaryValue::from_u8(0x2a).to_le_bytes()[0], 0x2a);
+    }
+
+    #[test]
+    fn try_lower_u128_truncation() {
+        let mut repr = [0u8; 32];
+        repr[..16].copy_from_slice(&u128::MAX.to_le_bytes());
+        let value = BinaryValue::from_le_bytes(repr);
+        assert_eq!(value.try_lower_u128(), Ok(u128::MAX));
+        // any bit at or above 128 is rejected rather than dropped
+        for bit in [128, 200, 255] {
+            let mut wide = value;
+            wide.value.set(bit, true);
+            assert_eq!(wide.lower_u128(), u128::MAX);
+            assert_eq!(
+                wide.try_lower_u128(),
+                Err(BinaryValueError::Overflow { max_bits: 128 })
+            );
+        }
+    }
+
+    #[test]
+    fn le_bytes_match_fp() {
+        // from_fp reads the canonical little endian encoding of the field element
+        for element in [Fp::zero(), Fp::from(42), -Fp::one()] {
+            let value = BinaryValue::from_fp(element);
+            assert_eq!(value.to_le_bytes(), element.to_repr());
+            assert_eq!(BinaryValue::from_le_bytes(element.to_repr()), value);
+            assert_eq!(value.try_to_fp(), Ok(element));
+        }
+        let state = Fp::from_u128(PATTERN_1_STATE);
+        assert_eq!(
+            BinaryValue::from_fp(state).try_lower_u128(),
+            Ok(PATTERN_1_STATE)
+        );
+        // -1 sets bits above 128, so it can only be read back as a field element
+        assert!(BinaryValue::from_fp(-Fp::one()).try_lower_u128().is_err());
+    }
+
+    #[test]
+    fn grid_round_trip() {
+        let value = BinaryValue::from_grid_string(
+            "
+            ....#.....
+            ....#.....
+            ...##.....
+            ...#......
+            ..........
+        ",
+        )
+        .unwrap();
+        assert_eq!(
+            value.iter_set_bits().collect::<Vec<_>>(),
+            [4, 14, 23, 24, 33]
+        );
+        let grid = value.to_grid_string();
+        assert_eq!(grid.lines().count(), 10);
+        assert_eq!(grid.lines().nth(2), Some("...##....."));
+        assert_eq!(BinaryValue::from_grid_string(&grid), Ok(value));
+        // short rows, unknown cells and an 11th row are rejected
+        assert_eq!(
+            BinaryValue::from_grid_string(".......... ........."),
+            Err(BinaryValueError::InvalidGrid { row: 1 })
+        );
+        assert_eq!(
+            BinaryValue::from_grid_string("....x....."),
+            Err(BinaryValueError::InvalidGrid { row: 0 })
+        );
+        assert_eq!(
+            BinaryValue::from_grid_string(&"..........\n".repeat(11)),
+            Err(BinaryValueError::InvalidGrid { row: 10 })
+        );
+    }
+
+    #[test]
+    fn popcount_ranges() {
+        let mut repr = [0u8; 32];
+        repr[..16].copy_from_slice(&PATTERN_1_STATE.to_le_bytes());
+        let state = BinaryValue::from_repr(repr);
+        assert_eq!(state.popcount_range(0, BOARD_SIZE), 17);
+        // ranges within a limb, across the limb boundary at bit 64 and empty ranges
+        for (lo, hi) in [
+            (0, 10),
+            (30, 40),
+            (60, 70),
+            (0, 64),
+            (64, 128),
+            (63, 65),
+            (5, 5),
+        ] {
+            assert_eq!(
+                state.popcount_range(lo, hi),
+                state.mask(lo, hi).value.count_ones() as u32,
+                "bits {}..{}",
+                lo,
+                hi
+            );
+        }
+        // every limb of a saturated value
+        let max = BinaryValue::from_repr([0xff; 32]);
+        assert_eq!(max.popcount_range(0, 256), 256);
+        assert_eq!(max.popcount_range(1, 255), 254);
+        assert_eq!(max.popcount_range(100, 200), 100);
+    }
+
+    #[test]
+    fn mask_rows() {
+        let mut repr = [0u8; 32];
+        repr[..16].copy_from_slice(&PATTERN_1_STATE.to_le_bytes());
+        let state = BinaryValue::from_repr(repr);
+        assert_eq!(state.row(0).lower_u128(), PATTERN_1_STATE & 0x3ff);
+        for r in 0..10 {
+            let row = state.row(r);
+            assert_eq!(row.lower_u128(), PATTERN_1_STATE >> (r * 10) & 0x3ff);
+            assert!(row.value[10..].not_any());
+        }
+        // bit 10 of the value is bit 0 of the mask
+        let mut value = U256::ZERO;
+        value.set(10, true);
+        value.set(20, true);
+        let masked = BinaryValue::new_unchecked(value).mask(10, 20);
+        assert_eq!(masked.iter_set_bits().collect::<Vec<_>>(), [0]);
+        // masks reach the top bit and shifts past it empty the value
+        let full = BinaryValue::from_repr([0xff; 32]);
+        assert_eq!(full.mask(0, 256), full);
+        assert_eq!(full.mask(250, 256), BinaryValue::bit_mask(6));
+        assert_eq!(full.mask(256, 256), BinaryValue::empty());
+        assert_eq!(full >> 300, BinaryValue::empty());
+    }
+
+    #[test]
+    fn new_shot_popcount() {
+        let mut value = U256::ZERO;
+        assert_eq!(
+            BinaryValue::new_shot(value),
+            Err(BinaryValueError::InvalidShot { popcount: 0 })
+        );
+        value.set(42, true);
+        assert!(BinaryValue::new_shot(value).is_ok());
+        value.set(43, true);
+        assert_eq!(
+            BinaryValue::new_shot(value),
+            Err(BinaryValueError::InvalidShot { popcount: 2 })
+        );
+        let mut value = U256::ZERO;
+        value.set(BOARD_SIZE, true);
+        assert_eq!(
+            BinaryValue::new_shot(value),
+            Err(BinaryValueError::Overflow {
+                max_bits: BOARD_SIZE
+            })
+        );
+    }
+
+    #[test]
+    fn bitfield_byte_order() {
+        let mut repr = [0u8; 32];
+        repr[..16].copy_from_slice(&PATTERN_1_STATE.to_le_bytes());
+        let value = BinaryValue::from_repr(repr);
+        let le = value.bitfield::<Fp, BOARD_SIZE>();
+        let mut be = value.bitfield_be::<Fp, BOARD_SIZE>();
+        be.reverse();
+        assert_eq!(le, be);
+        assert_eq!(le, value.bitfield_ordered(BitOrder::LittleEndian));
+    }
+
+    #[test]
+    fn bitfield_circuit_order() {
+        // Num2BitsChip constrains value = sum(bits[i] * 2^i), so bitfield must stay little endian
+        let value = BinaryValue::from_u8(0b0000_0110);
+        let (recomposed, _) = value
+            .bitfield::<Fp, 8>()
+            .iter()
+            .fold((Fp::zero(), Fp::one()), |(sum, weight), bit| {
+                (sum + *bit * weight, weight.double())
+            });
+        assert_eq!(recomposed, value.to_fp::<Fp>());
+        assert_eq!(value.bitfield_be::<Fp, 8>()[5], Fp::one());
+    }
+
+    #[test]
+    fn iter_set_bits_matches_scan() {
+        // set bits at both ends of every limb and none in the third limb
+        let mut value = BinaryValue::empty();
+        for i in [0, 1, 63, 64, 100, 127, 192, 255] {
+            value.value.set(i, true);
+        }
+        let expected: Vec<usize> = (0..256).filter(|i| value.value[*i]).collect();
+        assert_eq!(value.iter_set_bits().collect::<Vec<_>>(), expected);
+        assert_eq!(BinaryValue::empty().iter_set_bits().next(), None);
+        assert_eq!(
+            BinaryValue::from_repr([0xff; 32]).iter_set_bits().count(),
+            256
+        );
+    }
+
+    #[test]
+    fn to_fp_matches_u128_path() {
+        // every 100 bit board state fits in 128 bits, where both conversions agree
+        for state in [0, 1, PATTERN_1_STATE, (1u128 << BOARD_SIZE) - 1, u128::MAX] {
+            let mut repr = [0u8; 32];
+            repr[..16].copy_from_slice(&state.to_le_bytes());
+            let value = BinaryValue::from_repr(repr);
+            assert_eq!(value.to_fp::<Fp>(), Fp::from_u128(value.lower_u128()));
+            assert_eq!(value.to_fp::<Fp>(), value.try_to_fp().unwrap());
+        }
+    }
+
+    #[test]
+    fn to_fp_wide_values() {
+        // bits above 128 are kept by to_fp and dropped by lower_u128
+        let mut wide = BinaryValue::from_u8(1);
+        wide.value.set(128, true);
+        let expected = Fp::from_u128(1 << 64).square() + Fp::one();
+        assert_eq!(wide.to_fp::<Fp>(), expected);
+        assert_eq!(wide.to_fp::<Fp>(), wide.try_to_fp().unwrap());
+        assert_eq!(Fp::from_u128(wide.lower_u128()), Fp::one());
+        // values at or above the modulus are reduced instead of rejected
+        let full = BinaryValue::from_repr([0xff; 32]);
+        assert!(full.try_to_fp().is_err());
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&[0xff; 32]);
+        assert_eq!(full.to_fp::<Fp>(), Fp::from_bytes_wide(&bytes));
+    }
+
+    #[test]
+    fn duplicate_shots() {
+        let mut fired = HashSet::new();
+        for (x, y) in [(3, 4), (9, 9), (0, 0)] {
+            assert!(fired.insert(serialize::<1>([x], [y])));
+        }
+        // firing at (3, 4) again is detected as a duplicate
+        assert!(!fired.insert(serialize::<1>([3], [4])));
+        assert_eq!(fired.len(), 3);
+    }
+
+    #[test]
+    fn numeric_ordering() {
+        let bits = |indices: &[usize]| {
+            let mut value = U256::ZERO;
+            for i in indices {
+                value.set(*i, true);
+            }
+            BinaryValue::new_unchecked(value)
+        };
+        let sorted = [
+            BinaryValue::empty(),
+            bits(&[0]),
+            bits(&[0, 1, 2, 3, 4, 5, 6, 7]),
+            // bit 8 outranks every bit of the least significant byte
+            bits(&[8]),
+            bits(&[0, 99]),
+            bits(&[1, 99]),
+            bits(&[255]),
+        ];
+        let set: BTreeSet<BinaryValue> = sorted.iter().rev().copied().collect();
+        assert!(set.iter().eq(sorted.iter()));
+        assert_eq!(
+            BinaryValue::from_u8(200).cmp(&BinaryValue::from_u8(100)),
+            Ordering::Greater
+        );
     }
 }
@@ -1,15 +1,59 @@
 use crate::utils::binary::U256;
+#[cfg(feature = "std")]
+use halo2_proofs::pasta::pallas;
 
 use {
     crate::utils::{
-        binary::BinaryValue,
+        binary::{BinaryValue, BinaryValueError},
+        coordinate::Coordinate,
         deck::Deck,
-        ship::{Ship, WitnessOption, DEFAULT_WITNESS_OPTIONS},
+        ship::{Ship, ShipType, WitnessOption, DEFAULT_WITNESS_OPTIONS},
     },
+    alloc::{format, string::String, vec::Vec},
     bitvec::prelude::*,
 };
 
 pub const BOARD_SIZE: usize = 100;
+// length of the little endian board state encoding in bytes (100 bits rounded up to whole bytes)
+pub const BOARD_ENCODING_SIZE: usize = 13;
+
+/**
+ * Game rules enforced by the board circuit that a board placement can break
+ * @dev returned by Board::is_valid() to explain why a board proof would fail
+ */
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameRuleViolation {
+    // ship has no bits set in either the horizontal or vertical commitment
+    Unplaced {
+        ship: ShipType,
+    },
+    // ship has bits set in both the horizontal and vertical commitment
+    DualPlacement {
+        ship: ShipType,
+    },
+    // ship placement does not set exactly ship length bits
+    WrongLength {
+        ship: ShipType,
+        expected: usize,
+        found: usize,
+    },
+    // ship placement bits are not one consecutive run
+    NotContiguous {
+        ship: ShipType,
+    },
+    // ship placement leaves the board or wraps onto the next row/ column
+    OutOfBounds {
+        ship: ShipType,
+    },
+    // more than one ship occupies the board cell
+    Collision {
+        cell: usize,
+    },
+    // board state does not match the ship placements at the board cell
+    StateMismatch {
+        cell: usize,
+    },
+}
 
 // Definition of all state data needed to create board commitments
 #[derive(Clone, Copy, Debug)]
@@ -80,21 +124,233 @@ impl Board {
         for i in 0..ships.len() {
             if ships[i].is_some() {
                 let ship = ships[i].unwrap();
-                let placement = ship.witness(utilities[i]);
-                for j in 0..BOARD_SIZE {
-                    // transpoe horizontal
-                    if placement[0].value[j] {
-                        state.get_mut(j).unwrap().set(true);
-                    };
-                    // transpose horizontal
-                    let v_index = j % 10 * 10 + j / 10;
-                    if placement[1].value[j] {
-                        state.get_mut(v_index).unwrap().set(true);
-                    };
+                let placement = ship.claimed(utilities[i]);
+                // transpose horizontal
+                for j in placement[0].iter_set_bits().take_while(|j| *j < BOARD_SIZE) {
+                    state.set(j, true);
+                }
+                // transpose vertical
+                for j in placement[1].iter_set_bits().take_while(|j| *j < BOARD_SIZE) {
+                    state.set(j % 10 * 10 + j / 10, true);
+                }
+            }
+        }
+        BinaryValue::new_unchecked(state)
+    }
+
+    /**
+     * Enumerate the cells occupied by the placed ships
+     *
+     * @return - (x, y) coordinate of every occupied cell in ascending (y * 10 + x) order
+     */
+    pub fn cells(&self) -> impl Iterator<Item = (u8, u8)> {
+        self.state(DEFAULT_WITNESS_OPTIONS)
+            .iter_set_bits()
+            .map(|index| ((index % 10) as u8, (index / 10) as u8))
+    }
+
+    /**
+     * Compute the field element a board commitment is made to
+     * @dev canonical replacement for both state().to_fp() and from_u128(state().lower_u128());
+     *      the two agree for any state below 2^128, which every 100 bit board state is
+     *
+     * @param utilities - Witness utility options for testing malicious cases
+     * @return - board state as the pedersen commitment message
+     */
+    #[cfg(feature = "std")]
+    pub fn commitment_preimage(&self, utilities: [WitnessOption; 5]) -> pallas::Base {
+        self.state(utilities).to_fp()
+    }
+
+    /**
+     * Encode the board state as little endian bytes
+     *
+     * @return - 13 byte little endian encoding of the 100 bit board state
+     */
+    pub fn to_bytes(&self) -> [u8; BOARD_ENCODING_SIZE] {
+        self.state(DEFAULT_WITNESS_OPTIONS).value.into_inner()[..BOARD_ENCODING_SIZE]
+            .try_into()
+            .unwrap()
+    }
+
+    /**
+     * Check the board against every rule the board circuit enforces without running the prover
+     *
+     * @return - Ok if a board proof would verify, or every rule the placement violates
+     */
+    pub fn is_valid(&self) -> Result<(), Vec<GameRuleViolation>> {
+        self.is_valid_witness(DEFAULT_WITNESS_OPTIONS)
+    }
+
+    /**
+     * Check the witness produced for the board against every rule the board circuit enforces
+     * @dev mirrors the placement, transpose and board state constraints of BoardChip
+     *
+     * @param utilities - Witness utility options for testing malicious cases
+     * @return - Ok if a board proof of this witness would verify, or every rule the witness violates
+     */
+    pub fn is_valid_witness(
+        &self,
+        utilities: [WitnessOption; 5],
+    ) -> Result<(), Vec<GameRuleViolation>> {
+        let ship_types = [
+            ShipType::Carrier,
+            ShipType::Battleship,
+            ShipType::Cruiser,
+            ShipType::Submarine,
+            ShipType::Destroyer,
+        ];
+        let mut violations = Vec::<GameRuleViolation>::new();
+        // number of witnessed placement bits on each (untransposed) board cell
+        let mut occupied = [0usize; BOARD_SIZE];
+        for (i, ship_type) in ship_types.into_iter().enumerate() {
+            let ship = match self.ships.iterator()[i] {
+                Some(ship) if ship.x < 10 && ship.y < 10 => ship,
+                Some(_) => {
+                    violations.push(GameRuleViolation::OutOfBounds { ship: ship_type });
+                    continue;
                 }
+                None => {
+                    violations.push(GameRuleViolation::Unplaced { ship: ship_type });
+                    continue;
+                }
+            };
+            let [horizontal, vertical] = ship.witness(utilities[i]);
+            // the circuit transposes every witnessed bit onto the board, valid or not
+            for index in horizontal.value[..BOARD_SIZE].iter_ones() {
+                occupied[index] += 1;
+            }
+            for index in vertical.value[..BOARD_SIZE].iter_ones() {
+                occupied[index % 10 * 10 + index / 10] += 1;
+            }
+            if horizontal.value[BOARD_SIZE..].any() || vertical.value[BOARD_SIZE..].any() {
+                violations.push(GameRuleViolation::OutOfBounds { ship: ship_type });
+                continue;
+            }
+            // only one orientation may be placed
+            let placement = match (horizontal.value.any(), vertical.value.any()) {
+                (true, true) => {
+                    violations.push(GameRuleViolation::DualPlacement { ship: ship_type });
+                    continue;
+                }
+                (false, false) => {
+                    violations.push(GameRuleViolation::Unplaced { ship: ship_type });
+                    continue;
+                }
+                (true, false) => horizontal,
+                (false, true) => vertical,
+            };
+            // placement must be one run of ship length bits within a single row
+            let length = ship_type.length();
+            let found = placement.value.count_ones();
+            let start = placement.value.first_one().unwrap();
+            let end = placement.value.last_one().unwrap();
+            if found != length {
+                violations.push(GameRuleViolation::WrongLength {
+                    ship: ship_type,
+                    expected: length,
+                    found,
+                });
+            } else if end - start + 1 != length {
+                violations.push(GameRuleViolation::NotContiguous { ship: ship_type });
+            } else if start / 10 != end / 10 {
+                violations.push(GameRuleViolation::OutOfBounds { ship: ship_type });
             }
         }
-        BinaryValue::new(state)
+        // placed ships must not overlap and must match the claimed board state
+        let state = self.state(utilities);
+        for (cell, count) in occupied.into_iter().enumerate() {
+            if count > 1 {
+                violations.push(GameRuleViolation::Collision { cell });
+            } else if state.value[cell] != (count == 1) {
+                violations.push(GameRuleViolation::StateMismatch { cell });
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /**
+     * Determine whether a shot at a given coordinate hits a ship on the board
+     *
+     * @param x - horizontal coordinate of the shot [0-9]
+     * @param y - vertical coordinate of the shot [0-9]
+     * @return - true if the cell is occupied by a ship, or an error if the coordinate is off the board
+     */
+    pub fn hit(&self, x: u8, y: u8) -> Result<bool, BinaryValueError> {
+        Ok(self.hit_at(Coordinate::new(x, y)?))
+    }
+
+    /**
+     * Determine whether a shot at a validated coordinate hits a ship on the board
+     *
+     * @param coordinate - cell of the board shot at
+     * @return - true if the cell is occupied by a ship
+     */
+    pub fn hit_at(&self, coordinate: Coordinate) -> bool {
+        self.state(DEFAULT_WITNESS_OPTIONS).value[coordinate.index()]
+    }
+
+    /**
+     * Determine the honest hit assertion for a serialized shot against the board state
+     *
+     * @param shot - 100-bit shot commitment with exactly one bit flipped
+     * @return - true if the shot bit is flipped in the board state, or an error if the shot is invalid
+     */
+    pub fn evaluate_shot(&self, shot: &BinaryValue) -> Result<bool, BinaryValueError> {
+        let shot = BinaryValue::new_shot(shot.value)?;
+        let index = shot.value.first_one().unwrap();
+        Ok(self.state(DEFAULT_WITNESS_OPTIONS).value[index])
+    }
+
+    /**
+     * Count the hits landed on the board by a sequence of shots
+     * @dev out-of-circuit reference for the ShotChip running sum output
+     *
+     * @param shots - serialized shot commitments fired at the board
+     * @return - number of shot bits that are set in the board state, summed over all shots
+     */
+    pub fn hit_count(&self, shots: &[BinaryValue]) -> usize {
+        let state = self.state(DEFAULT_WITNESS_OPTIONS);
+        shots
+            .iter()
+            .map(|shot| (*shot & state).popcount_range(0, BOARD_SIZE) as usize)
+            .sum()
+    }
+
+    /**
+     * Count the cells of a row occupied by ships
+     *
+     * @param r - the row to count, 0-9
+     * @return - number of occupied cells in row r
+     */
+    pub fn row_count(&self, r: u8) -> u8 {
+        let lo = r as usize * 10;
+        self.state(DEFAULT_WITNESS_OPTIONS).popcount_range(lo, lo + 10) as u8
+    }
+
+    /**
+     * Determine whether a ship has had every cell it occupies hit by a sequence of shots
+     *
+     * @param ship_index - index of the ship in the deck [carrier, battleship, cruiser, submarine, destroyer]
+     * @param shots - serialized shot commitments fired at the board
+     * @return - true if the ship is placed and all of its cells are covered by the union of the shots
+     */
+    pub fn is_sunk_by(&self, ship_index: usize, shots: &[BinaryValue]) -> bool {
+        let ship = match self.ships.iterator().get(ship_index) {
+            Some(Some(ship)) => *ship,
+            _ => return false,
+        };
+        let mut covered = BinaryValue::empty().value;
+        for shot in shots {
+            covered |= shot.value;
+        }
+        ship.coordinates(false)
+            .into_iter()
+            .all(|coordinate| covered[coordinate])
     }
 
     /**
@@ -119,30 +375,260 @@ impl Board {
         full_witness.try_into().unwrap()
     }
 
+    /**
+     * Format the ship commitments as in witness() and pass each through an arbitrary corruption
+     * @dev used to inject malicious witnesses beyond the cases covered by WitnessOption
+     *
+     * @param utilities - Witness utility options applied before mutation
+     * @param mutate - given the commitment index [0-9] and value, return the value to witness
+     * @return - array of H/V ship commitment values after mutation
+     */
+    pub fn mutate_witness(
+        self,
+        utilities: [WitnessOption; 5],
+        mutate: impl Fn(usize, BinaryValue) -> BinaryValue,
+    ) -> [BinaryValue; 10] {
+        let mut witness = self.witness(utilities);
+        for (i, commitment) in witness.iter_mut().enumerate() {
+            *commitment = mutate(i, *commitment);
+        }
+        witness
+    }
+
+    /**
+     * Render ASCII representing the ship placement
+     *
+     * @return - 10x10 grid of board state bits with (0, 0) in the bottom left
+     */
+    pub fn ascii(&self) -> String {
+        let state = self.state(DEFAULT_WITNESS_OPTIONS);
+        render_grid(|i| if state.value[i] { '1' } else { '0' })
+    }
+
     /**
      * Render ASCII to the console representing the ship placement
      */
+    #[cfg(feature = "std")]
     pub fn print(&self) {
-        let mut lines = Vec::<String>::new();
-        for i in 0..BOARD_SIZE {
-            if i % 10 == 0 {
-                let mut out = format!("{} |", i / 10);
-                for j in 0..10 {
-                    out = format!(
-                        "{} {}",
-                        out,
-                        self.state(DEFAULT_WITNESS_OPTIONS).value[i + j] as u8
-                    );
-                }
-                lines.push(out);
-            }
+        println!("{}", self.ascii());
+    }
+}
+
+/**
+ * Render a 10x10 grid of board cells with x/ y axes, shared by the board and opponent view printers
+ *
+ * @param cell - character to draw for each serialized board cell index (y * 10 + x)
+ * @return - grid with row 9 at the top and (0, 0) in the bottom left
+ */
+pub(crate) fn render_grid(cell: impl Fn(usize) -> char) -> String {
+    let mut lines = Vec::<String>::new();
+    for i in (0..BOARD_SIZE).step_by(10) {
+        let mut out = format!("{} |", i / 10);
+        for j in 0..10 {
+            out = format!("{} {}", out, cell(i + j));
+        }
+        lines.push(out);
+    }
+    lines.push(String::from(" (Y)"));
+    lines.reverse();
+    lines.push(String::from("   -------------------- (X)"));
+    lines.push(String::from("    0 1 2 3 4 5 6 7 8 9"));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{
+            fixtures::{pattern_1, pattern_2, PATTERN_1, PATTERN_1_STATE, PATTERN_2},
+            ship::STANDARD_FLEET,
+            shot::serialize,
+        },
+        halo2_proofs::arithmetic::FieldExt,
+        rand::{rngs::OsRng, RngCore},
+    };
+
+    #[test]
+    fn commitment_preimage_conversions_agree() {
+        // fixture boards, the empty and full boards, and random 100 bit states
+        let mut states = vec![0, PATTERN_1_STATE, (1u128 << BOARD_SIZE) - 1];
+        states.extend((0..1000).map(|_| {
+            let value = (OsRng.next_u64() as u128) << 64 | OsRng.next_u64() as u128;
+            value & ((1u128 << BOARD_SIZE) - 1)
+        }));
+        for state in states {
+            let mut repr = [0u8; 32];
+            repr[..16].copy_from_slice(&state.to_le_bytes());
+            let value = BinaryValue::from_repr(repr);
+            assert_eq!(value.to_fp::<pallas::Base>(), pallas::Base::from_u128(value.lower_u128()));
+        }
+        assert_eq!(
+            pattern_1().commitment_preimage(DEFAULT_WITNESS_OPTIONS),
+            pallas::Base::from_u128(PATTERN_1_STATE)
+        );
+        // they would diverge above 2^128, where lower_u128 truncates and to_fp does not
+        let mut wide = BinaryValue::empty();
+        wide.value.set(200, true);
+        assert_ne!(wide.to_fp::<pallas::Base>(), pallas::Base::from_u128(wide.lower_u128()));
+    }
+
+    #[test]
+    fn hit_matches_state() {
+        let board = pattern_1();
+        for index in 0..BOARD_SIZE {
+            let (x, y) = ((index % 10) as u8, (index / 10) as u8);
+            assert_eq!(board.hit(x, y), Ok(PATTERN_1_STATE >> index & 1 == 1));
+        }
+    }
+
+    #[test]
+    fn is_valid_patterns() {
+        assert_eq!(pattern_1().is_valid(), Ok(()));
+        assert_eq!(pattern_2().is_valid(), Ok(()));
+    }
+
+    #[test]
+    fn is_valid_violations() {
+        // carrier missing
+        let mut ships = PATTERN_1;
+        ships[0] = None;
+        assert_eq!(
+            Board::from(&Deck::from(ships)).is_valid(),
+            Err(vec![GameRuleViolation::Unplaced {
+                ship: ShipType::Carrier
+            }])
+        );
+        // cruiser placed horizontally at (9, 0) wraps onto the next row
+        let mut ships = PATTERN_2;
+        ships[2] = Some((9, 0, false));
+        assert_eq!(
+            Board::from(&Deck::from(ships)).is_valid(),
+            Err(vec![GameRuleViolation::OutOfBounds {
+                ship: ShipType::Cruiser
+            }])
+        );
+        // cruiser placed horizontally at (4, 1) overlaps the destroyer at (6, 1)
+        let mut ships = PATTERN_1;
+        ships[2] = Some((4, 1, false));
+        assert_eq!(
+            Board::from(&Deck::from(ships)).is_valid(),
+            Err(vec![GameRuleViolation::Collision { cell: 16 }])
+        );
+        // cruiser witnessed vertically over (1, 0)..(1, 2) while the board state claims (0, 1)..(2, 1)
+        let mut options = DEFAULT_WITNESS_OPTIONS;
+        options[2] = WitnessOption::SwappedOrientation;
+        assert_eq!(
+            pattern_1().is_valid_witness(options),
+            Err(vec![
+                GameRuleViolation::StateMismatch { cell: 1 },
+                GameRuleViolation::StateMismatch { cell: 10 },
+                GameRuleViolation::StateMismatch { cell: 12 },
+                GameRuleViolation::StateMismatch { cell: 21 }
+            ])
+        );
+        // battleship witnessed one bit too long
+        let mut options = DEFAULT_WITNESS_OPTIONS;
+        options[1] = WitnessOption::Oversized;
+        assert_eq!(
+            pattern_1().is_valid_witness(options),
+            Err(vec![GameRuleViolation::WrongLength {
+                ship: ShipType::Battleship,
+                expected: 4,
+                found: 5
+            }])
+        );
+    }
+
+    #[test]
+    fn to_bytes_matches_state() {
+        let bytes = pattern_1().to_bytes();
+        let mut state = [0u8; 16];
+        state[..13].copy_from_slice(&bytes);
+        assert_eq!(u128::from_le_bytes(state), PATTERN_1_STATE);
+    }
+
+    #[test]
+    fn cells_match_state() {
+        // every cell of a fully placed fleet is found
+        for board in [pattern_1(), pattern_2()] {
+            let cells: Vec<(u8, u8)> = board.cells().collect();
+            assert_eq!(cells.len(), STANDARD_FLEET.cells());
+            let expected: Vec<(u8, u8)> = (0..BOARD_SIZE as u8)
+                .map(|i| (i % 10, i / 10))
+                .filter(|(x, y)| board.hit(*x, *y).unwrap())
+                .collect();
+            assert_eq!(cells, expected);
         }
-        lines.push(String::from(" (Y)"));
-        lines.reverse();
-        lines.push(String::from("   -------------------- (X)"));
-        lines.push(String::from("    0 1 2 3 4 5 6 7 8 9"));
-        for line in lines {
-            println!("{}", line);
+        assert_eq!(Board::new().cells().next(), None);
+    }
+
+    #[test]
+    fn hit_off_board() {
+        assert_eq!(
+            pattern_1().hit(10, 0),
+            Err(BinaryValueError::InvalidCoordinate { x: 10, y: 0 })
+        );
+        assert_eq!(
+            pattern_1().hit(0, 255),
+            Err(BinaryValueError::InvalidCoordinate { x: 0, y: 255 })
+        );
+    }
+
+    #[test]
+    fn evaluate_multi_shot() {
+        let shot = serialize::<2>([3, 4], [3, 3]);
+        assert_eq!(
+            pattern_1().evaluate_shot(&shot),
+            Err(BinaryValueError::InvalidShot { popcount: 2 })
+        );
+        assert_eq!(
+            pattern_1().evaluate_shot(&BinaryValue::empty()),
+            Err(BinaryValueError::InvalidShot { popcount: 0 })
+        );
+    }
+
+    #[test]
+    fn hit_count_matches_hits() {
+        let board = pattern_1();
+        let shots: Vec<BinaryValue> = (0..100u8)
+            .map(|i| serialize::<1>([i % 10], [i / 10]))
+            .collect();
+        // every cell fired upon hits each of the 17 occupied cells exactly once
+        assert_eq!(board.hit_count(&shots), 17);
+        let hits = shots
+            .iter()
+            .filter(|shot| board.evaluate_shot(shot).unwrap())
+            .count();
+        assert_eq!(board.hit_count(&shots), hits);
+        assert_eq!(board.hit_count(&[]), 0);
+    }
+
+    #[test]
+    fn row_counts() {
+        for board in [pattern_1(), pattern_2()] {
+            for r in 0..10 {
+                let cells = (0..10).filter(|x| board.hit(*x, r).unwrap()).count();
+                assert_eq!(board.row_count(r) as usize, cells, "row {}", r);
+            }
+            let total: usize = (0..10).map(|r| board.row_count(r) as usize).sum();
+            assert_eq!(total, 17);
         }
     }
+
+    #[test]
+    fn is_sunk_by_ship_cells() {
+        let board = pattern_1();
+        // destroyer at (6, 1) horizontal covers (6, 1) and (7, 1)
+        let first = serialize::<1>([6], [1]);
+        let second = serialize::<1>([7], [1]);
+        assert!(!board.is_sunk_by(4, &[first]));
+        assert!(board.is_sunk_by(4, &[first, second]));
+        assert!(!board.is_sunk_by(0, &[first, second]));
+        // missing ships and out of range indices are never sunk
+        let mut deck = Deck::from(PATTERN_1);
+        deck.remove(ShipType::Destroyer);
+        assert!(!Board::from(&deck).is_sunk_by(4, &[]));
+        assert!(!board.is_sunk_by(5, &[]));
+    }
 }
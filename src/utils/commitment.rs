@@ -0,0 +1,297 @@
+use {
+    crate::utils::{
+        binary::{BinaryValue, BinaryValueError},
+        board::{Board, BOARD_SIZE},
+        coordinate::Coordinate,
+        error::BattleshipError,
+        pedersen::pedersen_coordinates,
+        ship::DEFAULT_WITNESS_OPTIONS,
+    },
+    halo2_gadgets::poseidon::primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+    halo2_proofs::{
+        arithmetic::{CurveAffine, FieldExt},
+        pasta::{
+            group::{ff::PrimeField, GroupEncoding},
+            pallas,
+        },
+    },
+};
+
+/**
+ * Public pedersen commitment to a board state
+ * @dev wraps the (x, y) coordinates of the commitment point in public input order
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoardCommitment(pub [pallas::Base; 2]);
+
+/**
+ * Board commitment compressed into 32 bytes for on-chain verification
+ * @dev little endian x coordinate with the parity of y in the top bit, i.e. the pallas point encoding
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CompressedCommitment(pub [u8; 32]);
+
+/**
+ * Shot commitment serialized as a 100 bit value with the (y * 10 + x)th bit flipped
+ * @dev unchecked so malicious shots can still be witnessed in tests; use ShotCommitment::new for coordinates
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ShotCommitment(pub BinaryValue);
+
+/**
+ * Commits to one board under both the Poseidon and the Pedersen scheme
+ * @dev migration tooling for cross-referencing on-chain records while deployments move between
+ *      schemes; the Poseidon commitment is computed out of circuit and is never proven
+ */
+pub struct CommitmentBridge;
+
+impl BoardCommitment {
+    /**
+     * Commit to a board state
+     * @dev panics if the commitment is the identity, i.e. an empty board with a zero trapdoor; use
+     *      try_commit() for boards and trapdoors that are not sampled by this crate
+     *
+     * @param board - the board state committed to
+     * @param trapdoor - randomly sampled blinding factor for the commitment
+     * @return - the (x, y) coordinates of the pedersen commitment to the board state
+     */
+    pub fn commit(board: &BinaryValue, trapdoor: &pallas::Scalar) -> BoardCommitment {
+        BoardCommitment::try_commit(board, trapdoor).unwrap()
+    }
+
+    /**
+     * Commit to a board state, rejecting a commitment to the identity point
+     *
+     * @param board - the board state committed to
+     * @param trapdoor - blinding factor for the commitment
+     * @return - the (x, y) coordinates of the pedersen commitment, or an error if it is the identity
+     */
+    pub fn try_commit(
+        board: &BinaryValue,
+        trapdoor: &pallas::Scalar,
+    ) -> Result<BoardCommitment, BattleshipError> {
+        pedersen_coordinates(&board.to_fp(), trapdoor).map(BoardCommitment)
+    }
+
+    /**
+     * Compress the commitment into its 32 byte point encoding
+     *
+     * @return - the compressed commitment
+     */
+    pub fn compress(&self) -> CompressedCommitment {
+        CompressedCommitment::compress(&self.0[0], &self.0[1]).unwrap()
+    }
+}
+
+impl CompressedCommitment {
+    /**
+     * Compress the affine coordinates of a commitment point
+     *
+     * @param x - x coordinate of the commitment
+     * @param y - y coordinate of the commitment
+     * @return - the compressed commitment, or an error if (x, y) is not on the curve
+     */
+    pub fn compress(
+        x: &pallas::Base,
+        y: &pallas::Base,
+    ) -> Result<CompressedCommitment, BattleshipError> {
+        Option::<pallas::Affine>::from(pallas::Affine::from_xy(*x, *y))
+            .map(|point| CompressedCommitment(point.to_bytes()))
+            .ok_or(BattleshipError::InvalidCommitmentPoint)
+    }
+
+    /**
+     * Recover the (x, y) coordinates circuits export from a compressed commitment
+     * @dev circuits still constrain both coordinates; decompress before verifying a proof
+     *
+     * @return - the board commitment, or an error if the bytes do not encode a non-identity point
+     */
+    pub fn decompress(&self) -> Result<BoardCommitment, BattleshipError> {
+        let point = Option::<pallas::Affine>::from(pallas::Affine::from_bytes(&self.0))
+            .ok_or(BattleshipError::InvalidCommitmentPoint)?;
+        let coordinates =
+            Option::<_>::from(point.coordinates()).ok_or(BattleshipError::IdentityCommitment)?;
+        Ok(BoardCommitment([*coordinates.x(), *coordinates.y()]))
+    }
+}
+
+impl CommitmentBridge {
+    /**
+     * Commit to a board with Poseidon
+     * @dev the trapdoor is a pallas scalar for Pedersen, so it is reduced into the base field
+     *
+     * @param board - the board committed to
+     * @param trapdoor - blinding factor shared with the Pedersen commitment
+     * @return - Poseidon(board state, trapdoor mod p)
+     */
+    pub fn poseidon(board: &Board, trapdoor: &pallas::Scalar) -> pallas::Base {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&trapdoor.to_repr());
+        let blinding = pallas::Base::from_bytes_wide(&wide);
+        let state = board.state(DEFAULT_WITNESS_OPTIONS).to_fp();
+        poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([state, blinding])
+    }
+
+    /**
+     * Commit to a board under both schemes with the same trapdoor
+     * @dev panics if the Pedersen commitment is the identity, like BoardCommitment::commit()
+     *
+     * @param board - the board committed to
+     * @param trapdoor - blinding factor for both commitments
+     * @return - (Poseidon commitment, (x, y) coordinates of the Pedersen commitment)
+     */
+    pub fn compare(
+        board: &Board,
+        trapdoor: &pallas::Scalar,
+    ) -> (pallas::Base, (pallas::Base, pallas::Base)) {
+        let pedersen = BoardCommitment::commit(&board.state(DEFAULT_WITNESS_OPTIONS), trapdoor);
+        (
+            CommitmentBridge::poseidon(board, trapdoor),
+            (pedersen.0[0], pedersen.0[1]),
+        )
+    }
+}
+
+impl ShotCommitment {
+    /**
+     * Serialize a shot at an x, y coordinate
+     *
+     * @param x - horizontal coordinate of the shot
+     * @param y - vertical coordinate of the shot
+     * @return - the shot commitment, or an error if the coordinate is off the board
+     */
+    pub fn new(x: u8, y: u8) -> Result<ShotCommitment, BinaryValueError> {
+        Ok(ShotCommitment::from(Coordinate::new(x, y)?))
+    }
+
+    /**
+     * Export the shot commitment as the public input of a shot proof
+     *
+     * @return - the serialized shot as a pallas base field element
+     */
+    pub fn to_fp(&self) -> pallas::Base {
+        self.0.to_fp()
+    }
+}
+
+impl From<Coordinate> for ShotCommitment {
+    fn from(coordinate: Coordinate) -> ShotCommitment {
+        ShotCommitment(coordinate.to_shot())
+    }
+}
+
+/**
+ * Pack a shot and its result into the single public output of a packed shot proof
+ *
+ * @param x - horizontal coordinate of the shot
+ * @param y - vertical coordinate of the shot
+ * @param hit - whether the shot hit the board
+ * @return - y * 10 + x + hit * 100, or an error if the coordinate is off the board
+ */
+pub fn pack(x: u8, y: u8, hit: bool) -> Result<pallas::Base, BinaryValueError> {
+    if x >= 10 || y >= 10 {
+        return Err(BinaryValueError::InvalidCoordinate { x, y });
+    }
+    let packed = y as u64 * 10 + x as u64 + hit as u64 * BOARD_SIZE as u64;
+    Ok(pallas::Base::from(packed))
+}
+
+/**
+ * Unpack the public output of a packed shot proof
+ * @dev inverse of pack()
+ *
+ * @param packed - y * 10 + x + hit * 100
+ * @return - (x, y, hit), or None if the value does not pack a shot on the board
+ */
+pub fn unpack(packed: pallas::Base) -> Option<(u8, u8, bool)> {
+    let repr = packed.to_repr();
+    if repr[1..].iter().any(|byte| *byte != 0) || repr[0] as usize >= 2 * BOARD_SIZE {
+        return None;
+    }
+    let index = repr[0] % BOARD_SIZE as u8;
+    Some((index % 10, index / 10, repr[0] as usize >= BOARD_SIZE))
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::fixtures::{pattern_1, pattern_2},
+        halo2_proofs::arithmetic::Field,
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn bridge_deterministic() {
+        let board = pattern_1();
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let (poseidon, pedersen) = CommitmentBridge::compare(&board, &trapdoor);
+        // the same board and trapdoor always produce the same pair of commitments
+        assert_eq!(
+            CommitmentBridge::compare(&board, &trapdoor),
+            (poseidon, pedersen)
+        );
+        let commitment = BoardCommitment::commit(&board.state(DEFAULT_WITNESS_OPTIONS), &trapdoor);
+        assert_eq!(pedersen, (commitment.0[0], commitment.0[1]));
+        // both schemes bind the board and the trapdoor
+        let (other, _) = CommitmentBridge::compare(&pattern_2(), &trapdoor);
+        assert_ne!(other, poseidon);
+        let (other, _) = CommitmentBridge::compare(&board, &(trapdoor + pallas::Scalar::one()));
+        assert_ne!(other, poseidon);
+    }
+
+    #[test]
+    fn compression_round_trip() {
+        let boards = [
+            BinaryValue::empty(),
+            pattern_1().state(DEFAULT_WITNESS_OPTIONS),
+            pattern_2().state(DEFAULT_WITNESS_OPTIONS),
+        ];
+        for board in boards {
+            for _ in 0..4 {
+                let trapdoor = pallas::Scalar::random(&mut OsRng);
+                let commitment = BoardCommitment::commit(&board, &trapdoor);
+                let compressed = commitment.compress();
+                assert_eq!(compressed.decompress(), Ok(commitment));
+                // -(x, y) shares x and only differs in the parity bit
+                let negated = CompressedCommitment::compress(&commitment.0[0], &-commitment.0[1]);
+                assert_eq!(negated.unwrap().0[..31], compressed.0[..31]);
+                assert_ne!(negated.unwrap().0[31], compressed.0[31]);
+            }
+        }
+        // (0, 0) is not on y^2 = x^3 + 5
+        assert_eq!(
+            CompressedCommitment::compress(&pallas::Base::zero(), &pallas::Base::zero()),
+            Err(BattleshipError::InvalidCommitmentPoint)
+        );
+        // the identity encodes as zero bytes and has no coordinates to export
+        assert_eq!(
+            CompressedCommitment([0; 32]).decompress(),
+            Err(BattleshipError::IdentityCommitment)
+        );
+        // an x coordinate at or above the modulus
+        assert_eq!(
+            CompressedCommitment([0xff; 32]).decompress(),
+            Err(BattleshipError::InvalidCommitmentPoint)
+        );
+    }
+
+    #[test]
+    fn pack_round_trip() {
+        for index in 0..BOARD_SIZE as u8 {
+            let (x, y) = (index % 10, index / 10);
+            for hit in [false, true] {
+                assert_eq!(unpack(pack(x, y, hit).unwrap()), Some((x, y, hit)));
+            }
+        }
+        assert_eq!(pack(3, 5, true), Ok(pallas::Base::from(153)));
+        assert_eq!(
+            pack(10, 0, false),
+            Err(BinaryValueError::InvalidCoordinate { x: 10, y: 0 })
+        );
+        // values past the last hit on (9, 9) do not pack a shot
+        assert_eq!(unpack(pallas::Base::from(199)), Some((9, 9, true)));
+        assert_eq!(unpack(pallas::Base::from(200)), None);
+        assert_eq!(unpack(-pallas::Base::one()), None);
+    }
+}
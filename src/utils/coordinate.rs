@@ -0,0 +1,169 @@
+use {
+    crate::utils::{
+        binary::{BinaryValue, BinaryValueError},
+        board::BOARD_SIZE,
+        shot::serialize,
+    },
+    core::{fmt, str::FromStr},
+};
+
+/**
+ * Cell of the board, validated to fall on the board when constructed
+ * @dev parsed from and displayed as battleship notation: column A-J for x, then row 1-10 for y
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Coordinate {
+    x: u8,
+    y: u8,
+}
+
+impl Coordinate {
+    /**
+     * Construct a coordinate if it falls on the board
+     *
+     * @param x - horizontal coordinate [0-9]
+     * @param y - vertical coordinate [0-9]
+     * @return - the coordinate, or an error if it is off the board
+     */
+    pub fn new(x: u8, y: u8) -> Result<Coordinate, BinaryValueError> {
+        if x >= 10 || y >= 10 {
+            return Err(BinaryValueError::InvalidCoordinate { x, y });
+        }
+        Ok(Coordinate { x, y })
+    }
+
+    /**
+     * Construct the coordinate of a bit of a serialized board or shot
+     *
+     * @param index - bit index y * 10 + x
+     * @return - the coordinate, or an error if the index is off the board
+     */
+    pub fn from_index(index: usize) -> Result<Coordinate, BinaryValueError> {
+        if index >= BOARD_SIZE {
+            return Err(BinaryValueError::Overflow {
+                max_bits: BOARD_SIZE,
+            });
+        }
+        Coordinate::new((index % 10) as u8, (index / 10) as u8)
+    }
+
+    // horizontal coordinate [0-9]
+    pub fn x(&self) -> u8 {
+        self.x
+    }
+
+    // vertical coordinate [0-9]
+    pub fn y(&self) -> u8 {
+        self.y
+    }
+
+    /**
+     * Return the bit of a serialized board or shot the coordinate maps to
+     *
+     * @return - y * 10 + x
+     */
+    pub fn index(&self) -> usize {
+        self.y as usize * 10 + self.x as usize
+    }
+
+    /**
+     * Serialize the coordinate into a shot commitment
+     *
+     * @return - 100-bit integer where the (y * 10 + x)th bit is flipped
+     */
+    pub fn to_shot(&self) -> BinaryValue {
+        serialize::<1>([self.x], [self.y])
+    }
+}
+
+impl FromStr for Coordinate {
+    type Err = BinaryValueError;
+
+    /**
+     * Parse battleship notation such as "B7"
+     * @dev a well formed column or row past the edge of the board (e.g. "K1", "A11") is reported as
+     *      InvalidCoordinate rather than InvalidCoordinateNotation
+     */
+    fn from_str(notation: &str) -> Result<Coordinate, BinaryValueError> {
+        let (column, row) = match notation.as_bytes() {
+            [column @ b'A'..=b'Z', row @ ..] if !row.is_empty() => (column - b'A', &notation[1..]),
+            _ => return Err(BinaryValueError::InvalidCoordinateNotation),
+        };
+        if row.starts_with('0') || !row.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(BinaryValueError::InvalidCoordinateNotation);
+        }
+        match row.parse::<u8>() {
+            Ok(row) if row > 0 => Coordinate::new(column, row - 1),
+            // rows that do not fit in a u8 are still numbers past the edge of the board
+            Err(_) => Err(BinaryValueError::InvalidCoordinate {
+                x: column,
+                y: u8::MAX,
+            }),
+            _ => Err(BinaryValueError::InvalidCoordinateNotation),
+        }
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", (b'A' + self.x) as char, self.y + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::utils::shot::deserialize, alloc::string::ToString};
+
+    #[test]
+    fn notation_round_trip() {
+        let coordinate: Coordinate = "B7".parse().unwrap();
+        assert_eq!((coordinate.x(), coordinate.y()), (1, 6));
+        assert_eq!(coordinate.index(), 61);
+        // the first and far corner of the board
+        assert_eq!("A1".parse(), Coordinate::new(0, 0));
+        assert_eq!("J10".parse(), Coordinate::new(9, 9));
+        for index in 0..BOARD_SIZE {
+            let coordinate = Coordinate::from_index(index).unwrap();
+            assert_eq!(coordinate.index(), index);
+            assert_eq!(coordinate.to_string().parse(), Ok(coordinate));
+            assert_eq!(
+                deserialize(coordinate.to_shot()),
+                Ok((coordinate.x(), coordinate.y()))
+            );
+        }
+    }
+
+    #[test]
+    fn notation_rejected() {
+        // well formed but off the board
+        assert_eq!(
+            "K1".parse::<Coordinate>(),
+            Err(BinaryValueError::InvalidCoordinate { x: 10, y: 0 })
+        );
+        assert_eq!(
+            "A11".parse::<Coordinate>(),
+            Err(BinaryValueError::InvalidCoordinate { x: 0, y: 10 })
+        );
+        assert_eq!(
+            "A300".parse::<Coordinate>(),
+            Err(BinaryValueError::InvalidCoordinate { x: 0, y: u8::MAX })
+        );
+        // malformed
+        for notation in [
+            "", "A", "7B", "b7", "A0", "A01", "A-1", "A+1", "A1 ", " A1", "AA1",
+        ] {
+            assert_eq!(
+                notation.parse::<Coordinate>(),
+                Err(BinaryValueError::InvalidCoordinateNotation),
+                "{}",
+                notation
+            );
+        }
+        assert_eq!(
+            Coordinate::from_index(BOARD_SIZE),
+            Err(BinaryValueError::Overflow {
+                max_bits: BOARD_SIZE
+            })
+        );
+    }
+}
@@ -1,7 +1,76 @@
 use {
     crate::utils::ship::{Ship, ShipType},
-    std::ops::{Index, IndexMut},
+    alloc::{format, string::String, vec::Vec},
+    bitvec::prelude::*,
+    core::ops::{Index, IndexMut},
 };
+#[cfg(feature = "std")]
+use {
+    crate::utils::{board::Board, ship::DEFAULT_WITNESS_OPTIONS},
+    rand::Rng,
+};
+
+// version nibble written by Deck::to_bytes()
+pub const DECK_ENCODING_VERSION: u8 = 1;
+// length of the packed deck encoding in bytes
+pub const DECK_ENCODING_SIZE: usize = 7;
+
+/**
+ * Errors returned when parsing a deck from its string notation
+ * @dev offset is the byte offset into the notation string where the problem starts
+ */
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DeckNotationError {
+    // ship code is not one of CA, BS, CR, SU, DE
+    UnknownShip { offset: usize },
+    // separator between the parts of a placement is missing
+    MissingSeparator { offset: usize, expected: char },
+    // column is not a letter A-J
+    InvalidColumn { offset: usize },
+    // row is not a number 1-10
+    InvalidRow { offset: usize },
+    // orientation is not H or V
+    InvalidOrientation { offset: usize },
+    // ship is placed more than once
+    DuplicateShip { offset: usize },
+    // ship placement extends past the edge of the board
+    OffBoard { offset: usize },
+}
+
+impl core::fmt::Display for DeckNotationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeckNotationError::UnknownShip { offset } => {
+                write!(
+                    f,
+                    "Expected ship code CA, BS, CR, SU or DE at byte {}",
+                    offset
+                )
+            }
+            DeckNotationError::MissingSeparator { offset, expected } => {
+                write!(f, "Expected '{}' at byte {}", expected, offset)
+            }
+            DeckNotationError::InvalidColumn { offset } => {
+                write!(f, "Expected column A-J at byte {}", offset)
+            }
+            DeckNotationError::InvalidRow { offset } => {
+                write!(f, "Expected row 1-10 at byte {}", offset)
+            }
+            DeckNotationError::InvalidOrientation { offset } => {
+                write!(f, "Expected orientation H or V at byte {}", offset)
+            }
+            DeckNotationError::DuplicateShip { offset } => {
+                write!(f, "Ship at byte {} is already placed", offset)
+            }
+            DeckNotationError::OffBoard { offset } => {
+                write!(f, "Ship at byte {} does not fit on the board", offset)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeckNotationError {}
 
 // contains all 5 ship commitments
 #[derive(Clone, Copy, Debug)]
@@ -44,6 +113,36 @@ impl Deck {
         deck
     }
 
+    /**
+     * Return a deck with every ship placed uniformly at random without overlap
+     * @dev rejection samples each ship in enum order against the ships already placed
+     *
+     * @param rng - source of randomness for the placements
+     * @return - a deck with 5 valid & randomly placed ships
+     */
+    #[cfg(feature = "std")]
+    pub fn random<R: Rng>(rng: &mut R) -> Self {
+        let mut deck = Deck::new();
+        for ship_type in [
+            ShipType::Carrier,
+            ShipType::Battleship,
+            ShipType::Cruiser,
+            ShipType::Submarine,
+            ShipType::Destroyer,
+        ] {
+            let placements: Vec<Ship> = Ship::placements(ship_type).collect();
+            let occupied = Board::from(&deck).state(DEFAULT_WITNESS_OPTIONS);
+            loop {
+                let ship = placements[rng.gen_range(0..placements.len())];
+                if (ship.bits(false).value & occupied.value).not_any() {
+                    deck.add(ship);
+                    break;
+                }
+            }
+        }
+        deck
+    }
+
     /**
      * Selectively place ships onto the board (instead of all 5)
      * @dev index corresponds to [carrier, battleship, cruiser, submarine, destroyer]
@@ -70,6 +169,209 @@ impl Deck {
         deck
     }
 
+    /**
+     * Selectively place ships onto the board, naming each ship instead of relying on array order
+     * @dev every position of Deck::from() has the same type, so swapping two compiles but gives the
+     *      ships the wrong lengths
+     *
+     * @param carrier - optional (x, y, z) placement of the length 5 carrier
+     * @param battleship - optional (x, y, z) placement of the length 4 battleship
+     * @param cruiser - optional (x, y, z) placement of the length 3 cruiser
+     * @param submarine - optional (x, y, z) placement of the length 3 submarine
+     * @param destroyer - optional (x, y, z) placement of the length 2 destroyer
+     * @return - the deck with each placed ship
+     */
+    pub fn from_named(
+        carrier: Option<(u8, u8, bool)>,
+        battleship: Option<(u8, u8, bool)>,
+        cruiser: Option<(u8, u8, bool)>,
+        submarine: Option<(u8, u8, bool)>,
+        destroyer: Option<(u8, u8, bool)>,
+    ) -> Self {
+        Deck::from([carrier, battleship, cruiser, submarine, destroyer])
+    }
+
+    /**
+     * Selectively place ships onto the board, rejecting any placement that does not fit on the board
+     * @dev index corresponds to [carrier, battleship, cruiser, submarine, destroyer]
+     *
+     * @param ships - array of optional ship placements
+     * @return - the deck, or a string explaining why a placement was rejected
+     */
+    pub fn try_from(ships: [Option<(u8, u8, bool)>; 5]) -> Result<Self, &'static str> {
+        let ship_types = [
+            ShipType::Carrier,
+            ShipType::Battleship,
+            ShipType::Cruiser,
+            ShipType::Submarine,
+            ShipType::Destroyer,
+        ];
+        let mut deck = Deck::new();
+        for (ship_type, ship) in ship_types.into_iter().zip(ships) {
+            if let Some((x, y, z)) = ship {
+                deck.add(Ship::try_new(ship_type, x, y, z)?);
+            }
+        }
+        Ok(deck)
+    }
+
+    /**
+     * Pack the deck into a fixed size byte encoding
+     * @dev little endian bit layout:
+     *      [0, 4) version | 5 x ([x: 4, y: 4, z: 1] at 4 + 9i) | [49, 54) placed flags | [54, 56) zero
+     *
+     * @return - 7 byte encoding of the deck
+     */
+    pub fn to_bytes(&self) -> [u8; DECK_ENCODING_SIZE] {
+        let mut bits = BitArray::<[u8; DECK_ENCODING_SIZE], Lsb0>::ZERO;
+        bits[0..4].store_le(DECK_ENCODING_VERSION);
+        for (i, ship) in self.iterator().into_iter().enumerate() {
+            if let Some(ship) = ship {
+                let offset = 4 + 9 * i;
+                bits[offset..offset + 4].store_le(ship.x);
+                bits[offset + 4..offset + 8].store_le(ship.y);
+                bits.set(offset + 8, ship.z);
+                bits.set(49 + i, true);
+            }
+        }
+        bits.into_inner()
+    }
+
+    /**
+     * Unpack a deck encoded by to_bytes(), rejecting any placement that does not fit on the board
+     *
+     * @param bytes - 7 byte encoding of the deck
+     * @return - the deck, or a string explaining why the encoding was rejected
+     */
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let bytes: [u8; DECK_ENCODING_SIZE] = bytes
+            .try_into()
+            .map_err(|_| "Deck encoding must be 7 bytes!")?;
+        let bits = BitArray::<_, Lsb0>::new(bytes);
+        if bits[0..4].load_le::<u8>() != DECK_ENCODING_VERSION {
+            return Err("Unsupported deck encoding version!");
+        } else if bits[54..].any() {
+            return Err("Reserved deck encoding bits must be 0!");
+        }
+        let mut ships = [None; 5];
+        for (i, ship) in ships.iter_mut().enumerate() {
+            let offset = 4 + 9 * i;
+            let placement = &bits[offset..offset + 9];
+            if bits[49 + i] {
+                *ship = Some((
+                    placement[0..4].load_le::<u8>(),
+                    placement[4..8].load_le::<u8>(),
+                    placement[8],
+                ));
+            } else if placement.any() {
+                return Err("Unplaced ship must not have a placement!");
+            }
+        }
+        Deck::try_from(ships)
+    }
+
+    /**
+     * Parse a deck from comma separated placements such as "CA:D4-V, BS:F5-H"
+     * @dev each placement is <ship code>:<column A-J><row 1-10>-<H|V> naming the ship head; ships
+     *      may be listed in any order or left out, and whitespace around placements is ignored
+     *
+     * @param notation - the deck notation
+     * @return - the deck, or an error naming the byte offset of the malformed placement
+     */
+    pub fn from_notation(notation: &str) -> Result<Self, DeckNotationError> {
+        let mut deck = Deck::new();
+        if notation.trim().is_empty() {
+            return Ok(deck);
+        }
+        let mut offset = 0;
+        for entry in notation.split(',') {
+            let start = offset + entry.len() - entry.trim_start().len();
+            let ship = Deck::parse_placement(entry.trim(), start)?;
+            if deck[ship.ship_type].is_some() {
+                return Err(DeckNotationError::DuplicateShip { offset: start });
+            }
+            deck.add(ship);
+            offset += entry.len() + 1;
+        }
+        Ok(deck)
+    }
+
+    /**
+     * Format the deck in the notation read by from_notation()
+     *
+     * @return - the placed ships in enum order, e.g. "CA:D4-V, BS:F5-H, CR:A2-H, SU:A6-V, DE:G2-H"
+     */
+    pub fn to_notation(&self) -> String {
+        self.iter()
+            .filter_map(|(ship_type, ship)| {
+                ship.map(|ship| {
+                    let column = (b'A' + ship.x) as char;
+                    let orientation = if ship.z { 'V' } else { 'H' };
+                    format!(
+                        "{}:{}{}-{}",
+                        ship_type.code(),
+                        column,
+                        ship.y + 1,
+                        orientation
+                    )
+                })
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /**
+     * Parse a single <ship code>:<column><row>-<H|V> placement
+     *
+     * @param entry - the placement with surrounding whitespace trimmed
+     * @param offset - byte offset of the placement in the deck notation
+     * @return - the ship, or an error naming the byte offset of the malformed part
+     */
+    fn parse_placement(entry: &str, offset: usize) -> Result<Ship, DeckNotationError> {
+        let bytes = entry.as_bytes();
+        let ship_type = [
+            ShipType::Carrier,
+            ShipType::Battleship,
+            ShipType::Cruiser,
+            ShipType::Submarine,
+            ShipType::Destroyer,
+        ]
+        .into_iter()
+        .find(|ship_type| entry.get(0..2) == Some(ship_type.code()))
+        .ok_or(DeckNotationError::UnknownShip { offset })?;
+        if bytes.get(2) != Some(&b':') {
+            return Err(DeckNotationError::MissingSeparator {
+                offset: offset + 2,
+                expected: ':',
+            });
+        }
+        let x = match bytes.get(3) {
+            Some(column @ b'A'..=b'J') => column - b'A',
+            _ => return Err(DeckNotationError::InvalidColumn { offset: offset + 3 }),
+        };
+        let digits = bytes[4..].iter().take_while(|c| c.is_ascii_digit()).count();
+        let y = match entry[4..4 + digits].parse::<u8>() {
+            Ok(row @ 1..=10) => row - 1,
+            _ => return Err(DeckNotationError::InvalidRow { offset: offset + 4 }),
+        };
+        if bytes.get(4 + digits) != Some(&b'-') {
+            return Err(DeckNotationError::MissingSeparator {
+                offset: offset + 4 + digits,
+                expected: '-',
+            });
+        }
+        let z = match &entry[5 + digits..] {
+            "H" => false,
+            "V" => true,
+            _ => {
+                return Err(DeckNotationError::InvalidOrientation {
+                    offset: offset + 5 + digits,
+                })
+            }
+        };
+        Ship::try_new(ship_type, x, y, z).map_err(|_| DeckNotationError::OffBoard { offset })
+    }
+
     /**
      * Return an iterable reference to ships in enum order
      *
@@ -85,6 +387,24 @@ impl Deck {
         ]
     }
 
+    /**
+     * Iterate over the ship types of the fleet alongside their placements
+     * @dev Ship already dispatches its length on ShipType, so placements of every length share one
+     *      item type
+     *
+     * @return - (ship type, optional placement) pairs in enum order
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (ShipType, Option<Ship>)> {
+        let ship_types = [
+            ShipType::Carrier,
+            ShipType::Battleship,
+            ShipType::Cruiser,
+            ShipType::Submarine,
+            ShipType::Destroyer,
+        ];
+        ship_types.into_iter().zip(self.iterator())
+    }
+
     /**
      * Add a new ship of given type, or overwrite current ship of given type
      *
@@ -128,3 +448,193 @@ impl IndexMut<ShipType> for Deck {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{
+            board::Board,
+            fixtures::{PATTERN_1, PATTERN_2},
+            ship::DEFAULT_WITNESS_OPTIONS,
+        },
+    };
+
+    #[test]
+    fn bytes_round_trip() {
+        let mut partial = PATTERN_2;
+        partial[1] = None;
+        for ships in [PATTERN_1, PATTERN_2, partial, [None; 5]] {
+            let deck = Deck::from(ships);
+            let decoded = Deck::from_bytes(&deck.to_bytes()).unwrap();
+            assert_eq!(
+                decoded
+                    .iterator()
+                    .map(|ship| ship.map(|ship| (ship.x, ship.y, ship.z))),
+                ships
+            );
+            // the packed deck reproduces the same board state
+            assert_eq!(
+                Board::from(&decoded).state(DEFAULT_WITNESS_OPTIONS),
+                Board::from(&deck).state(DEFAULT_WITNESS_OPTIONS)
+            );
+        }
+    }
+
+    #[test]
+    fn bytes_malformed() {
+        let bytes = Deck::from(PATTERN_1).to_bytes();
+        // wrong length
+        assert!(Deck::from_bytes(&bytes[..6]).is_err());
+        assert!(Deck::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        // wrong version
+        let mut version = bytes;
+        version[0] = version[0] & 0xf0 | 2;
+        assert!(Deck::from_bytes(&version).is_err());
+        // reserved bits set
+        let mut reserved = bytes;
+        reserved[6] |= 0x80;
+        assert!(Deck::from_bytes(&reserved).is_err());
+        // unplaced ship with placement bits
+        let mut unplaced = Deck::from([None; 5]).to_bytes();
+        unplaced[0] |= 0x10;
+        assert!(Deck::from_bytes(&unplaced).is_err());
+        // carrier placed horizontally at (6, 0) does not fit on the board
+        let mut ships = PATTERN_1;
+        ships[0] = Some((6, 0, false));
+        assert_eq!(
+            Deck::from_bytes(&Deck::from(ships).to_bytes()).err(),
+            Some("Ship placement does not fit on the board!")
+        );
+    }
+
+    #[test]
+    fn notation_round_trip() {
+        let notation = "CA:D4-V, BS:F5-H, CR:A2-H, SU:A6-V, DE:G2-H";
+        let deck = Deck::from_notation(notation).unwrap();
+        assert_eq!(deck.to_bytes(), Deck::from(PATTERN_1).to_bytes());
+        assert_eq!(deck.to_notation(), notation);
+        let mut partial = PATTERN_2;
+        partial[1] = None;
+        for ships in [PATTERN_1, PATTERN_2, partial, [None; 5]] {
+            let deck = Deck::from(ships);
+            let decoded = Deck::from_notation(&deck.to_notation()).unwrap();
+            assert_eq!(decoded.to_bytes(), deck.to_bytes());
+        }
+        // ships may be listed in any order with any whitespace around them
+        let shuffled = Deck::from_notation(" DE:G2-H,SU:A6-V ,  CR:A2-H,BS:F5-H,CA:D4-V ").unwrap();
+        assert_eq!(shuffled.to_notation(), notation);
+        // the last row and column are two digit and last letter respectively
+        let corner = Deck::from_notation("DE:I10-H").unwrap();
+        assert_eq!(
+            corner.to_bytes(),
+            Deck::from_named(None, None, None, None, Some((8, 9, false))).to_bytes()
+        );
+    }
+
+    #[test]
+    fn notation_malformed() {
+        let cases = [
+            ("XX:A1-H", DeckNotationError::UnknownShip { offset: 0 }),
+            (
+                "CA",
+                DeckNotationError::MissingSeparator {
+                    offset: 2,
+                    expected: ':',
+                },
+            ),
+            ("CA:K1-H", DeckNotationError::InvalidColumn { offset: 3 }),
+            ("CA:a1-H", DeckNotationError::InvalidColumn { offset: 3 }),
+            ("CA:A0-H", DeckNotationError::InvalidRow { offset: 4 }),
+            ("CA:A11-H", DeckNotationError::InvalidRow { offset: 4 }),
+            ("CA:A-H", DeckNotationError::InvalidRow { offset: 4 }),
+            (
+                "CA:A1H",
+                DeckNotationError::MissingSeparator {
+                    offset: 5,
+                    expected: '-',
+                },
+            ),
+            (
+                "CA:A1-D",
+                DeckNotationError::InvalidOrientation { offset: 6 },
+            ),
+            (
+                "CA:A1-HV",
+                DeckNotationError::InvalidOrientation { offset: 6 },
+            ),
+            ("CA:G1-H", DeckNotationError::OffBoard { offset: 0 }),
+            (
+                "CA:A1-H, CA:A2-H",
+                DeckNotationError::DuplicateShip { offset: 9 },
+            ),
+            // offsets count from the start of the whole notation
+            (
+                "CA:A1-H, BS:A2-X",
+                DeckNotationError::InvalidOrientation { offset: 15 },
+            ),
+            ("CA:A1-H,", DeckNotationError::UnknownShip { offset: 8 }),
+        ];
+        for (notation, error) in cases {
+            assert_eq!(
+                Deck::from_notation(notation).err(),
+                Some(error),
+                "{}",
+                notation
+            );
+        }
+    }
+
+    #[test]
+    fn from_named_matches_array_order() {
+        let deck = Deck::from_named(
+            Some((3, 3, true)),
+            Some((5, 4, false)),
+            Some((0, 1, false)),
+            Some((0, 5, true)),
+            Some((6, 1, false)),
+        );
+        assert_eq!(deck.to_bytes(), Deck::from(PATTERN_1).to_bytes());
+        // each named placement is given the length of its ship
+        assert_eq!(deck.destroyer.unwrap().ship_type, ShipType::Destroyer);
+        let deck = Deck::from_named(None, None, None, None, Some((6, 1, false)));
+        assert_eq!(
+            deck.to_bytes(),
+            Deck::from([None, None, None, None, PATTERN_1[4]]).to_bytes()
+        );
+    }
+
+    #[test]
+    fn iter_canonical_order() {
+        let expected = [
+            ShipType::Carrier,
+            ShipType::Battleship,
+            ShipType::Cruiser,
+            ShipType::Submarine,
+            ShipType::Destroyer,
+        ];
+        let deck = Deck::default();
+        assert_eq!(deck.iter().count(), 5);
+        for ((ship_type, ship), expected) in deck.iter().zip(expected) {
+            assert_eq!(ship_type, expected);
+            assert_eq!(ship.unwrap().ship_type, expected);
+        }
+        // unplaced ships are still yielded in their slot
+        let mut ships = PATTERN_1;
+        ships[2] = None;
+        let placed: Vec<bool> = Deck::from(ships)
+            .iter()
+            .map(|(_, ship)| ship.is_some())
+            .collect();
+        assert_eq!(placed, [true, true, false, true, true]);
+    }
+
+    #[test]
+    fn random_is_valid() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let board = Board::from(&Deck::random(&mut rng));
+            assert_eq!(board.is_valid(), Ok(()));
+        }
+    }
+}
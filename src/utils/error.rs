@@ -0,0 +1,189 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use halo2_proofs::plonk::Error;
+
+/**
+ * Errors raised while marshalling values between the game utilities and the circuits
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BattleshipError {
+    // a collection did not have the length of the fixed size array it is converted into
+    LengthMismatch { expected: usize, found: usize },
+    // public input at the given index is not a 32 byte field element encoding
+    InvalidInstanceLength { index: usize, length: usize },
+    // public input at the given index encodes a value at or above the field modulus
+    NonCanonicalInstance { index: usize },
+    // board occupies a different number of cells than the fleet it is proven for
+    InvalidFleetSize { actual: usize, expected: usize },
+    // pedersen commitment is the identity point, which has no affine coordinates to export
+    IdentityCommitment,
+    // commitment coordinates or compressed encoding do not describe a point on the curve
+    InvalidCommitmentPoint,
+    // verifier was given a different number of public inputs than the circuit exports
+    InstanceLengthMismatch { expected: usize, got: usize },
+    // shot proof is bound to a different turn than its position in the game
+    TurnMismatch { expected: u64, got: u64 },
+    // H and V commitments of the ship at the given fleet index set a different number of bits
+    // than the ship's length
+    ShipLengthMismatch { ship: u8, expected: u8, found: u8 },
+}
+
+impl core::fmt::Display for BattleshipError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BattleshipError::LengthMismatch { expected, found } => {
+                write!(f, "Expected {} elements, found {}", expected, found)
+            }
+            BattleshipError::InvalidInstanceLength { index, length } => {
+                write!(f, "Public input {} is {} bytes, expected 32", index, length)
+            }
+            BattleshipError::NonCanonicalInstance { index } => {
+                write!(f, "Public input {} is not a canonical field element", index)
+            }
+            BattleshipError::InvalidFleetSize { actual, expected } => {
+                write!(f, "Board occupies {} cells, fleet has {}", actual, expected)
+            }
+            BattleshipError::IdentityCommitment => {
+                write!(f, "Commitment is the identity point")
+            }
+            BattleshipError::InvalidCommitmentPoint => {
+                write!(f, "Commitment is not a point on the curve")
+            }
+            BattleshipError::InstanceLengthMismatch { expected, got } => {
+                write!(f, "Expected {} public inputs, got {}", expected, got)
+            }
+            BattleshipError::TurnMismatch { expected, got } => {
+                write!(f, "Expected a shot on turn {}, got turn {}", expected, got)
+            }
+            BattleshipError::ShipLengthMismatch {
+                ship,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Ship {} has {} bits set across its commitments, expected {}",
+                ship, found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BattleshipError {}
+
+/**
+ * Surface a BattleshipError raised during synthesis as a halo2 error
+ * @dev halo2 errors carry no message, so every variant becomes Error::Synthesis
+ */
+#[cfg(feature = "std")]
+impl From<BattleshipError> for Error {
+    fn from(_: BattleshipError) -> Error {
+        Error::Synthesis
+    }
+}
+
+/**
+ * Errors returned when verifying a proof
+ * @dev keeps malformed verifier input apart from proofs halo2 rejects, which Error cannot explain
+ */
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum VerifyError {
+    // the public inputs cannot belong to the circuit, so the proof was never checked
+    Instance(BattleshipError),
+    // halo2 rejected the proof
+    Proof(Error),
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VerifyError::Instance(error) => write!(f, "Invalid public inputs: {}", error),
+            VerifyError::Proof(error) => write!(f, "Proof verification failed: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifyError {}
+
+#[cfg(feature = "std")]
+impl From<BattleshipError> for VerifyError {
+    fn from(error: BattleshipError) -> VerifyError {
+        VerifyError::Instance(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for VerifyError {
+    fn from(error: Error) -> VerifyError {
+        VerifyError::Proof(error)
+    }
+}
+
+/**
+ * Surface a VerifyError as a halo2 error for callers that only deal in halo2 errors
+ * @dev malformed public inputs become Error::InvalidInstances
+ */
+#[cfg(feature = "std")]
+impl From<VerifyError> for Error {
+    fn from(error: VerifyError) -> Error {
+        match error {
+            VerifyError::Instance(_) => Error::InvalidInstances,
+            VerifyError::Proof(error) => error,
+        }
+    }
+}
+
+/**
+ * Check that a verifier was given as many public inputs as a circuit exports
+ *
+ * @param expected - number of public inputs the circuit exports
+ * @param instance - public inputs passed to the verifier
+ * @return - Ok if the lengths match, or the expected and given lengths
+ */
+pub fn check_instance_len<T>(expected: usize, instance: &[T]) -> Result<(), BattleshipError> {
+    match instance.len() {
+        got if got == expected => Ok(()),
+        got => Err(BattleshipError::InstanceLengthMismatch { expected, got }),
+    }
+}
+
+/**
+ * Convert a vector into a fixed size array
+ *
+ * @param N: length of the array
+ * @param v - vector expected to hold exactly N elements
+ * @return - the array, or a length mismatch error naming both lengths
+ */
+pub fn to_fixed_array<T, const N: usize>(v: Vec<T>) -> Result<[T; N], BattleshipError> {
+    let found = v.len();
+    v.try_into()
+        .map_err(|_| BattleshipError::LengthMismatch { expected: N, found })
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, alloc::vec};
+
+    #[test]
+    fn to_fixed_array_lengths() {
+        assert_eq!(to_fixed_array::<u8, 3>(vec![1, 2, 3]), Ok([1, 2, 3]));
+        // too few and too many elements are reported instead of panicking
+        assert_eq!(
+            to_fixed_array::<u8, 3>(vec![1, 2]),
+            Err(BattleshipError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            to_fixed_array::<u8, 3>(vec![1, 2, 3, 4]),
+            Err(BattleshipError::LengthMismatch {
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use {
+    crate::utils::{binary::BinaryValue, commitment::BoardCommitment},
+    halo2_proofs::pasta::pallas,
+};
+
+// width of one EVM word in bytes
+pub const WORD_SIZE: usize = 32;
+
+/**
+ * Reasons calldata cannot be decoded into public inputs
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EvmDecodeError {
+    // calldata is not a whole number of 32 byte words
+    InvalidLength { length: usize },
+    // calldata does not hold the number of public inputs the circuit exports
+    WrongInstanceCount { expected: usize, found: usize },
+    // word at the given index is not below the field modulus
+    NotInField { word: usize },
+}
+
+impl core::fmt::Display for EvmDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EvmDecodeError::InvalidLength { length } => {
+                write!(f, "Calldata length {} is not a multiple of 32", length)
+            }
+            EvmDecodeError::WrongInstanceCount { expected, found } => {
+                write!(f, "Expected {} public inputs, found {}", expected, found)
+            }
+            EvmDecodeError::NotInField { word } => {
+                write!(f, "Word {} is not a canonical field element", word)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvmDecodeError {}
+
+/**
+ * Encode public inputs as the uint256 words expected by the BattleZips contracts
+ *
+ * @param instances - public inputs of a proof
+ * @return - one 32 byte big endian word per public input, in order
+ */
+pub fn encode_instances(instances: &[pallas::Base]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(instances.len() * WORD_SIZE);
+    for instance in instances {
+        let mut word = BinaryValue::from_fp(*instance).to_repr();
+        word.reverse();
+        bytes.extend_from_slice(&word);
+    }
+    bytes
+}
+
+/**
+ * Decode uint256 words into public inputs
+ * @dev words at or above the field modulus are rejected rather than reduced, so every field
+ *      element has exactly one encoding
+ *
+ * @param bytes - 32 byte big endian words
+ * @return - public inputs in the same order, or why the calldata was rejected
+ */
+pub fn decode_instances(bytes: &[u8]) -> Result<Vec<pallas::Base>, EvmDecodeError> {
+    if bytes.len() % WORD_SIZE != 0 {
+        return Err(EvmDecodeError::InvalidLength {
+            length: bytes.len(),
+        });
+    }
+    bytes
+        .chunks(WORD_SIZE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut word: [u8; WORD_SIZE] = chunk.try_into().unwrap();
+            word.reverse();
+            BinaryValue::from_repr(word)
+                .try_to_fp()
+                .map_err(|_| EvmDecodeError::NotInField { word: i })
+        })
+        .collect()
+}
+
+/**
+ * Decode exactly N public inputs
+ *
+ * @param bytes - 32 byte big endian words
+ * @return - the N public inputs, or why the calldata was rejected
+ */
+fn decode_exact<const N: usize>(bytes: &[u8]) -> Result<[pallas::Base; N], EvmDecodeError> {
+    let instances = decode_instances(bytes)?;
+    let found = instances.len();
+    instances
+        .try_into()
+        .map_err(|_| EvmDecodeError::WrongInstanceCount { expected: N, found })
+}
+
+/**
+ * Encode the public inputs of a board proof
+ *
+ * @param commitment - the public board commitment
+ * @return - [commitment x, commitment y] as 64 bytes of calldata
+ */
+pub fn encode_board_instances(commitment: &BoardCommitment) -> Vec<u8> {
+    encode_instances(&commitment.0)
+}
+
+/**
+ * Decode the public inputs of a board proof
+ *
+ * @param bytes - 64 bytes of calldata
+ * @return - the board commitment, or why the calldata was rejected
+ */
+pub fn decode_board_instances(bytes: &[u8]) -> Result<BoardCommitment, EvmDecodeError> {
+    Ok(BoardCommitment(decode_exact::<2>(bytes)?))
+}
+
+/**
+ * Encode the public inputs of a shot proof
+ *
+ * @param instances - [commitment x, commitment y, shot, hit] as built by ShotCircuit::instance()
+ * @return - 128 bytes of calldata
+ */
+pub fn encode_shot_instances(instances: &[pallas::Base; 4]) -> Vec<u8> {
+    encode_instances(instances)
+}
+
+/**
+ * Decode the public inputs of a shot proof
+ *
+ * @param bytes - 128 bytes of calldata
+ * @return - [commitment x, commitment y, shot, hit], or why the calldata was rejected
+ */
+pub fn decode_shot_instances(bytes: &[u8]) -> Result<[pallas::Base; 4], EvmDecodeError> {
+    decode_exact::<4>(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            circuits::{board::BoardCircuit, shot::ShotCircuit},
+            utils::{
+                fixtures::pattern_1,
+                ship::DEFAULT_WITNESS_OPTIONS,
+                shot::{serialize, ShotResult},
+            },
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            pasta::{group::ff::PrimeField, vesta},
+            poly::commitment::Params,
+        },
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn round_trip_real_proof() {
+        // prove a hit at (3, 5) against board pattern 1
+        let board = pattern_1();
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let circuit = ShotCircuit::new(state, trapdoor, serialize::<1>([3], [5]), ShotResult::Hit);
+        let params: Params<vesta::Affine> = Params::new(ShotCircuit::min_k());
+        let pk = ShotCircuit::keygen(&params).unwrap();
+        let proof = circuit.prove(&params, &pk).unwrap();
+        // the decoded calldata verifies the proof
+        let calldata = encode_shot_instances(&circuit.public_inputs());
+        assert_eq!(calldata.len(), 4 * WORD_SIZE);
+        let instances = decode_shot_instances(&calldata).unwrap();
+        assert_eq!(instances, circuit.public_inputs());
+        assert!(ShotCircuit::verify(&params, pk.get_vk(), &instances, &proof).is_ok());
+        // the hit flag is the last byte of the last big endian word
+        assert_eq!(calldata[4 * WORD_SIZE - 1], 1);
+        // board commitments round trip through the board wrapper
+        let board_circuit =
+            BoardCircuit::new(board.witness(DEFAULT_WITNESS_OPTIONS), state, trapdoor);
+        let commitment = board_circuit.commitment();
+        let calldata = encode_board_instances(&commitment);
+        assert_eq!(decode_board_instances(&calldata), Ok(commitment));
+        assert_eq!(
+            decode_shot_instances(&calldata),
+            Err(EvmDecodeError::WrongInstanceCount {
+                expected: 4,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn reject_out_of_range_word() {
+        // the field modulus itself is the smallest word that does not reduce canonically
+        let mut modulus = (-pallas::Base::one()).to_repr();
+        modulus[0] += 1;
+        modulus.reverse();
+        let mut calldata = encode_instances(&[pallas::Base::one()]);
+        calldata.extend_from_slice(&modulus);
+        assert_eq!(
+            decode_instances(&calldata),
+            Err(EvmDecodeError::NotInField { word: 1 })
+        );
+        // the largest field element is still accepted
+        let max = encode_instances(&[-pallas::Base::one()]);
+        assert_eq!(decode_instances(&max), Ok(vec![-pallas::Base::one()]));
+        // partial words are rejected
+        assert_eq!(
+            decode_instances(&calldata[..WORD_SIZE + 1]),
+            Err(EvmDecodeError::InvalidLength {
+                length: WORD_SIZE + 1
+            })
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::utils::{board::Board, deck::Deck};
+
+pub const PATTERN_1: [Option<(u8, u8, bool)>; 5] = [
+    Some((3, 3, true)),
+    Some((5, 4, false)),
+    Some((0, 1, false)),
+    Some((0, 5, true)),
+    Some((6, 1, false)),
+];
+
+pub const PATTERN_2: [Option<(u8, u8, bool)>; 5] = [
+    Some((3, 4, false)),
+    Some((9, 6, true)),
+    Some((0, 0, false)),
+    Some((0, 6, false)),
+    Some((6, 1, true)),
+];
+
+pub const PATTERN_1_STATE: u128 = 0x2409025e80200031c00;
+
+pub const PATTERN_2_STATE: u128 = 0x8020080207000f80004010007;
+
+/**
+ * Construct battleship board pattern #1 used throughout the tests and benches
+ *
+ * @return - Board with all five ships placed according to PATTERN_1
+ */
+pub fn pattern_1() -> Board {
+    Board::from(&Deck::from(PATTERN_1))
+}
+
+/**
+ * Construct battleship board pattern #2 used throughout the tests and benches
+ *
+ * @return - Board with all five ships placed according to PATTERN_2
+ */
+pub fn pattern_2() -> Board {
+    Board::from(&Deck::from(PATTERN_2))
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::utils::ship::DEFAULT_WITNESS_OPTIONS};
+
+    #[test]
+    fn pattern_states() {
+        assert_eq!(
+            pattern_1().state(DEFAULT_WITNESS_OPTIONS).try_lower_u128(),
+            Ok(PATTERN_1_STATE)
+        );
+        assert_eq!(
+            pattern_2().state(DEFAULT_WITNESS_OPTIONS).try_lower_u128(),
+            Ok(PATTERN_2_STATE)
+        );
+    }
+}
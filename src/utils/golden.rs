@@ -0,0 +1,199 @@
+#[cfg(feature = "golden")]
+use {
+    crate::{
+        circuits::{
+            board::{BoardCircuit, BOARD_CIRCUIT_K},
+            shot::SHOT_CIRCUIT_K,
+        },
+        utils::{
+            commitment::ShotCommitment, fixtures::pattern_1, rng::DeterministicRng,
+            ship::DEFAULT_WITNESS_OPTIONS, shot::serialize,
+        },
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        plonk::create_proof,
+        transcript::{Blake2bWrite, Challenge255},
+    },
+};
+use {
+    crate::{
+        circuits::{bundle::pinned_vk_digest, shot::ShotCircuit},
+        utils::{
+            binary::{BinaryValue, BinaryValueError},
+            shot::ShotResult,
+        },
+    },
+    halo2_proofs::{
+        pasta::{pallas, vesta},
+        plonk::{keygen_pk, keygen_vk, Error, ProvingKey, VerifyingKey},
+        poly::commitment::Params,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+// seed of the DeterministicRng that draws the golden trapdoor and proof blinding factors
+pub const GOLDEN_SEED: u64 = 0xba771e;
+
+/**
+ * Committed golden values for one circuit used to detect unintended changes to keys or instance layout
+ * @dev the proof bytes are stored alongside the fixture in a separate binary file
+ */
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GoldenFixture {
+    pub k: u32,
+    pub params: String,
+    pub vk: String,
+    pub instance: Vec<String>,
+}
+
+/**
+ * A golden fixture with the proof it describes, as stored in tests/golden
+ *
+ * @param name - file stem of the fixture's json and proof files
+ */
+#[cfg(feature = "golden")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoldenProof {
+    pub name: &'static str,
+    pub fixture: GoldenFixture,
+    pub proof: Vec<u8>,
+}
+
+#[cfg(feature = "golden")]
+impl GoldenProof {
+    /**
+     * Encode the fixture exactly as it is committed to tests/golden
+     *
+     * @return - pretty printed json with a trailing newline
+     */
+    pub fn fixture_json(&self) -> String {
+        serde_json::to_string_pretty(&self.fixture).unwrap() + "\n"
+    }
+}
+
+/**
+ * Generate the golden board and shot proofs used by tests/golden.rs
+ * @dev every trapdoor and blinding factor is drawn from GOLDEN_SEED, so each call returns the same
+ *      bytes until a change to the verifying key or instance layout
+ *
+ * @return - the board proof and a hit at (3, 3) against the same board commitment
+ */
+#[cfg(feature = "golden")]
+pub fn generate() -> Result<[GoldenProof; 2], Error> {
+    let mut rng = DeterministicRng::new(GOLDEN_SEED);
+    // construct battleship board pattern #1
+    let board = pattern_1();
+    let state = board.state(DEFAULT_WITNESS_OPTIONS);
+    let trapdoor = pallas::Scalar::random(&mut rng);
+
+    // prove the board opening
+    let params: Params<vesta::Affine> = Params::new(BOARD_CIRCUIT_K);
+    let pk = BoardCircuit::keygen(&params)?;
+    let circuit = BoardCircuit::new(board.witness(DEFAULT_WITNESS_OPTIONS), state, trapdoor);
+    let commitment = circuit.try_commitment()?;
+    let board_proof = GoldenProof {
+        name: "board",
+        fixture: GoldenFixture {
+            k: BOARD_CIRCUIT_K,
+            params: params_digest(&params),
+            vk: vk_digest(pk.get_vk()),
+            instance: encode_instance(&commitment.0),
+        },
+        proof: circuit.prove_with_rng(&params, &pk, &mut rng)?,
+    };
+
+    // prove a hit at (3, 3) against the same board commitment
+    let params: Params<vesta::Affine> = Params::new(SHOT_CIRCUIT_K);
+    let pk = shot_keygen(&params)?;
+    let shot = serialize::<1>([3], [3]);
+    let hit = ShotResult::Hit;
+    let instance = ShotCircuit::instance(&commitment, &ShotCommitment(shot), hit);
+    let circuit = ShotCircuit::new(state, trapdoor, shot, hit);
+    let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        &[circuit],
+        &[&[&instance]],
+        &mut rng,
+        &mut transcript,
+    )?;
+    let shot_proof = GoldenProof {
+        name: "shot",
+        fixture: GoldenFixture {
+            k: SHOT_CIRCUIT_K,
+            params: params_digest(&params),
+            vk: vk_digest(pk.get_vk()),
+            instance: encode_instance(&instance),
+        },
+        proof: transcript.finalize(),
+    };
+    Ok([board_proof, shot_proof])
+}
+
+/**
+ * Hash the serialized polynomial commitment parameters
+ *
+ * @param params - polynomial commitment parameters
+ * @return - hex encoded blake2b digest of the serialized params
+ */
+pub fn params_digest(params: &Params<vesta::Affine>) -> String {
+    let mut bytes = Vec::<u8>::new();
+    params.write(&mut bytes).unwrap();
+    blake2b_simd::blake2b(&bytes).to_hex().to_string()
+}
+
+/**
+ * Hash a verifying key for a golden fixture
+ * @dev the proof bundle fingerprint of the same key is the first 32 bytes of this digest
+ *
+ * @param vk - verifying key to fingerprint
+ * @return - hex encoded pinned_vk_digest()
+ */
+pub fn vk_digest(vk: &VerifyingKey<vesta::Affine>) -> String {
+    hex::encode(pinned_vk_digest(vk))
+}
+
+/**
+ * Encode public inputs as hex strings of their little endian representation
+ *
+ * @param instance - public inputs of a proof
+ * @return - hex encoded field elements in the same order
+ */
+pub fn encode_instance(instance: &[pallas::Base]) -> Vec<String> {
+    instance
+        .iter()
+        .map(|value| BinaryValue::from_fp(*value).to_hex())
+        .collect()
+}
+
+/**
+ * Decode public inputs encoded by encode_instance()
+ *
+ * @param instance - hex encoded field elements
+ * @return - public inputs in the same order, or an error if any input is not a canonical field element
+ */
+pub fn decode_instance(instance: &[String]) -> Result<Vec<pallas::Base>, BinaryValueError> {
+    instance
+        .iter()
+        .map(|value| BinaryValue::from_hex(value)?.try_to_fp())
+        .collect()
+}
+
+/**
+ * Generate the proving key for the shot circuit
+ *
+ * @param params - polynomial commitment parameters with k = SHOT_CIRCUIT_K
+ * @return - the proving key for any ShotCircuit
+ */
+pub fn shot_keygen(params: &Params<vesta::Affine>) -> Result<ProvingKey<vesta::Affine>, Error> {
+    let empty_circuit = ShotCircuit::new(
+        BinaryValue::empty(),
+        pallas::Scalar::one(),
+        BinaryValue::empty(),
+        ShotResult::Miss,
+    );
+    let vk = keygen_vk(params, &empty_circuit)?;
+    keygen_pk(params, vk, &empty_circuit)
+}
@@ -2,6 +2,23 @@ pub mod ship;
 pub mod deck;
 pub mod board;
 pub mod binary;
+pub mod coordinate;
 pub mod shot;
+pub mod opponent;
+pub mod analysis;
+pub mod symmetry;
+pub mod error;
+#[cfg(feature = "std")]
 pub mod constants;
-pub mod pedersen;
\ No newline at end of file
+#[cfg(feature = "std")]
+pub mod pedersen;
+#[cfg(feature = "std")]
+pub mod commitment;
+#[cfg(feature = "std")]
+pub mod evm;
+#[cfg(feature = "std")]
+pub mod golden;
+#[cfg(feature = "test-utils")]
+pub mod rng;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod fixtures;
\ No newline at end of file
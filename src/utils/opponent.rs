@@ -0,0 +1,204 @@
+use {
+    crate::utils::{
+        binary::{BinaryValue, BinaryValueError},
+        board::render_grid,
+        coordinate::Coordinate,
+        ship::{ShipType, STANDARD_FLEET},
+    },
+    alloc::{string::String, vec::Vec},
+};
+
+/**
+ * Publicly known state of an opponent's board, built from verified shot proofs
+ * @dev hits and misses are serialized like shots (bit y * 10 + x)
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpponentView {
+    pub hits: BinaryValue,
+    pub misses: BinaryValue,
+    pub sunk: Vec<ShipType>,
+}
+
+impl OpponentView {
+    /**
+     * Construct a view of an opponent's board before any shots have been fired
+     *
+     * @return - OpponentView with no resolved cells
+     */
+    pub fn new() -> Self {
+        OpponentView {
+            hits: BinaryValue::empty(),
+            misses: BinaryValue::empty(),
+            sunk: Vec::new(),
+        }
+    }
+
+    /**
+     * Record the verified result of a shot at the opponent's board
+     *
+     * @param x - x coordinate of the shot
+     * @param y - y coordinate of the shot
+     * @param hit - public hit assertion of the verified shot proof
+     * @return - Ok if the shot was recorded, or an error if the coordinate is off the board
+     */
+    pub fn apply_shot(&mut self, x: u8, y: u8, hit: bool) -> Result<(), BinaryValueError> {
+        self.apply(Coordinate::new(x, y)?, hit);
+        Ok(())
+    }
+
+    /**
+     * Record the opponent's hit or miss response to a shot at a validated coordinate
+     *
+     * @param coordinate - cell of the opponent's board shot at
+     * @param hit - whether the opponent reported a hit
+     */
+    pub fn apply(&mut self, coordinate: Coordinate, hit: bool) {
+        self.hits.value.set(coordinate.index(), hit);
+        self.misses.value.set(coordinate.index(), !hit);
+    }
+
+    /**
+     * Record that the opponent announced a ship as sunk
+     *
+     * @param ship - the sunk ship
+     */
+    pub fn mark_sunk(&mut self, ship: ShipType) {
+        if !self.sunk.contains(&ship) {
+            self.sunk.push(ship);
+        }
+    }
+
+    /**
+     * Determine whether a cell has already been shot at
+     *
+     * @param x - x coordinate of the cell
+     * @param y - y coordinate of the cell
+     * @return - true if the cell is a known hit or miss; false for unknown or off board cells
+     */
+    pub fn is_cell_resolved(&self, x: u8, y: u8) -> bool {
+        match Coordinate::new(x, y) {
+            Ok(coordinate) => self.is_resolved(coordinate),
+            Err(_) => false,
+        }
+    }
+
+    /**
+     * Determine whether a validated coordinate has already been shot at
+     *
+     * @param coordinate - cell of the opponent's board
+     * @return - true if the cell is a known hit or miss
+     */
+    pub fn is_resolved(&self, coordinate: Coordinate) -> bool {
+        self.hits.value[coordinate.index()] || self.misses.value[coordinate.index()]
+    }
+
+    /**
+     * Count the hits landed on the opponent's board
+     *
+     * @return - number of cells known to be occupied
+     */
+    pub fn hits(&self) -> usize {
+        self.hits.value.count_ones()
+    }
+
+    /**
+     * Count the occupied cells that have not been hit yet
+     *
+     * @return - cells of the standard fleet left to hit before the opponent loses
+     */
+    pub fn remaining_cells(&self) -> usize {
+        STANDARD_FLEET.cells().saturating_sub(self.hits())
+    }
+
+    /**
+     * Render ASCII representing the known opponent board in the same grid as Board::ascii()
+     *
+     * @return - 10x10 grid marking hits with X, misses with o and unknown cells with .
+     */
+    pub fn ascii(&self) -> String {
+        render_grid(|i| {
+            if self.hits.value[i] {
+                'X'
+            } else if self.misses.value[i] {
+                'o'
+            } else {
+                '.'
+            }
+        })
+    }
+}
+
+impl Default for OpponentView {
+    fn default() -> Self {
+        OpponentView::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{
+            board::BOARD_SIZE,
+            fixtures::{pattern_1, PATTERN_1_STATE},
+        },
+    };
+
+    #[test]
+    fn replay_game() {
+        let board = pattern_1();
+        let mut view = OpponentView::new();
+        // sweep every cell of the board, answering each shot honestly
+        for index in 0..BOARD_SIZE {
+            let (x, y) = ((index % 10) as u8, (index / 10) as u8);
+            assert!(!view.is_cell_resolved(x, y));
+            view.apply_shot(x, y, board.hit(x, y).unwrap()).unwrap();
+            assert!(view.is_cell_resolved(x, y));
+        }
+        // the view's hits are exactly the board state
+        assert_eq!(view.hits.lower_u128(), PATTERN_1_STATE);
+        assert_eq!(
+            view.misses.lower_u128(),
+            !PATTERN_1_STATE & ((1 << BOARD_SIZE) - 1)
+        );
+        assert_eq!(view.hits(), 17);
+        assert_eq!(view.remaining_cells(), 0);
+        // the rendered grid matches the board grid cell for cell with hits for occupied cells
+        let (view_ascii, board_ascii) = (view.ascii(), board.ascii());
+        let rows = view_ascii.lines().zip(board_ascii.lines());
+        for (i, (view_line, board_line)) in rows.enumerate() {
+            match i {
+                1..=10 => {
+                    assert_eq!(view_line[..4], board_line[..4]);
+                    let cells = board_line[4..].replace('1', "X").replace('0', "o");
+                    assert_eq!(view_line[4..], cells);
+                }
+                // the (Y) label and x axis are identical
+                _ => assert_eq!(view_line, board_line),
+            }
+        }
+    }
+
+    #[test]
+    fn partial_view() {
+        let mut view = OpponentView::new();
+        view.apply_shot(3, 5, true).unwrap();
+        view.apply_shot(0, 0, false).unwrap();
+        assert_eq!(view.hits(), 1);
+        assert_eq!(view.remaining_cells(), 16);
+        assert!(!view.is_cell_resolved(9, 9));
+        assert!(!view.is_cell_resolved(10, 0));
+        assert_eq!(
+            view.apply_shot(10, 0, true),
+            Err(BinaryValueError::InvalidCoordinate { x: 10, y: 0 })
+        );
+        view.mark_sunk(ShipType::Destroyer);
+        view.mark_sunk(ShipType::Destroyer);
+        assert_eq!(view.sunk, [ShipType::Destroyer]);
+        let ascii = view.ascii();
+        let lines: Vec<&str> = ascii.lines().collect();
+        // row 5 is the fifth line below the (Y) label and row 0 is just above the x axis
+        assert_eq!(lines[5], "5 | . . . X . . . . . .");
+        assert_eq!(lines[10], "0 | o . . . . . . . . .");
+    }
+}
@@ -1,10 +1,18 @@
 use {
-    crate::utils::constants::{
-        BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES,
+    crate::utils::{
+        board::Board,
+        constants::{
+            BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES,
+        },
+        error::BattleshipError,
+        ship::DEFAULT_WITNESS_OPTIONS,
     },
     halo2_proofs::{
-        arithmetic::CurveExt,
-        pasta::{group::ff::PrimeField, pallas},
+        arithmetic::{CurveAffine, CurveExt},
+        pasta::{
+            group::{ff::PrimeField, Curve, Group},
+            pallas,
+        },
     },
 };
 
@@ -25,4 +33,195 @@ pub fn pedersen_commit(message: &pallas::Base, trapdoor: &pallas::Scalar) -> pal
 
     // compute the pedersen commitment for the given value + trapdoor
     v * message + r * trapdoor
-}
\ No newline at end of file
+}
+
+/**
+ * Compute the affine coordinates of a pedersen commitment for a given value and trapdoor
+ * @dev the commitment is only the identity if the message and trapdoor are both 0 (e.g. an empty
+ *      board committed with a zero trapdoor), which has no coordinates to witness or export
+ *
+ * @param message - Base field element of message being committed to
+ * @param trapdoor - Scalar field element of the trapdoor to reveal the commitment
+ * @return - the (x, y) coordinates of the commitment, or an error if it is the identity
+ */
+pub fn pedersen_coordinates(
+    message: &pallas::Base,
+    trapdoor: &pallas::Scalar,
+) -> Result<[pallas::Base; 2], BattleshipError> {
+    let commitment = pedersen_commit(message, trapdoor).to_affine();
+    let coordinates =
+        Option::<_>::from(commitment.coordinates()).ok_or(BattleshipError::IdentityCommitment)?;
+    Ok([*coordinates.x(), *coordinates.y()])
+}
+/**
+ * Check that a pedersen commitment opens to a given value and trapdoor
+ * @dev pallas point equality is computed in constant time (subtle::ConstantTimeEq)
+ *
+ * @param commitment - the pedersen commitment being opened
+ * @param message - Base field element of the claimed message
+ * @param trapdoor - Scalar field element of the claimed trapdoor
+ * @return - true if the commitment was computed from message and trapdoor
+ */
+pub fn verify_commitment(
+    commitment: &pallas::Point,
+    message: &pallas::Base,
+    trapdoor: &pallas::Scalar,
+) -> bool {
+    pedersen_commit(message, trapdoor) == *commitment
+}
+
+/**
+ * Open a board commitment by revealing the board and the trapdoor it was committed with
+ *
+ * @param commitment - the pedersen commitment exported by the board circuit
+ * @param board - the revealed board whose state is the committed message
+ * @param trapdoor - the revealed trapdoor of the commitment
+ * @return - Ok if the commitment opens to the board state, or an error message otherwise
+ */
+pub fn open(
+    commitment: &pallas::Point,
+    board: &Board,
+    trapdoor: &pallas::Scalar,
+) -> Result<(), &'static str> {
+    let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
+    match verify_commitment(commitment, &message, trapdoor) {
+        true => Ok(()),
+        false => Err("Board commitment does not open to the revealed board and trapdoor"),
+    }
+}
+
+/**
+ * Additively homomorphic pedersen commitment to a counter such as a hit tally
+ * @dev shares generators with the board commitment; commitments to a and b sum to a commitment to a + b
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TallyCommitment(pub pallas::Point);
+
+impl TallyCommitment {
+    /**
+     * Commit to an integer value with a given trapdoor
+     *
+     * @param value - the integer being committed to
+     * @param trapdoor - Scalar field element of the trapdoor to reveal the commitment
+     * @return - the tally commitment to value
+     */
+    pub fn commit_u64(value: u64, trapdoor: &pallas::Scalar) -> Self {
+        TallyCommitment(pedersen_commit(&pallas::Base::from(value), trapdoor))
+    }
+
+    /**
+     * Homomorphically add two tally commitments
+     *
+     * @param other - the tally commitment to add to this one
+     * @return - commitment to the sum of both values under the sum of both trapdoors
+     */
+    pub fn add(&self, other: &Self) -> Self {
+        TallyCommitment(self.0 + other.0)
+    }
+
+    /**
+     * Check that a set of tally commitments sums to a claimed total
+     *
+     * @param commitments - the per-turn tally commitments
+     * @param total - the claimed sum of all committed values
+     * @param trapdoor_sum - the sum of all trapdoors used in the commitments
+     * @return - true if the summed commitment opens to total with trapdoor_sum
+     */
+    pub fn verify_sum(
+        commitments: &[Self],
+        total: u64,
+        trapdoor_sum: &pallas::Scalar,
+    ) -> bool {
+        let sum = commitments
+            .iter()
+            .fold(TallyCommitment(pallas::Point::identity()), |sum, commitment| {
+                sum.add(commitment)
+            });
+        verify_commitment(&sum.0, &pallas::Base::from(total), trapdoor_sum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::fixtures::{pattern_1, pattern_2},
+        halo2_proofs::arithmetic::Field,
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn open_valid() {
+        let board = pattern_1();
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
+        let commitment = pedersen_commit(&message, &trapdoor);
+        assert!(verify_commitment(&commitment, &message, &trapdoor));
+        assert_eq!(open(&commitment, &board, &trapdoor), Ok(()));
+    }
+
+    #[test]
+    fn identity_commitment() {
+        // an empty board committed with a zero trapdoor is the identity point
+        assert_eq!(
+            pedersen_coordinates(&pallas::Base::zero(), &pallas::Scalar::zero()),
+            Err(BattleshipError::IdentityCommitment)
+        );
+        // a zero message alone is still blinded by the trapdoor
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let commitment = pedersen_commit(&pallas::Base::zero(), &trapdoor).to_affine();
+        let coordinates = commitment.coordinates().unwrap();
+        assert_eq!(
+            pedersen_coordinates(&pallas::Base::zero(), &trapdoor),
+            Ok([*coordinates.x(), *coordinates.y()])
+        );
+    }
+
+    #[test]
+    fn open_wrong_trapdoor() {
+        let board = pattern_1();
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
+        let commitment = pedersen_commit(&message, &trapdoor);
+        let wrong_trapdoor = trapdoor + pallas::Scalar::one();
+        assert!(!verify_commitment(&commitment, &message, &wrong_trapdoor));
+        assert!(open(&commitment, &board, &wrong_trapdoor).is_err());
+    }
+
+    #[test]
+    fn open_wrong_board() {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let message = pattern_1().commitment_preimage(DEFAULT_WITNESS_OPTIONS);
+        let commitment = pedersen_commit(&message, &trapdoor);
+        assert!(open(&commitment, &pattern_2(), &trapdoor).is_err());
+    }
+
+    #[test]
+    fn tally_sum_opens() {
+        // commit to one hit per turn for all 17 occupied cells
+        let trapdoors = [(); 17].map(|_| pallas::Scalar::random(&mut OsRng));
+        let commitments = trapdoors.map(|trapdoor| TallyCommitment::commit_u64(1, &trapdoor));
+        let trapdoor_sum = trapdoors.iter().fold(pallas::Scalar::zero(), |sum, t| sum + t);
+        assert!(TallyCommitment::verify_sum(&commitments, 17, &trapdoor_sum));
+        // the running commitment matches a fresh commitment to the total
+        let running = commitments[1..]
+            .iter()
+            .fold(commitments[0], |sum, commitment| sum.add(commitment));
+        assert_eq!(running, TallyCommitment::commit_u64(17, &trapdoor_sum));
+    }
+
+    #[test]
+    fn tally_sum_forged() {
+        let trapdoors = [(); 17].map(|_| pallas::Scalar::random(&mut OsRng));
+        let commitments = trapdoors.map(|trapdoor| TallyCommitment::commit_u64(1, &trapdoor));
+        let trapdoor_sum = trapdoors.iter().fold(pallas::Scalar::zero(), |sum, t| sum + t);
+        // claimed total does not match the committed hits
+        assert!(!TallyCommitment::verify_sum(&commitments, 16, &trapdoor_sum));
+        assert!(!TallyCommitment::verify_sum(&commitments, 18, &trapdoor_sum));
+        // trapdoor sum omits one of the turns
+        let forged = trapdoor_sum - trapdoors[16];
+        assert!(!TallyCommitment::verify_sum(&commitments, 17, &forged));
+        // a turn dropped from the tally
+        assert!(!TallyCommitment::verify_sum(&commitments[..16], 17, &trapdoor_sum));
+    }
+}
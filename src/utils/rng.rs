@@ -0,0 +1,44 @@
+use {
+    rand::{CryptoRng, Error, RngCore, SeedableRng},
+    rand_chacha::ChaCha20Rng,
+};
+
+/**
+ * ChaCha20 rng seeded with a fixed value so trapdoors and proofs can be reproduced byte for byte
+ * @dev for golden vectors and debugging only; anyone who knows the seed can recover the trapdoors
+ *      and proof blinding factors drawn from it
+ */
+#[derive(Clone, Debug)]
+pub struct DeterministicRng(ChaCha20Rng);
+
+impl DeterministicRng {
+    /**
+     * Construct an rng that always produces the same stream for the same seed
+     *
+     * @param seed - the seed expanded into the ChaCha20 key
+     * @return - the seeded rng
+     */
+    pub fn new(seed: u64) -> DeterministicRng {
+        DeterministicRng(ChaCha20Rng::seed_from_u64(seed))
+    }
+}
+
+impl RngCore for DeterministicRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for DeterministicRng {}
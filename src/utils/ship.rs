@@ -1,11 +1,114 @@
 use {
     crate::utils::{
         binary::{BinaryValue},
-        board::BOARD_SIZE
+        board::BOARD_SIZE,
+        error::BattleshipError,
     },
+    alloc::vec::Vec,
     bitvec::prelude::*,
 };
 
+/**
+ * Ship lengths making up a fleet, in the order their H/V commitments are witnessed
+ * @dev each ship contributes one H and one V commitment to a board proof
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FleetConfig {
+    pub ships: &'static [usize],
+}
+
+// shortest and longest ship the placement chip is instantiated for
+pub const MIN_SHIP_LENGTH: usize = 2;
+pub const MAX_SHIP_LENGTH: usize = 5;
+
+// the standard battleship fleet: carrier, battleship, cruiser, submarine, destroyer
+pub const STANDARD_FLEET: FleetConfig = FleetConfig {
+    ships: &[5, 4, 3, 3, 2],
+};
+
+impl FleetConfig {
+    /**
+     * Return the total number of board cells occupied by the fleet
+     *
+     * @return - sum of all ship lengths
+     */
+    pub const fn cells(&self) -> usize {
+        let mut cells = 0;
+        let mut i = 0;
+        while i < self.ships.len() {
+            cells += self.ships[i];
+            i += 1;
+        }
+        cells
+    }
+
+    /**
+     * Return the number of H/V ship commitments witnessed for the fleet
+     *
+     * @return - two commitments per ship
+     */
+    pub const fn commitments(&self) -> usize {
+        self.ships.len() * 2
+    }
+
+    /**
+     * Check that the fleet can be proven by the board chip
+     *
+     * @return - Ok, or a string explaining why the fleet is not supported
+     */
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.ships.is_empty() {
+            Err("Fleet must contain at least one ship!")
+        } else if self
+            .ships
+            .iter()
+            .any(|length| !(MIN_SHIP_LENGTH..=MAX_SHIP_LENGTH).contains(length))
+        {
+            Err("Fleet contains a ship length the placement chip does not support!")
+        } else if self.cells() > BOARD_SIZE {
+            Err("Fleet does not fit in 100 board cells!")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/**
+ * Check that each ship's H and V commitments together set exactly the ship's length in bits
+ * @dev a cheap pre-proof check: a wrong count always fails the placement running sum, but only
+ *      surfaces there as a constraint failure; placement shape is still left to the circuit
+ *
+ * @param fleet - ship lengths the commitments are witnessed for
+ * @param commitments - an H and a V commitment per ship, in fleet order
+ * @return - Ok if every ship sets its length in bits, or the first ship that does not
+ */
+pub fn validate_ship_commitments(
+    fleet: &FleetConfig,
+    commitments: &[BinaryValue],
+) -> Result<(), BattleshipError> {
+    if commitments.len() != fleet.commitments() {
+        return Err(BattleshipError::LengthMismatch {
+            expected: fleet.commitments(),
+            found: commitments.len(),
+        });
+    }
+    for (ship, (expected, pair)) in fleet.ships.iter().zip(commitments.chunks(2)).enumerate() {
+        let found: usize = pair
+            .iter()
+            .map(|commitment| commitment.value.count_ones())
+            .sum();
+        if found != *expected {
+            // a fleet fits in 100 cells, so only found can exceed a u8
+            return Err(BattleshipError::ShipLengthMismatch {
+                ship: ship as u8,
+                expected: *expected as u8,
+                found: found.min(u8::MAX as usize) as u8,
+            });
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 
 pub enum ShipType {
@@ -78,6 +181,21 @@ impl ShipType {
             ShipType::Destroyer => "Destroyer",
         }
     }
+
+    /**
+     * Return the two letter code of the ship used in deck notation
+     *
+     * @return - ship code as a string slice
+     */
+    pub const fn code(self) -> &'static str {
+        match self {
+            ShipType::Carrier => "CA",
+            ShipType::Battleship => "BS",
+            ShipType::Cruiser => "CR",
+            ShipType::Submarine => "SU",
+            ShipType::Destroyer => "DE",
+        }
+    }
 }
 
 /**
@@ -106,9 +224,30 @@ impl Ship {
         Self { ship_type, x, y, z }
     }
 
+    /**
+     * Construct a new Ship object only if the full placement fits on the board
+     *
+     * @param S - the type of ship (affects length and can only be placed once per board)
+     * @param x - horizontal coordinate of the ship head
+     * @param y - vertical coordinate of the ship head
+     * @param z - dictates whether ship extends from x, y horizontally or veritcally
+     * @return - instantiated Ship object, or a string explaining why the placement is off the board
+     */
+    pub fn try_new(ship_type: ShipType, x: u8, y: u8, z: bool) -> Result<Ship, &'static str> {
+        let length = ship_type.length();
+        // (coordinate the ship does not extend along, coordinate of the ship head it extends from)
+        let (fixed, start) = if z { (x, y) } else { (y, x) };
+        if fixed >= 10 || start as usize + length > 10 {
+            Err("Ship placement does not fit on the board!")
+        } else {
+            Ok(Ship::new(ship_type, x, y, z))
+        }
+    }
+
     /**
      * Render ASCII to the console representing the ship placement
      */
+    #[cfg(feature = "std")]
     pub fn print(self) {
         let bits = self.bits(true).value;
         let mut lines = Vec::<String>::new();
@@ -174,6 +313,20 @@ impl Ship {
         }
         bits
     }
+
+    /**
+     * Enumerate every legal placement of a ship type on an otherwise empty board
+     *
+     * @param ship_type - the type of ship being placed
+     * @return - iterator over all placements that fit on the board, horizontal placements first
+     */
+    pub fn placements(ship_type: ShipType) -> impl Iterator<Item = Ship> {
+        [false, true].into_iter().flat_map(move |z| {
+            (0..10u8).flat_map(move |y| {
+                (0..10u8).filter_map(move |x| Ship::try_new(ship_type, x, y, z).ok())
+            })
+        })
+    }
 }
 
 // use in a halo 2 proof
@@ -194,6 +347,26 @@ impl Ship {
             WitnessOption::ExtraBit => self.extra_bit(),
             WitnessOption::Oversized => self.oversized(),
             WitnessOption::Undersized => self.undersized(),
+            WitnessOption::ShiftedWindow => self.shifted_window(),
+            WitnessOption::ZeroPlacement => self.zero_placement(),
+            WitnessOption::HighBits => self.high_bits(),
+            WitnessOption::SwappedOrientation => self.swapped_orientation(),
+        }
+    }
+
+    /**
+     * Export the horizontal and vertical placements the board state claims for this ship
+     * @dev differs from witness() only for options where the board state is not derived from the commitments
+     *
+     * @param utility - the type of test case being applied to the witness
+     * @return - two placements used to compute the transposed board state
+     */
+    pub fn claimed(self, utility: WitnessOption) -> [BinaryValue; 2] {
+        match utility {
+            WitnessOption::ZeroPlacement | WitnessOption::SwappedOrientation => {
+                self.default_witness()
+            }
+            _ => self.witness(utility),
         }
     }
 
@@ -309,6 +482,69 @@ impl Ship {
         placement[target].value.get_mut(index).unwrap().set(false);
         placement
     }
+
+    /**
+     * Export a horizontal or vertical ship commitment manipulated so that the run of bits crosses a row boundary
+     * @dev shifts the run to end one bit into the next row - do not use on ships in the last row
+     *
+     * @return - array of two placements where non-0 has the correct bit count but wraps rows
+     */
+    fn shifted_window(self) -> [BinaryValue; 2] {
+        // get default placement
+        let mut placement = self.default_witness();
+        // get the placement commitment being mutated
+        let target = if self.z { 1 } else { 0 };
+        // get the first index of the run such that the last bit lands in the next row
+        let length = self.ship_type.length();
+        let start = self.coordinates(true)[0] / 10 * 10 + 11 - length;
+        // move run such that ... 0 0 1 1 1 | 0 0 ... becomes ... 0 0 0 0 1 | 1 1 ...
+        placement[target] = BinaryValue::empty();
+        for index in start..start + length {
+            placement[target].value.get_mut(index).unwrap().set(true);
+        }
+        placement
+    }
+
+    /**
+     * Export a horizontal and vertical ship commitment where neither placement is set
+     * @dev the board state still claims the cells of the default placement (see claimed())
+     *
+     * @return - array of two empty placements
+     */
+    fn zero_placement(self) -> [BinaryValue; 2] {
+        [BinaryValue::empty(), BinaryValue::empty()]
+    }
+
+    /**
+     * Export a horizontal or vertical ship commitment manipulated so that a bit above the board is set
+     * @dev sets bit 100 (first bit outside of the 10x10 board) in the non-0 placement
+     *
+     * @return - array of two placements where non-0 is manipulated to have a bit above index 99
+     */
+    fn high_bits(self) -> [BinaryValue; 2] {
+        // get default placement
+        let mut placement = self.default_witness();
+        // get the placement commitment being mutated
+        let target = if self.z { 1 } else { 0 };
+        // set the first bit above the board
+        placement[target]
+            .value
+            .get_mut(BOARD_SIZE)
+            .unwrap()
+            .set(true);
+        placement
+    }
+
+    /**
+     * Export a horizontal and vertical ship commitment where the placement is witnessed in the wrong orientation
+     * @dev the board state still claims the default orientation (see claimed())
+     *
+     * @return - array of two placements where the default placement is moved to the other commitment
+     */
+    fn swapped_orientation(self) -> [BinaryValue; 2] {
+        let [horizontal, vertical] = self.default_witness();
+        [vertical, horizontal]
+    }
 }
 
 // Defines possible options for witness generation for a given ship placement
@@ -328,6 +564,15 @@ pub enum WitnessOption {
     Oversized,
     // for ship length N, input H or V commitment that has N-1 bits
     Undersized,
+    // for ship length N, input H or V commitment that has N consecutive bits crossing a row boundary
+    // @dev do not use on ships placed in the last row/ column
+    ShiftedWindow,
+    // input empty H and V commitments while the board state still claims the ship's cells
+    ZeroPlacement,
+    // input H or V commitment with a bit set above index 99
+    HighBits,
+    // input the placement in the opposite commitment (H in V or V in H) while the board state claims the original
+    SwappedOrientation,
 }
 
 pub const DEFAULT_WITNESS_OPTIONS: [WitnessOption; 5] = [
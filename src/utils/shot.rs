@@ -1,4 +1,57 @@
-use {crate::utils::binary::BinaryValue, bitvec::prelude::*};
+use crate::utils::binary::{BinaryValue, BinaryValueError};
+#[cfg(feature = "std")]
+use halo2_proofs::pasta::pallas;
+
+/**
+ * Defender's answer to a shot, asserted publicly by the shot circuit
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShotResult {
+    Hit,
+    Miss,
+}
+
+impl ShotResult {
+    // true if the shot hit a ship
+    pub fn is_hit(self) -> bool {
+        self == ShotResult::Hit
+    }
+
+    // export the hit assertion as the public input of a shot proof (1 for a hit, 0 for a miss)
+    #[cfg(feature = "std")]
+    pub fn to_fp(self) -> pallas::Base {
+        pallas::Base::from(self.is_hit() as u64)
+    }
+}
+
+impl From<bool> for ShotResult {
+    fn from(hit: bool) -> ShotResult {
+        if hit {
+            ShotResult::Hit
+        } else {
+            ShotResult::Miss
+        }
+    }
+}
+
+impl From<ShotResult> for bool {
+    fn from(result: ShotResult) -> bool {
+        result.is_hit()
+    }
+}
+
+impl From<ShotResult> for BinaryValue {
+    fn from(result: ShotResult) -> BinaryValue {
+        BinaryValue::from_u8(result.is_hit() as u8)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ShotResult> for pallas::Base {
+    fn from(result: ShotResult) -> pallas::Base {
+        result.to_fp()
+    }
+}
 
 /**
  * Serializes an X, Y shot into a binary value for the shot circuit
@@ -17,3 +70,16 @@ pub fn serialize<const S: usize>(x: [u8; S], y: [u8; S]) -> BinaryValue {
     }
     bits
 }
+
+/**
+ * Deserializes a shot commitment back into its X, Y coordinates
+ * @dev inverse of serialize::<1>()
+ *
+ * @param shot - 100-bit integer with exactly one bit flipped
+ * @return - (x, y) coordinate of the shot, or an error if the commitment is not a single shot on the board
+ */
+pub fn deserialize(shot: BinaryValue) -> Result<(u8, u8), BinaryValueError> {
+    let shot = BinaryValue::new_shot(shot.value)?;
+    let index = shot.iter_set_bits().next().unwrap();
+    Ok(((index % 10) as u8, (index / 10) as u8))
+}
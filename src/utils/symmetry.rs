@@ -0,0 +1,122 @@
+use crate::utils::{binary::BinaryValue, board::BOARD_SIZE};
+
+/**
+ * Rotations and reflections of the 10x10 board
+ * @dev the public index of a symmetry is its position in SYMMETRIES plus one; 0 means no symmetry
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Symmetry {
+    // (x, y) -> (9 - y, x)
+    Rotate90,
+    // (x, y) -> (9 - x, 9 - y)
+    Rotate180,
+    // (x, y) -> (y, 9 - x)
+    Rotate270,
+    // (x, y) -> (9 - x, y)
+    MirrorHorizontal,
+    // (x, y) -> (x, 9 - y)
+    MirrorVertical,
+}
+
+// every symmetry in public index order
+pub const SYMMETRIES: [Symmetry; 5] = [
+    Symmetry::Rotate90,
+    Symmetry::Rotate180,
+    Symmetry::Rotate270,
+    Symmetry::MirrorHorizontal,
+    Symmetry::MirrorVertical,
+];
+
+impl Symmetry {
+    /**
+     * Return the public index the symmetry chip exports for this symmetry
+     *
+     * @return - position in SYMMETRIES plus one
+     */
+    pub fn index(self) -> u64 {
+        SYMMETRIES
+            .iter()
+            .position(|symmetry| *symmetry == self)
+            .unwrap() as u64
+            + 1
+    }
+
+    /**
+     * Return the cell of the original board that moves onto a cell of the transformed board
+     *
+     * @param index - (y * 10 + x) index of a cell on the transformed board
+     * @return - (y * 10 + x) index of the cell it was moved from
+     */
+    pub fn source(self, index: usize) -> usize {
+        let (x, y) = (index % 10, index / 10);
+        let (x, y) = match self {
+            Symmetry::Rotate90 => (y, 9 - x),
+            Symmetry::Rotate180 => (9 - x, 9 - y),
+            Symmetry::Rotate270 => (9 - y, x),
+            Symmetry::MirrorHorizontal => (9 - x, y),
+            Symmetry::MirrorVertical => (x, 9 - y),
+        };
+        y * 10 + x
+    }
+
+    /**
+     * Transform a board state
+     *
+     * @param board - 100 bit board state
+     * @return - the board state with every cell moved by the symmetry
+     */
+    pub fn apply(self, board: &BinaryValue) -> BinaryValue {
+        let mut transformed = BinaryValue::empty();
+        for index in 0..BOARD_SIZE {
+            transformed
+                .value
+                .set(index, board.value[self.source(index)]);
+        }
+        transformed
+    }
+
+    /**
+     * Find the first symmetry that transforms one board state into another
+     * @dev off-circuit oracle for the index exported by SymmetryChip
+     *
+     * @param board - the original board state
+     * @param other - the board state to compare against
+     * @return - the first matching symmetry in SYMMETRIES order, or None
+     */
+    pub fn find(board: &BinaryValue, other: &BinaryValue) -> Option<Symmetry> {
+        SYMMETRIES
+            .into_iter()
+            .find(|symmetry| symmetry.apply(board).value[..BOARD_SIZE] == other.value[..BOARD_SIZE])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::{fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS},
+    };
+
+    #[test]
+    fn symmetries_compose() {
+        let board = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+        // rotating by 90 twice is rotating by 180, and mirroring twice is the identity
+        let rotated = Symmetry::Rotate90.apply(&board);
+        assert_eq!(
+            Symmetry::Rotate90.apply(&rotated),
+            Symmetry::Rotate180.apply(&board)
+        );
+        assert_eq!(Symmetry::Rotate270.apply(&rotated), board);
+        for mirror in [Symmetry::MirrorHorizontal, Symmetry::MirrorVertical] {
+            assert_eq!(mirror.apply(&mirror.apply(&board)), board);
+        }
+        // the oracle recovers each symmetry of an asymmetric board
+        for symmetry in SYMMETRIES {
+            assert_eq!(
+                Symmetry::find(&board, &symmetry.apply(&board)),
+                Some(symmetry)
+            );
+        }
+        assert_eq!(Symmetry::find(&board, &board), None);
+    }
+}
@@ -1,11 +1,11 @@
 use {
     crate::circuits::{board::BoardCircuit, shot::ShotCircuit},
     crate::utils::{
-        binary::BinaryValue, board::Board, deck::Deck, pedersen::pedersen_commit,
-        ship::DEFAULT_WITNESS_OPTIONS, shot::serialize,
+        binary::BinaryValue, board::Board, coordinate::Coordinate, deck::Deck,
+        pedersen::pedersen_commit, ship::DEFAULT_WITNESS_OPTIONS, shot::ShotResult,
     },
     halo2_proofs::{
-        arithmetic::{CurveAffine, Field, FieldExt},
+        arithmetic::{CurveAffine, Field},
         circuit::Value,
         pasta::{group::Curve, pallas, vesta, EpAffine, EqAffine, Fp, Fq},
         plonk::{
@@ -38,7 +38,7 @@ pub fn prove_board(placed_ships: JsValue) -> JsValue {
     // sample a random trapdoor value for commitment
     let trapdoor: Fq = pallas::Scalar::random(&mut OsRng);
     // marshall the board state into a pallas base field element
-    let message: Fp = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+    let message: Fp = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
     // commit to the board state
     let commitment: Vec<Fp> = {
         let commitment: EpAffine = pedersen_commit(&message, &trapdoor).to_affine();
@@ -112,19 +112,21 @@ pub fn verify_board(js_commitment: JsValue, js_proof: JsValue) -> bool {
 }
 
 #[wasm_bindgen]
-pub fn prove_shot(js_hit: JsValue, js_ships: JsValue, js_shot: JsValue) -> JsValue {
+pub fn prove_shot(js_ships: JsValue, js_shot: JsValue) -> JsValue {
     let placed_ships: [Option<(u8, u8, bool)>; 5] =
         from_value::<[Option<(u8, u8, bool)>; 5]>(js_ships).unwrap();
 
     let board: Board = Board::from(&Deck::from(placed_ships));
     let parsed_shot: [u8; 2] = from_value::<[u8; 2]>(js_shot).unwrap();
-    let shot = serialize::<1>([parsed_shot[0]], [parsed_shot[1]]);
-    // assert a hit and wrap in u256
-    let hit = BinaryValue::from_u8(from_value::<u8>(js_hit).unwrap());
+    let coordinate = Coordinate::new(parsed_shot[0], parsed_shot[1])
+        .expect("shot must target a single cell on the board");
+    let shot = coordinate.to_shot();
+    // evaluate the hit assertion against the board so it always matches the witness
+    let hit = ShotResult::from(board.hit_at(coordinate));
     // sample a random trapdoor value for commitment
     let trapdoor = pallas::Scalar::random(&mut OsRng);
     // marshall the board state into a pallas base field element
-    let message = pallas::Base::from_u128(board.state(DEFAULT_WITNESS_OPTIONS).lower_u128());
+    let message = board.commitment_preimage(DEFAULT_WITNESS_OPTIONS);
     // commit to the board state
     let commitment = {
         let commitment = pedersen_commit(&message, &trapdoor).to_affine();
@@ -136,8 +138,8 @@ pub fn prove_shot(js_hit: JsValue, js_ships: JsValue, js_shot: JsValue) -> JsVal
     let public_outputs = vec![
         commitment.0,
         commitment.1,
-        pallas::Base::from_u128(shot.lower_u128()),
-        pallas::Base::from_u128(hit.lower_u128()),
+        shot.to_fp(),
+        hit.to_fp(),
     ];
     // construct Shot circuit
     let circuit = ShotCircuit::new(board.state(DEFAULT_WITNESS_OPTIONS), trapdoor, shot, hit);
@@ -187,7 +189,7 @@ pub fn verify_shot(js_outputs: JsValue, js_proof: JsValue) -> bool {
         BinaryValue::empty(),
         pallas::Scalar::random(&mut OsRng),
         BinaryValue::empty(),
-        BinaryValue::empty(),
+        ShotResult::Miss,
     );
 
     let vk = keygen_vk(&params, &empty_circuit).expect("keygen_vk should not fail");
@@ -0,0 +1,152 @@
+use {
+    battlezips_halo2::{
+        circuits::{board::BoardCircuit, shot::ShotCircuit},
+        utils::{
+            binary::BinaryValue,
+            board::{Board, BOARD_SIZE},
+            deck::Deck,
+            pedersen::pedersen_commit,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::{serialize, ShotResult},
+        },
+    },
+    halo2_proofs::{
+        arithmetic::{CurveAffine, Field},
+        dev::MockProver,
+        pasta::{group::Curve, pallas},
+    },
+    proptest::prelude::*,
+    rand::rngs::OsRng,
+};
+
+// total number of cells occupied by a full deck of ships
+const FLEET_CELLS: usize = 5 + 4 + 3 + 3 + 2;
+
+/**
+ * Compute the public (x, y) pedersen commitment to a board state
+ *
+ * @param state - the board state to commit to
+ * @param trapdoor - the blinding factor for the commitment
+ * @return - x and y coordinates of the commitment on pallas
+ */
+fn commit(state: &BinaryValue, trapdoor: &pallas::Scalar) -> Vec<pallas::Base> {
+    let commitment = pedersen_commit(&state.to_fp(), trapdoor).to_affine();
+    let x = commitment.coordinates().unwrap().x().to_owned();
+    let y = commitment.coordinates().unwrap().y().to_owned();
+    vec![x, y]
+}
+
+/**
+ * Strategy for a single ship placement that stays on the board
+ *
+ * @param length - length of the ship being placed
+ * @return - (x, y, z) tuple where the ship does not overflow its row or column
+ */
+fn placement(length: u8) -> impl Strategy<Value = (u8, u8, bool)> {
+    any::<bool>().prop_flat_map(move |z| {
+        let (x_max, y_max) = if z {
+            (10, 11 - length)
+        } else {
+            (11 - length, 10)
+        };
+        (0..x_max, 0..y_max, Just(z))
+    })
+}
+
+/**
+ * Strategy for a full deck of ships that are all on the board and do not collide
+ *
+ * @return - a board with all 5 ships validly placed
+ */
+fn valid_board() -> impl Strategy<Value = Board> {
+    (
+        placement(5),
+        placement(4),
+        placement(3),
+        placement(3),
+        placement(2),
+    )
+        .prop_map(|ships| {
+            Board::from(&Deck::from([
+                Some(ships.0),
+                Some(ships.1),
+                Some(ships.2),
+                Some(ships.3),
+                Some(ships.4),
+            ]))
+        })
+        .prop_filter("ships collide", |board| {
+            board.state(DEFAULT_WITNESS_OPTIONS).value[0..BOARD_SIZE].count_ones() == FLEET_CELLS
+        })
+}
+
+/**
+ * Strategy for a corruption of a single ship commitment
+ * @dev mutations are (commitment index [0-9], bit index [0-99], mode) where mode is
+ *      0: flip the bit, 1: insert the bit (set), 2: delete the bit (clear)
+ *
+ * @return - the commitment, bit, and mode to mutate with
+ */
+fn mutation() -> impl Strategy<Value = (usize, usize, u8)> {
+    (0..10usize, 0..BOARD_SIZE, 0..3u8)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4))]
+
+    #[test]
+    fn valid_board_verifies(board in valid_board()) {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let circuit = BoardCircuit::new(board.witness(DEFAULT_WITNESS_OPTIONS), state, trapdoor);
+        let prover = MockProver::run(12, &circuit, vec![commit(&state, &trapdoor)]).unwrap();
+        prop_assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn mutated_board_fails(board in valid_board(), (index, bit, mode) in mutation()) {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let honest = board.witness(DEFAULT_WITNESS_OPTIONS);
+        let ship_commitments = board.mutate_witness(DEFAULT_WITNESS_OPTIONS, |i, mut value| {
+            if i == index {
+                let set = match mode {
+                    0 => !value.value[bit],
+                    1 => true,
+                    _ => false,
+                };
+                value.value.set(bit, set);
+            }
+            value
+        });
+        let circuit = BoardCircuit::new(ship_commitments, state, trapdoor);
+        let prover = MockProver::run(12, &circuit, vec![commit(&state, &trapdoor)]).unwrap();
+        // a mutation that did not change the witness must still verify to the same board state
+        if ship_commitments == honest {
+            prop_assert_eq!(prover.verify(), Ok(()));
+        } else {
+            prop_assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn shot_verifies(board in valid_board(), x in 0..10u8, y in 0..10u8) {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let state = board.state(DEFAULT_WITNESS_OPTIONS);
+        let shot = serialize::<1>([x], [y]);
+        let hit = state.value[(y * 10 + x) as usize];
+        let mut public_inputs = commit(&state, &trapdoor);
+        public_inputs.push(shot.to_fp());
+        // honest hit assertion passes
+        let circuit = ShotCircuit::new(state, trapdoor, shot, ShotResult::from(hit));
+        let mut honest = public_inputs.clone();
+        honest.push(pallas::Base::from(hit));
+        let prover = MockProver::run(11, &circuit, vec![honest]).unwrap();
+        prop_assert_eq!(prover.verify(), Ok(()));
+        // dishonest hit assertion fails
+        let circuit = ShotCircuit::new(state, trapdoor, shot, ShotResult::from(!hit));
+        public_inputs.push(pallas::Base::from(!hit));
+        let prover = MockProver::run(11, &circuit, vec![public_inputs]).unwrap();
+        prop_assert!(prover.verify().is_err());
+    }
+}
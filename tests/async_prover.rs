@@ -0,0 +1,55 @@
+#![cfg(feature = "async")]
+use {
+    battlezips_halo2::{
+        circuits::game::ProverContext,
+        utils::{
+            fixtures::pattern_1,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::{serialize, ShotResult},
+        },
+        ShotCircuit,
+    },
+    halo2_proofs::{arithmetic::Field, pasta::pallas},
+    rand::rngs::OsRng,
+    std::sync::Arc,
+};
+
+// shot circuits against board pattern 1: a hit at (3, 5) and a miss at (0, 0)
+fn shot_circuits() -> Vec<ShotCircuit> {
+    let state = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+    let trapdoor = pallas::Scalar::random(&mut OsRng);
+    vec![
+        ShotCircuit::new(state, trapdoor, serialize::<1>([3], [5]), ShotResult::Hit),
+        ShotCircuit::new(state, trapdoor, serialize::<1>([0], [0]), ShotResult::Miss),
+    ]
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn prove_shots_concurrently() {
+    let context = ProverContext::new().unwrap();
+    let circuits = shot_circuits();
+    // both proofs run on the blocking pool at the same time with the shared keys
+    let (first, second) = tokio::join!(
+        context.prove_shot_async(circuits[0].clone()),
+        context.prove_shot_async(circuits[1].clone())
+    );
+    for (circuit, proof) in circuits.iter().zip([first.unwrap(), second.unwrap()]) {
+        assert!(context
+            .verify_shot(&circuit.public_inputs(), &proof)
+            .is_ok());
+    }
+    // cloning the context shares the proving key instead of copying it
+    let clone = context.clone();
+    assert!(Arc::ptr_eq(&clone.shot_pk, &context.shot_pk));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn prove_many_shots_in_order() {
+    let context = ProverContext::new().unwrap();
+    let circuits = shot_circuits();
+    let proofs = context.prove_many_shots(circuits.clone(), 1).await.unwrap();
+    assert_eq!(proofs.len(), circuits.len());
+    for (circuit, proof) in circuits.iter().zip(proofs.iter()) {
+        assert!(context.verify_shot(&circuit.public_inputs(), proof).is_ok());
+    }
+}
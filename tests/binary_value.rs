@@ -0,0 +1,115 @@
+use {
+    battlezips_halo2::utils::{
+        binary::{BinaryValue, U256},
+        board::BOARD_SIZE,
+    },
+    halo2_proofs::{
+        arithmetic::FieldExt,
+        pasta::{group::ff::PrimeField, pallas},
+    },
+    proptest::prelude::*,
+};
+
+/**
+ * Read bit i of a little endian byte string without going through BinaryValue
+ *
+ * @param bytes - the 32 byte little endian value
+ * @param i - index of the bit to read
+ * @return - whether bit i is set
+ */
+fn bit(bytes: &[u8; 32], i: usize) -> bool {
+    bytes[i / 8] >> (i % 8) & 1 == 1
+}
+
+/**
+ * Strategy for 256 bit values that exercise the high limbs as often as the board bits
+ *
+ * @return - 32 little endian bytes, sparse or dense
+ */
+fn value() -> impl Strategy<Value = [u8; 32]> {
+    prop_oneof![
+        any::<[u8; 32]>(),
+        // board sized values keep the top 156 bits clear
+        any::<u128>().prop_map(|low| {
+            let mut bytes = [0u8; 32];
+            bytes[..16].copy_from_slice(&(low & ((1 << BOARD_SIZE) - 1)).to_le_bytes());
+            bytes
+        }),
+        // a single set bit anywhere in the value
+        (0..256usize).prop_map(|i| {
+            let mut bytes = [0u8; 32];
+            bytes[i / 8] = 1 << (i % 8);
+            bytes
+        }),
+    ]
+}
+
+// every BinaryValue adapter checked against a reference computed from the raw bytes, so any
+// change to the U256 backend must keep each conversion bit for bit
+proptest! {
+    #[test]
+    fn byte_and_hex_conversions(bytes in value()) {
+        let value = BinaryValue::from_le_bytes(bytes);
+        prop_assert_eq!(value.to_le_bytes(), bytes);
+        prop_assert_eq!(value.to_repr(), bytes);
+        prop_assert_eq!(value, BinaryValue::new_unchecked(U256::new(bytes)));
+        prop_assert_eq!(value.to_hex(), hex::encode(bytes));
+        prop_assert_eq!(BinaryValue::from_hex(&value.to_hex()), Ok(value));
+    }
+
+    #[test]
+    fn integer_conversions(bytes in value()) {
+        let value = BinaryValue::from_le_bytes(bytes);
+        let low = u128::from_le_bytes(bytes[..16].try_into().unwrap());
+        prop_assert_eq!(value.lower_u128(), low);
+        let high_set = bytes[16..].iter().any(|byte| *byte != 0);
+        prop_assert_eq!(value.try_lower_u128().is_ok(), !high_set);
+        prop_assert_eq!(BinaryValue::from_u8(bytes[0]).lower_u128(), bytes[0] as u128);
+    }
+
+    #[test]
+    fn field_conversions(bytes in value()) {
+        let value = BinaryValue::from_le_bytes(bytes);
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&bytes);
+        prop_assert_eq!(value.to_fp::<pallas::Base>(), pallas::Base::from_bytes_wide(&wide));
+        let canonical: Option<pallas::Base> = pallas::Base::from_repr(bytes).into();
+        prop_assert_eq!(value.try_to_fp().ok(), canonical);
+        if let Some(element) = canonical {
+            prop_assert_eq!(BinaryValue::from_fp(element), value);
+        }
+    }
+
+    #[test]
+    fn bit_access(bytes in value(), lo in 0..=256usize, hi in 0..=256usize) {
+        let value = BinaryValue::from_le_bytes(bytes);
+        for i in 0..256 {
+            prop_assert_eq!(value.value[i], bit(&bytes, i));
+        }
+        let bits = value.bitfield::<pallas::Base, BOARD_SIZE>();
+        let flags = value.bitfield_bitvec::<BOARD_SIZE>();
+        for (i, (field, flag)) in bits.into_iter().zip(flags).enumerate() {
+            prop_assert_eq!(field, pallas::Base::from(bit(&bytes, i)));
+            prop_assert_eq!(flag, bit(&bytes, i));
+        }
+        let set: Vec<usize> = (0..256).filter(|i| bit(&bytes, *i)).collect();
+        prop_assert_eq!(value.iter_set_bits().collect::<Vec<_>>(), set.clone());
+        prop_assert_eq!(value.to_bitvec().iter_ones().collect::<Vec<_>>(), set.clone());
+        let (lo, hi) = (lo.min(hi), lo.max(hi));
+        let masked: Vec<usize> = set
+            .iter()
+            .filter(|i| (lo..hi).contains(*i))
+            .map(|i| i - lo)
+            .collect();
+        prop_assert_eq!(value.popcount_range(lo, hi) as usize, masked.len());
+        prop_assert_eq!(value.mask(lo, hi).iter_set_bits().collect::<Vec<_>>(), masked);
+    }
+
+    #[test]
+    fn ordering(lhs in value(), rhs in value()) {
+        // numeric order compares the most significant byte first
+        let expected = lhs.iter().rev().cmp(rhs.iter().rev());
+        let (lhs, rhs) = (BinaryValue::from_le_bytes(lhs), BinaryValue::from_le_bytes(rhs));
+        prop_assert_eq!(lhs.cmp(&rhs), expected);
+    }
+}
@@ -0,0 +1,66 @@
+use {
+    battlezips_halo2::{
+        circuits::board::BOARD_CIRCUIT_K,
+        utils::{
+            commitment::BoardCommitment, fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS,
+        },
+        BoardCircuit,
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        pasta::{pallas, vesta},
+        poly::commitment::Params,
+    },
+    rand::rngs::OsRng,
+};
+
+#[test]
+fn prove_board_opening() {
+    // construct a valid board and a circuit committing to it
+    let board = pattern_1();
+    let trapdoor = pallas::Scalar::random(&mut OsRng);
+    let circuit = BoardCircuit::new(
+        board.witness(DEFAULT_WITNESS_OPTIONS),
+        board.state(DEFAULT_WITNESS_OPTIONS),
+        trapdoor,
+    );
+    // generate keys and prove the opening without revealing any shot
+    let params: Params<vesta::Affine> = Params::new(BOARD_CIRCUIT_K);
+    let pk = BoardCircuit::keygen(&params).unwrap();
+    let proof = circuit.prove(&params, &pk).unwrap();
+    // proof verifies against the published commitment
+    let commitment = circuit.commitment();
+    assert!(BoardCircuit::verify(&params, pk.get_vk(), &commitment, &proof).is_ok());
+    // proof does not verify against a different commitment
+    let wrong_commitment =
+        BoardCommitment([commitment.0[0], commitment.0[1] + pallas::Base::one()]);
+    assert!(BoardCircuit::verify(&params, pk.get_vk(), &wrong_commitment, &proof).is_err());
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn prove_board_batch() {
+    use battlezips_halo2::{Board, Deck};
+    // 5 fleets sliding right along even rows and 3 fleets sliding down along even columns
+    let decks = (0..5u8)
+        .map(|i| [0u8, 2, 4, 6, 8].map(|row| Some((i, row, false))))
+        .chain((0..3u8).map(|i| [0u8, 2, 4, 6, 8].map(|column| Some((column, i, true)))));
+    let circuits: Vec<BoardCircuit> = decks
+        .map(|deck| {
+            let board = Board::from(&Deck::from(deck));
+            BoardCircuit::new(
+                board.witness(DEFAULT_WITNESS_OPTIONS),
+                board.state(DEFAULT_WITNESS_OPTIONS),
+                pallas::Scalar::random(&mut OsRng),
+            )
+        })
+        .collect();
+    // prove every board against one shared set of params and proving key
+    let params: Params<vesta::Affine> = Params::new(BOARD_CIRCUIT_K);
+    let pk = BoardCircuit::keygen(&params).unwrap();
+    let proofs = BoardCircuit::prove_batch(&circuits, &params, &pk).unwrap();
+    assert_eq!(proofs.len(), circuits.len());
+    for (circuit, proof) in circuits.iter().zip(proofs.iter()) {
+        assert!(BoardCircuit::verify(&params, pk.get_vk(), &circuit.commitment(), proof).is_ok());
+    }
+}
@@ -0,0 +1,53 @@
+use {
+    battlezips_halo2::{
+        circuits::game::ProverContext,
+        utils::{
+            commitment::{BoardCommitment, ShotCommitment},
+            fixtures::pattern_1,
+            rng::DeterministicRng,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::{serialize, ShotResult},
+        },
+        BoardCircuit, ShotCircuit,
+    },
+    halo2_proofs::{arithmetic::Field, pasta::pallas},
+};
+
+/**
+ * Prove board pattern 1 and a hit at (3, 5) against it with every random value drawn from a seed
+ *
+ * @param context - shared prover context
+ * @param seed - seed of the rng drawing the trapdoor and both proofs' blinding factors
+ * @return - the board commitment, board proof and shot proof
+ */
+fn prove_seeded(context: &ProverContext, seed: u64) -> (BoardCommitment, Vec<u8>, Vec<u8>) {
+    let mut rng = DeterministicRng::new(seed);
+    let board = pattern_1();
+    let state = board.state(DEFAULT_WITNESS_OPTIONS);
+    let trapdoor = pallas::Scalar::random(&mut rng);
+    let circuit = BoardCircuit::new(board.witness(DEFAULT_WITNESS_OPTIONS), state, trapdoor);
+    let board_proof = context.prove_board_with_rng(&circuit, &mut rng).unwrap();
+    let shot = ShotCircuit::new(state, trapdoor, serialize::<1>([3], [5]), ShotResult::Hit);
+    let shot_proof = context.prove_shot_with_rng(&shot, &mut rng).unwrap();
+    (circuit.commitment(), board_proof, shot_proof)
+}
+
+#[test]
+fn same_seed_same_proofs() {
+    let context = ProverContext::new().unwrap();
+    let (commitment, board_proof, shot_proof) = prove_seeded(&context, 7);
+    // two runs with the same seed agree byte for byte
+    assert_eq!(
+        prove_seeded(&context, 7),
+        (commitment, board_proof.clone(), shot_proof.clone())
+    );
+    // and the reproducible proofs are still valid
+    assert!(context.verify_board(&commitment, &board_proof).is_ok());
+    let shot = ShotCommitment::new(3, 5).unwrap();
+    let instance = ShotCircuit::instance(&commitment, &shot, ShotResult::Hit);
+    assert!(context.verify_shot(&instance, &shot_proof).is_ok());
+    // another seed draws another trapdoor and other blinding factors
+    let (other, other_board_proof, _) = prove_seeded(&context, 8);
+    assert_ne!(other, commitment);
+    assert_ne!(other_board_proof, board_proof);
+}
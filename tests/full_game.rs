@@ -0,0 +1,23 @@
+use battlezips_halo2::utils::ship::STANDARD_FLEET;
+
+#[allow(dead_code)]
+#[path = "../examples/full_game.rs"]
+mod full_game;
+
+#[test]
+#[ignore = "generates a proof for every board and shot of a full game"]
+fn full_game() {
+    let (context, mut transcript) = full_game::play();
+    // the game ends once every cell of one fleet is hit
+    let winner = transcript.winner().unwrap();
+    let hits = transcript
+        .shots
+        .iter()
+        .filter(|shot| shot.defender != winner && shot.hit)
+        .count();
+    assert_eq!(hits, STANDARD_FLEET.cells());
+    assert!(transcript.verify(&context).is_ok());
+    // a transcript misreporting the result of a shot does not verify
+    transcript.shots[0].hit = !transcript.shots[0].hit;
+    assert!(transcript.verify(&context).is_err());
+}
@@ -0,0 +1,105 @@
+use {
+    battlezips_halo2::{
+        circuits::bundle::vk_fingerprint,
+        utils::golden::{decode_instance, params_digest, shot_keygen, vk_digest, GoldenFixture},
+        BoardCircuit,
+    },
+    halo2_proofs::{
+        pasta::vesta,
+        plonk::{verify_proof, ProvingKey, SingleVerifier},
+        poly::commitment::Params,
+        transcript::{Blake2bRead, Challenge255},
+    },
+    std::{fs, path::Path},
+};
+
+/**
+ * Load a committed golden fixture and its proof from tests/golden
+ *
+ * @param name - file stem of the fixture
+ * @return - the fixture and the serialized proof
+ */
+fn load_fixture(name: &str) -> (GoldenFixture, Vec<u8>) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let json = fs::read_to_string(dir.join(format!("{}.json", name))).unwrap();
+    let proof = fs::read(dir.join(format!("{}.proof", name))).unwrap();
+    (serde_json::from_str(&json).unwrap(), proof)
+}
+
+/**
+ * Check a golden fixture against freshly generated params and keys, then verify its proof
+ * @dev regenerate fixtures with `cargo run --bin regenerate-golden --features golden`
+ *
+ * @param name - file stem of the fixture
+ * @param keygen - proving key generator for the circuit under test
+ */
+fn check_fixture(
+    name: &str,
+    keygen: fn(
+        &Params<vesta::Affine>,
+    ) -> Result<ProvingKey<vesta::Affine>, halo2_proofs::plonk::Error>,
+) {
+    let (fixture, proof) = load_fixture(name);
+    let params: Params<vesta::Affine> = Params::new(fixture.k);
+    assert_eq!(
+        params_digest(&params),
+        fixture.params,
+        "{} params changed",
+        name
+    );
+    let pk = keygen(&params).unwrap();
+    assert_eq!(
+        vk_digest(pk.get_vk()),
+        fixture.vk,
+        "{} verifying key changed",
+        name
+    );
+    // proof bundles fingerprint the key with the same digest
+    let fingerprint = hex::encode(vk_fingerprint(pk.get_vk()));
+    assert_eq!(
+        fingerprint,
+        fixture.vk[..64],
+        "{} fingerprint changed",
+        name
+    );
+    let instance = decode_instance(&fixture.instance).unwrap();
+    let strategy = SingleVerifier::new(&params);
+    let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
+    assert!(
+        verify_proof(
+            &params,
+            pk.get_vk(),
+            strategy,
+            &[&[&instance]],
+            &mut transcript
+        )
+        .is_ok(),
+        "{} golden proof no longer verifies",
+        name
+    );
+}
+
+#[test]
+fn golden_board_proof() {
+    check_fixture("board", BoardCircuit::keygen);
+}
+
+#[test]
+fn golden_shot_proof() {
+    check_fixture("shot", shot_keygen);
+}
+
+#[cfg(feature = "golden")]
+#[test]
+fn golden_fixtures_regenerate() {
+    use battlezips_halo2::utils::golden::generate;
+    // the fixtures are reproducible from GOLDEN_SEED byte for byte
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    for golden in generate().unwrap() {
+        let name = golden.name;
+        let json = fs::read_to_string(dir.join(format!("{}.json", name))).unwrap();
+        assert_eq!(golden.fixture_json(), json, "{}.json is stale", name);
+        let proof = fs::read(dir.join(format!("{}.proof", name))).unwrap();
+        assert!(golden.proof == proof, "{}.proof is stale", name);
+    }
+}
@@ -0,0 +1,59 @@
+use std::{
+    path::Path,
+    process::{Command, Output},
+};
+
+// bare metal target whose sysroot ships core and alloc but no std
+const BARE_METAL_TARGET: &str = "thumbv7em-none-eabi";
+
+/**
+ * Check the library as an rlib with only the no_std + alloc data utilities enabled
+ * @dev the cdylib crate type needs a panic handler and allocator so it is not checked here
+ *
+ * @param target - target triple to check for, or None for the host
+ * @return - output of the cargo invocation
+ */
+fn check_without_std(target: Option<&str>) -> Output {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_std");
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .args([
+            "rustc",
+            "--lib",
+            "--no-default-features",
+            "--crate-type",
+            "rlib",
+        ])
+        .args(["--profile", "check", "--manifest-path"])
+        .arg(manifest)
+        .arg("--target-dir")
+        .arg(target_dir);
+    if let Some(target) = target {
+        command.args(["--target", target]);
+    }
+    command.output().unwrap()
+}
+
+#[test]
+fn utilities_build_without_std() {
+    let output = check_without_std(None);
+    assert!(
+        output.status.success(),
+        "no_std build failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+#[ignore = "needs `rustup target add thumbv7em-none-eabi`; run by the no_std workflow"]
+fn utilities_build_for_bare_metal() {
+    // the host check links std for dependencies that quietly use it; a target without std does not
+    let output = check_without_std(Some(BARE_METAL_TARGET));
+    assert!(
+        output.status.success(),
+        "{} build failed:\n{}",
+        BARE_METAL_TARGET,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,21 @@
+use {
+    battlezips_halo2::{
+        pedersen_commit,
+        utils::{fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS},
+        BinaryValue, BoardCircuit, ShotCircuit, ShotResult,
+    },
+    halo2_proofs::{arithmetic::Field, pasta::pallas},
+    rand::rngs::OsRng,
+};
+
+#[test]
+fn construct_circuits_from_crate_root() {
+    // construct battleship board pattern #1 using the root exports
+    let board = pattern_1();
+    let trapdoor = pallas::Scalar::random(&mut OsRng);
+    let state = board.state(DEFAULT_WITNESS_OPTIONS);
+    pedersen_commit(&state.to_fp(), &trapdoor);
+    // construct a board circuit and a shot circuit at (0, 0)
+    BoardCircuit::new(board.witness(DEFAULT_WITNESS_OPTIONS), state, trapdoor);
+    ShotCircuit::new(state, trapdoor, BinaryValue::from_u8(1), ShotResult::Miss);
+}
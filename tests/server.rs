@@ -0,0 +1,90 @@
+#![cfg(feature = "server")]
+use {
+    battlezips_halo2::{circuits::game::ProverContext, utils::fixtures::PATTERN_1},
+    std::{
+        io::{Read, Write},
+        net::{SocketAddr, TcpStream},
+    },
+    tiny_http::Server,
+};
+
+#[allow(dead_code)]
+#[path = "../examples/server.rs"]
+mod server;
+
+/**
+ * Send a request to the server and wait for the response
+ *
+ * @param address - address the server is bound to
+ * @param method - http method of the request
+ * @param path - path of the request
+ * @param body - json body of the request
+ * @return - http status code and json body of the response
+ */
+fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method,
+        path,
+        address,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn prove_and_verify_endpoints() {
+    // bind an ephemeral port and serve from a background thread
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let address = server.server_addr().to_ip().unwrap();
+    std::thread::spawn(move || server::serve(server, &ProverContext::new().unwrap()));
+
+    // prove board pattern 1 and verify its bundle
+    let ships = PATTERN_1.map(|ship| ship.unwrap());
+    let body = serde_json::json!({ "ships": ships, "trapdoor": null }).to_string();
+    let (status, board) = request(address, "POST", "/prove/board", &body);
+    assert_eq!(status, 200);
+    let body = serde_json::json!({ "bundle": board["bundle"] }).to_string();
+    let (status, verified) = request(address, "POST", "/verify", &body);
+    assert_eq!(status, 200);
+    assert_eq!(
+        verified,
+        serde_json::json!({ "kind": "board", "valid": true })
+    );
+
+    // prove a hit at (3, 3) against the committed board and verify its bundle
+    let body = serde_json::json!({
+        "ships": ships,
+        "trapdoor": board["trapdoor"],
+        "x": 3,
+        "y": 3
+    })
+    .to_string();
+    let (status, shot) = request(address, "POST", "/prove/shot", &body);
+    assert_eq!(status, 200);
+    assert_eq!(shot["hit"], true);
+    let body = serde_json::json!({ "bundle": shot["bundle"] }).to_string();
+    let (status, verified) = request(address, "POST", "/verify", &body);
+    assert_eq!(status, 200);
+    assert_eq!(
+        verified,
+        serde_json::json!({ "kind": "shot", "valid": true })
+    );
+
+    // malformed requests are rejected without proving
+    let body = serde_json::json!({ "bundle": "not base64!" }).to_string();
+    assert_eq!(request(address, "POST", "/verify", &body).0, 400);
+    let body =
+        serde_json::json!({ "ships": ships, "trapdoor": board["trapdoor"], "x": 10, "y": 0 })
+            .to_string();
+    assert_eq!(request(address, "POST", "/prove/shot", &body).0, 400);
+    assert_eq!(request(address, "GET", "/prove/board", "").0, 404);
+}
@@ -0,0 +1,31 @@
+use std::{path::Path, process::Command};
+
+#[test]
+#[ignore = "needs `rustup toolchain install stable`; run by the stable workflow"]
+fn library_builds_on_stable() {
+    // the crate enables no unstable features; rust-toolchain pins nightly only for the wasm build
+    // @dev checked through the rustup proxy since env!("CARGO") is the pinned nightly cargo
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let target = Path::new(env!("CARGO_TARGET_TMPDIR")).join("stable");
+    let output = Command::new("rustup")
+        .args([
+            "run",
+            "stable",
+            "cargo",
+            "check",
+            "--lib",
+            "--features",
+            "stable",
+            "--manifest-path",
+        ])
+        .arg(manifest)
+        .arg("--target-dir")
+        .arg(target)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stable build failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "trace")]
+use {
+    battlezips_halo2::{
+        utils::{
+            fixtures::pattern_1,
+            ship::DEFAULT_WITNESS_OPTIONS,
+            shot::{serialize, ShotResult},
+        },
+        BoardCircuit, ShotCircuit,
+    },
+    halo2_proofs::{arithmetic::Field, dev::MockProver, pasta::pallas},
+    rand::rngs::OsRng,
+    std::sync::{Arc, Mutex},
+    tracing::{
+        field::{Field as TraceField, Visit},
+        span::{Attributes, Id},
+        Subscriber,
+    },
+    tracing_subscriber::{
+        layer::{Context, Layer, SubscriberExt},
+        Registry,
+    },
+};
+
+// records "chip step" for every synthesis span opened while it is the default subscriber
+#[derive(Clone, Default)]
+struct StepRecorder(Arc<Mutex<Vec<String>>>);
+
+#[derive(Default)]
+struct StepVisitor {
+    chip: String,
+    step: String,
+}
+
+impl Visit for StepVisitor {
+    fn record_str(&mut self, field: &TraceField, value: &str) {
+        match field.name() {
+            "chip" => self.chip = value.to_string(),
+            "step" => self.step = value.to_string(),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _: &TraceField, _: &dyn core::fmt::Debug) {}
+}
+
+impl<S: Subscriber> Layer<S> for StepRecorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+        if attrs.metadata().name() == "synthesize" {
+            let mut visitor = StepVisitor::default();
+            attrs.record(&mut visitor);
+            let step = format!("{} {}", visitor.chip, visitor.step);
+            self.0.lock().unwrap().push(step);
+        }
+    }
+}
+
+// run a closure with a fresh recorder as the default subscriber and return the recorded steps
+fn record_steps(synthesize: impl FnOnce()) -> Vec<String> {
+    let recorder = StepRecorder::default();
+    let subscriber = Registry::default().with(recorder.clone());
+    tracing::subscriber::with_default(subscriber, synthesize);
+    let steps = recorder.0.lock().unwrap();
+    steps.clone()
+}
+
+#[test]
+fn board_synthesis_spans() {
+    let board = pattern_1();
+    let circuit = BoardCircuit::new(
+        board.witness(DEFAULT_WITNESS_OPTIONS),
+        board.state(DEFAULT_WITNESS_OPTIONS),
+        pallas::Scalar::random(&mut OsRng),
+    );
+    let steps = record_steps(|| {
+        let instance = circuit.public_inputs().to_vec();
+        let prover = MockProver::run(12, &circuit, vec![instance]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    });
+    // every step of the board chip opens exactly one span, in synthesis order
+    assert_eq!(
+        steps,
+        [
+            "board load_commitments",
+            "board decompose_commitments",
+            "board synth_placements",
+            "board transpose_placements",
+            "board recompose_board",
+            "board commit_board",
+        ]
+    );
+}
+
+#[test]
+fn shot_synthesis_spans() {
+    let state = pattern_1().state(DEFAULT_WITNESS_OPTIONS);
+    let trapdoor = pallas::Scalar::random(&mut OsRng);
+    let circuit = ShotCircuit::new(state, trapdoor, serialize::<1>([3], [5]), ShotResult::Hit);
+    let steps = record_steps(|| {
+        let instance = circuit.public_inputs().to_vec();
+        let prover = MockProver::run(12, &circuit, vec![instance]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    });
+    assert_eq!(
+        steps,
+        [
+            "shot load_advice",
+            "shot decompose",
+            "shot running_sums",
+            "shot running_sum_output",
+            "shot commit_board",
+            "shot export",
+        ]
+    );
+}
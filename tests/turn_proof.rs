@@ -0,0 +1,39 @@
+use {
+    battlezips_halo2::{
+        circuits::turn::TURN_CIRCUIT_K,
+        utils::{
+            binary::BinaryValue, fixtures::pattern_1, ship::DEFAULT_WITNESS_OPTIONS,
+            shot::serialize,
+        },
+        TurnCircuit,
+    },
+    halo2_proofs::{
+        arithmetic::Field,
+        pasta::{pallas, vesta},
+        poly::commitment::Params,
+    },
+    rand::rngs::OsRng,
+};
+
+#[test]
+fn prove_turn() {
+    // prove a valid board and a hit at (3, 5) on it in one proof
+    let board = pattern_1();
+    let circuit = TurnCircuit::new(
+        board.witness(DEFAULT_WITNESS_OPTIONS),
+        board.state(DEFAULT_WITNESS_OPTIONS),
+        pallas::Scalar::random(&mut OsRng),
+        serialize::<1>([3], [5]),
+        BinaryValue::from_u8(1),
+    );
+    let params: Params<vesta::Affine> = Params::new(TURN_CIRCUIT_K);
+    let pk = TurnCircuit::keygen(&params).unwrap();
+    let proof = circuit.prove(&params, &pk).unwrap();
+    // proof verifies against the published commitment, shot and hit
+    let public_inputs = circuit.public_inputs();
+    assert!(TurnCircuit::verify(&params, pk.get_vk(), &public_inputs, &proof).is_ok());
+    // proof does not verify as a miss
+    let mut miss = public_inputs;
+    miss[3] = pallas::Base::zero();
+    assert!(TurnCircuit::verify(&params, pk.get_vk(), &miss, &proof).is_err());
+}
@@ -0,0 +1,154 @@
+use {
+    battlezips_halo2::{
+        circuits::game::{Player, ProtocolError, ProverContext, TwoPlayerProtocol},
+        utils::{
+            binary::BinaryValue,
+            deck::Deck,
+            fixtures::{pattern_2, PATTERN_1, PATTERN_2},
+            ship::{DEFAULT_WITNESS_OPTIONS, STANDARD_FLEET},
+        },
+        ShotCircuit, ShotResult,
+    },
+    halo2_proofs::plonk::Error,
+};
+
+#[test]
+fn protocol_turn_rules() {
+    let context = ProverContext::new().unwrap();
+    // a deck missing the carrier is rejected before any board is proven
+    let mut ships = PATTERN_1;
+    ships[0] = None;
+    assert!(matches!(
+        TwoPlayerProtocol::setup(context.clone(), &Deck::from(ships), &Deck::from(PATTERN_2)),
+        Err(ProtocolError::InvalidBoard {
+            player: Player::One,
+            ..
+        })
+    ));
+    let (mut protocol, board1, board2) = TwoPlayerProtocol::setup(
+        context.clone(),
+        &Deck::from(PATTERN_1),
+        &Deck::from(PATTERN_2),
+    )
+    .unwrap();
+    assert!(context
+        .verify_board(&board1.commitment, &board1.proof)
+        .is_ok());
+    assert!(context
+        .verify_board(&board2.commitment, &board2.proof)
+        .is_ok());
+    // player one fires first
+    assert!(matches!(
+        protocol.take_turn(Player::Two, 0, 0),
+        Err(ProtocolError::OutOfTurn {
+            expected: Player::One
+        })
+    ));
+    assert!(matches!(
+        protocol.take_turn(Player::One, 10, 0),
+        Err(ProtocolError::InvalidShot(_))
+    ));
+    // (0, 0) is the cruiser of pattern 2 and open water on pattern 1
+    assert!(protocol.take_turn(Player::One, 0, 0).unwrap().hit);
+    assert!(!protocol.take_turn(Player::Two, 0, 0).unwrap().hit);
+    // a cell cannot be fired at twice by the same attacker
+    assert!(matches!(
+        protocol.take_turn(Player::One, 0, 0),
+        Err(ProtocolError::RepeatedShot { x: 0, y: 0 })
+    ));
+    assert_eq!(protocol.is_game_over(), None);
+    assert_eq!(protocol.transcript.shots.len(), 2);
+    // replaying the transcript rebuilds what each player learned about the other's board
+    let [view1, view2] = protocol.transcript.verify(&context).unwrap();
+    assert_eq!(view1.hits.iter_set_bits().collect::<Vec<_>>(), vec![0]);
+    assert_eq!(view1.misses, BinaryValue::empty());
+    assert_eq!(view2.hits, BinaryValue::empty());
+    assert_eq!(view2.misses.iter_set_bits().collect::<Vec<_>>(), vec![0]);
+    assert_eq!(view1, protocol.session(Player::One).view);
+    assert_eq!(view2, protocol.session(Player::Two).view);
+}
+
+#[test]
+fn transcript_rejects_repeated_turn() {
+    let context = ProverContext::new().unwrap();
+    let (mut protocol, _, _) = TwoPlayerProtocol::setup(
+        context.clone(),
+        &Deck::from(PATTERN_1),
+        &Deck::from(PATTERN_2),
+    )
+    .unwrap();
+    protocol.take_turn(Player::One, 0, 0).unwrap();
+    let record = protocol.take_turn(Player::Two, 0, 0).unwrap();
+    // each shot is bound to its position in the transcript
+    let turns: Vec<u64> = protocol
+        .transcript
+        .shots
+        .iter()
+        .map(|shot| shot.turn)
+        .collect();
+    assert_eq!(turns, vec![0, 1]);
+    assert!(protocol.transcript.verify(&context).is_ok());
+    // player one proves the second shot again as if it were fired on the first turn
+    let defender = protocol.session(Player::One);
+    let circuit = ShotCircuit::new_with_turn(
+        defender.board.state(DEFAULT_WITNESS_OPTIONS),
+        defender.trapdoor,
+        record.shot.0,
+        ShotResult::Miss,
+        0,
+    );
+    let proof = context.prove_shot(&circuit).unwrap();
+    let mut public_inputs = circuit.public_inputs().to_vec();
+    public_inputs.extend(circuit.turn);
+    assert!(context
+        .verify_turn_shot(&public_inputs.try_into().unwrap(), &proof)
+        .is_ok());
+    // both proofs are valid on their own but two shots cannot share a turn
+    let mut transcript = protocol.transcript.clone();
+    transcript.shots[1].turn = 0;
+    transcript.shots[1].proof = proof;
+    assert!(matches!(transcript.verify(&context), Err(Error::Synthesis)));
+    // relabelling the turn of a record does not carry over to its proof
+    let mut transcript = protocol.transcript.clone();
+    transcript.shots.swap(0, 1);
+    transcript.shots[0].turn = 0;
+    transcript.shots[1].turn = 1;
+    assert!(transcript.verify(&context).is_err());
+}
+
+#[test]
+#[ignore = "generates a proof for every shot of a full game"]
+fn protocol_full_game() {
+    let context = ProverContext::new().unwrap();
+    let (mut protocol, _, _) = TwoPlayerProtocol::setup(
+        context.clone(),
+        &Deck::from(PATTERN_1),
+        &Deck::from(PATTERN_2),
+    )
+    .unwrap();
+    // player one fires at every cell of pattern 2 while player two sweeps the first rows
+    let targets: Vec<(u8, u8)> = (0..100u8)
+        .map(|i| (i % 10, i / 10))
+        .filter(|(x, y)| pattern_2().hit(*x, *y).unwrap())
+        .collect();
+    assert_eq!(targets.len(), STANDARD_FLEET.cells());
+    for (i, (x, y)) in targets.into_iter().enumerate() {
+        assert!(protocol.take_turn(Player::One, x, y).unwrap().hit);
+        if protocol.is_game_over().is_none() {
+            protocol
+                .take_turn(Player::Two, i as u8 % 10, i as u8 / 10)
+                .unwrap();
+        }
+    }
+    assert_eq!(protocol.is_game_over(), Some(Player::One));
+    assert!(matches!(
+        protocol.take_turn(Player::Two, 9, 9),
+        Err(ProtocolError::GameOver {
+            winner: Player::One
+        })
+    ));
+    // the replayed view of the winner has no cells of the loser's fleet left to hit
+    let views = protocol.transcript.verify(&context).unwrap();
+    assert_eq!(views[0].remaining_cells(), 0);
+    assert_eq!(views[1], protocol.session(Player::Two).view);
+}